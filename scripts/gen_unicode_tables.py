@@ -0,0 +1,188 @@
+#!/usr/bin/env python3
+# Generates the Unicode tables used by src/bin/text.rs
+# - src/unicode_tables.rs from the Unicode Character Database
+# - data/grapheme_break_test.txt, a copy of GraphemeBreakTest.txt from the same place
+#
+# Download the files from unicode.org
+#   python3 scripts/gen_unicode_tables.py
+# Use files already downloaded to DIR
+#   python3 scripts/gen_unicode_tables.py --ucd DIR
+# Files from anywhere else, the generated header says where they came from
+#   python3 scripts/gen_unicode_tables.py --ucd DIR --source "..."
+
+import argparse
+import os
+import shutil
+import urllib.request
+
+VERSION = "17.0.0"
+
+# File name -> path under https://www.unicode.org/Public/<VERSION>/ucd/
+FILES = {
+    "GraphemeBreakProperty.txt": "auxiliary/GraphemeBreakProperty.txt",
+    "GraphemeBreakTest.txt": "auxiliary/GraphemeBreakTest.txt",
+    "emoji-data.txt": "emoji/emoji-data.txt",
+    "DerivedCoreProperties.txt": "DerivedCoreProperties.txt",
+    "EastAsianWidth.txt": "EastAsianWidth.txt",
+}
+
+ROOT = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
+TABLES = os.path.join(ROOT, "src", "unicode_tables.rs")
+BREAK_TEST = os.path.join(ROOT, "data", "grapheme_break_test.txt")
+
+# Grapheme_Cluster_Break value -> Gcb variant
+GCB = {
+    "CR": "CR",
+    "LF": "LF",
+    "Control": "Control",
+    "Extend": "Extend",
+    "ZWJ": "ZWJ",
+    "Regional_Indicator": "RegionalIndicator",
+    "Prepend": "Prepend",
+    "SpacingMark": "SpacingMark",
+    "L": "L",
+    "V": "V",
+    "T": "T",
+    "LV": "LV",
+    "LVT": "LVT",
+}
+
+HANGUL_FIRST, HANGUL_LAST = 0xAC00, 0xD7A3
+
+
+def download(ucd):
+    os.makedirs(ucd, exist_ok=True)
+    for name, path in FILES.items():
+        dest = os.path.join(ucd, name)
+        if not os.path.exists(dest):
+            url = f"https://www.unicode.org/Public/{VERSION}/ucd/{path}"
+            print(f"downloading {url}")
+            urllib.request.urlretrieve(url, dest)
+
+
+# Yields (first, last, [fields]) for each data line
+# 0300..036F    ; Extend # Mn [112] COMBINING GRAVE ACCENT..
+def parse(ucd, name):
+    with open(os.path.join(ucd, name), encoding="utf-8") as f:
+        for line in f:
+            line = line.split("#")[0].strip()
+            if not line:
+                continue
+            fields = [s.strip() for s in line.split(";")]
+            first, _, last = fields[0].partition("..")
+            yield int(first, 16), int(last or first, 16), fields[1:]
+
+
+# Sorts and joins adjacent ranges with the same value
+def merge(ranges):
+    out = []
+    for first, last, value in sorted(ranges):
+        if out and out[-1][1] + 1 == first and out[-1][2] == value:
+            out[-1] = (out[-1][0], last, value)
+        else:
+            assert not out or out[-1][1] < first, f"overlap at {first:04X}"
+            out.append((first, last, value))
+    return out
+
+
+def grapheme_break(ucd):
+    ranges = []
+    for first, last, (prop,) in parse(ucd, "GraphemeBreakProperty.txt"):
+        if prop in ("LV", "LVT"):
+            # Computed in text.rs, check that the rule holds
+            for c in range(first, last + 1):
+                assert HANGUL_FIRST <= c <= HANGUL_LAST
+                assert ((c - HANGUL_FIRST) % 28 == 0) == (prop == "LV")
+            continue
+        ranges.append((first, last, GCB[prop]))
+    return merge(ranges)
+
+
+def indic_conjunct_break(ucd):
+    ranges = []
+    for first, last, fields in parse(ucd, "DerivedCoreProperties.txt"):
+        if fields[0] == "InCB":
+            ranges.append((first, last, fields[1]))
+    return merge(ranges)
+
+
+def binary_property(ucd, name, prop):
+    ranges = [(first, last, None) for first, last, fields in parse(ucd, name) if fields[0] == prop]
+    return [(first, last) for first, last, _ in merge(ranges)]
+
+
+def east_asian_wide(ucd):
+    ranges = [
+        (first, last, None)
+        for first, last, (width,) in parse(ucd, "EastAsianWidth.txt")
+        if width in ("W", "F")
+    ]
+    return [(first, last) for first, last, _ in merge(ranges)]
+
+
+def hex_range(first, last):
+    return f"0x{first:04X}, 0x{last:04X}"
+
+
+def write_tables(ucd, source, out):
+    major, minor, update = VERSION.split(".")
+    w = out.write
+    w(f"// Generated by scripts/gen_unicode_tables.py from Unicode {VERSION}, do not edit\n")
+    w(f"// Source: {source}\n")
+    w("// Tables are sorted, non overlapping (first, last) code point ranges\n\n")
+    w(f"pub const UNICODE_VERSION: (u8, u8, u8) = ({major}, {minor}, {update});\n\n")
+
+    w("// Grapheme_Cluster_Break property, names as in UAX #29\n")
+    w("#[allow(clippy::upper_case_acronyms)]\n")
+    w("#[derive(Debug, Clone, Copy, PartialEq, Eq)]\n")
+    w("pub enum Gcb {\n    Other,\n")
+    for variant in GCB.values():
+        w(f"    {variant},\n")
+    w("}\n\n")
+    w("// From GraphemeBreakProperty.txt, code points not in the table are Other\n")
+    w(f"// - Hangul syllables {HANGUL_FIRST:04X}..{HANGUL_LAST:04X} are left out,\n")
+    w("//   LV every 28 code points and LVT in between\n")
+    w("pub const GRAPHEME_BREAK: &[(u32, u32, Gcb)] = &[\n")
+    for first, last, prop in grapheme_break(ucd):
+        w(f"    ({hex_range(first, last)}, Gcb::{prop}),\n")
+    w("];\n\n")
+
+    w("// Indic_Conjunct_Break property\n")
+    w("#[derive(Debug, Clone, Copy, PartialEq, Eq)]\n")
+    w("pub enum InCb {\n    Consonant,\n    Linker,\n    Extend,\n}\n\n")
+    w("// From DerivedCoreProperties.txt, code points not in the table are None\n")
+    w("pub const INDIC_CONJUNCT_BREAK: &[(u32, u32, InCb)] = &[\n")
+    for first, last, prop in indic_conjunct_break(ucd):
+        w(f"    ({hex_range(first, last)}, InCb::{prop}),\n")
+    w("];\n\n")
+
+    w("// Extended_Pictographic from emoji-data.txt\n")
+    w("pub const EXTENDED_PICTOGRAPHIC: &[(u32, u32)] = &[\n")
+    for first, last in binary_property(ucd, "emoji-data.txt", "Extended_Pictographic"):
+        w(f"    ({hex_range(first, last)}),\n")
+    w("];\n\n")
+
+    w("// East_Asian_Width = Wide or Fullwidth from EastAsianWidth.txt\n")
+    w("pub const EAST_ASIAN_WIDE: &[(u32, u32)] = &[\n")
+    for first, last in east_asian_wide(ucd):
+        w(f"    ({hex_range(first, last)}),\n")
+    w("];\n")
+
+
+def main():
+    parser = argparse.ArgumentParser()
+    parser.add_argument("--ucd", help="directory with the UCD files, downloaded if not given")
+    parser.add_argument("--source", help="where the --ucd files came from, if not unicode.org")
+    args = parser.parse_args()
+    ucd = args.ucd or os.path.join(ROOT, "target", "ucd", VERSION)
+    if not args.ucd:
+        download(ucd)
+
+    with open(TABLES, "w", encoding="utf-8") as out:
+        write_tables(ucd, args.source or "unicode.org", out)
+    shutil.copyfile(os.path.join(ucd, "GraphemeBreakTest.txt"), BREAK_TEST)
+    print(f"wrote {TABLES}\nwrote {BREAK_TEST}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,36 @@
+// Global allocator that counts heap allocations, to check that code does not allocate
+// A bin installs it with
+//   #[global_allocator]
+//   static GLOBAL: CountingAlloc = CountingAlloc;
+// Without it the counts stay at 0
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+pub struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+// realloc is not overridden, the default calls alloc so it is counted too
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+// Allocations since the program started, on all threads
+pub fn allocations() -> usize {
+    ALLOCATIONS.load(Ordering::Relaxed)
+}
+
+// Result of f and the number of allocations it made
+pub fn count_allocations<R>(f: impl FnOnce() -> R) -> (R, usize) {
+    let before = allocations();
+    let out = f();
+    (out, allocations() - before)
+}
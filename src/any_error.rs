@@ -0,0 +1,232 @@
+// Error type that keeps where an error came from
+// - Any std::error::Error converts into AnyError, so ? works
+// - .context("reading config") and .with_context(|| ..) on Result and Option
+//   wrap the error in a message, the wrapped error becomes its source()
+// - Backtrace captured when RUST_BACKTRACE or RUST_LIB_BACKTRACE is set
+// - downcast_ref / downcast to the concrete error anywhere in the chain
+//
+// {}   reading config
+//      Caused by:
+//          1: parsing line 3
+//          2: invalid digit found in string
+// {:#} reading config: parsing line 3: invalid digit found in string
+// {:?} same as {}, then the backtrace if captured
+//
+// AnyError does not implement Error itself, otherwise From<E: Error> would
+// conflict with From<AnyError> for AnyError
+
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::error::Error;
+use std::fmt;
+
+type BoxError = Box<dyn Error + Send + Sync + 'static>;
+
+pub struct AnyError {
+    error: BoxError,
+    backtrace: Backtrace,
+}
+
+pub type Result<T, E = AnyError> = std::result::Result<T, E>;
+
+// An error from a message, AnyError::msg("missing file")
+struct MessageError(String);
+
+impl fmt::Debug for MessageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for MessageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Error for MessageError {}
+
+// Message added by .context(), the wrapped error is the source
+#[derive(Debug)]
+struct ContextError {
+    context: String,
+    error: BoxError,
+}
+
+impl fmt::Display for ContextError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.context)
+    }
+}
+
+impl Error for ContextError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self.error.as_ref())
+    }
+}
+
+impl AnyError {
+    pub fn new<E: Error + Send + Sync + 'static>(error: E) -> Self {
+        Self::from_box(Box::new(error))
+    }
+
+    pub fn msg(msg: impl fmt::Display) -> Self {
+        Self::new(MessageError(msg.to_string()))
+    }
+
+    fn from_box(error: BoxError) -> Self {
+        Self {
+            error,
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    // Keeps the backtrace of the original error
+    pub fn context(self, context: impl fmt::Display) -> Self {
+        Self {
+            error: Box::new(ContextError {
+                context: context.to_string(),
+                error: self.error,
+            }),
+            backtrace: self.backtrace,
+        }
+    }
+
+    // Disabled unless RUST_BACKTRACE or RUST_LIB_BACKTRACE is set
+    pub fn backtrace(&self) -> &Backtrace {
+        &self.backtrace
+    }
+
+    // This error, then its source, then the source of the source...
+    pub fn chain(&self) -> Chain<'_> {
+        Chain {
+            next: Some(self.error.as_ref()),
+        }
+    }
+
+    pub fn root_cause(&self) -> &(dyn Error + 'static) {
+        self.chain().last().unwrap()
+    }
+
+    // First error of type E in the chain
+    pub fn downcast_ref<E: Error + 'static>(&self) -> Option<&E> {
+        self.chain().find_map(|e| e.downcast_ref::<E>())
+    }
+
+    // Owned E, looks through the errors wrapped by context()
+    // Sources of other errors can only be borrowed
+    pub fn downcast<E: Error + 'static>(self) -> Result<E, Self> {
+        let backtrace = self.backtrace;
+        let mut error = self.error;
+        let mut contexts = Vec::new();
+        loop {
+            error = match error.downcast::<E>() {
+                Ok(e) => return Ok(*e),
+                Err(e) => e,
+            };
+            match error.downcast::<ContextError>() {
+                Ok(c) => {
+                    contexts.push(c.context);
+                    error = c.error;
+                }
+                Err(e) => {
+                    error = e;
+                    break;
+                }
+            }
+        }
+        // Not found, put the contexts back
+        for context in contexts.into_iter().rev() {
+            error = Box::new(ContextError { context, error });
+        }
+        Err(Self { error, backtrace })
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> From<E> for AnyError {
+    fn from(error: E) -> Self {
+        Self::new(error)
+    }
+}
+
+pub struct Chain<'a> {
+    next: Option<&'a (dyn Error + 'static)>,
+}
+
+impl<'a> Iterator for Chain<'a> {
+    type Item = &'a (dyn Error + 'static);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let e = self.next?;
+        self.next = e.source();
+        Some(e)
+    }
+}
+
+impl fmt::Display for AnyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.error)?;
+        let mut causes = self.chain().skip(1).peekable();
+        if f.alternate() {
+            for cause in causes {
+                write!(f, ": {cause}")?;
+            }
+            return Ok(());
+        }
+        if causes.peek().is_some() {
+            write!(f, "\nCaused by:")?;
+        }
+        for (i, cause) in causes.enumerate() {
+            write!(f, "\n    {}: {cause}", i + 1)?;
+        }
+        Ok(())
+    }
+}
+
+// Used by fn main() -> Result<(), AnyError>
+impl fmt::Debug for AnyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)?;
+        if self.backtrace.status() == BacktraceStatus::Captured {
+            write!(f, "\n\nStack backtrace:\n{}", self.backtrace)?;
+        }
+        Ok(())
+    }
+}
+
+pub trait Context<T> {
+    fn context<C: fmt::Display>(self, context: C) -> Result<T>;
+
+    // The message is only built on error
+    fn with_context<C: fmt::Display, F: FnOnce() -> C>(self, f: F) -> Result<T>;
+}
+
+impl<T, E: Error + Send + Sync + 'static> Context<T> for Result<T, E> {
+    fn context<C: fmt::Display>(self, context: C) -> Result<T> {
+        self.map_err(|e| AnyError::new(e).context(context))
+    }
+
+    fn with_context<C: fmt::Display, F: FnOnce() -> C>(self, f: F) -> Result<T> {
+        self.map_err(|e| AnyError::new(e).context(f()))
+    }
+}
+
+impl<T> Context<T> for Result<T, AnyError> {
+    fn context<C: fmt::Display>(self, context: C) -> Result<T> {
+        self.map_err(|e| e.context(context))
+    }
+
+    fn with_context<C: fmt::Display, F: FnOnce() -> C>(self, f: F) -> Result<T> {
+        self.map_err(|e| e.context(f()))
+    }
+}
+
+// None becomes an error with the context as the message
+impl<T> Context<T> for Option<T> {
+    fn context<C: fmt::Display>(self, context: C) -> Result<T> {
+        self.ok_or_else(|| AnyError::msg(context))
+    }
+
+    fn with_context<C: fmt::Display, F: FnOnce() -> C>(self, f: F) -> Result<T> {
+        self.ok_or_else(|| AnyError::msg(f()))
+    }
+}
@@ -0,0 +1,112 @@
+#![allow(unused)]
+
+// AnyError, an error type that keeps where an error came from (src/any_error.rs)
+// box_dyn_error.rs loses the origin of an error, here and in question.rs
+// the MathError and ParseError of src/errors.rs keep it as context
+// cargo run --bin any_error
+// RUST_BACKTRACE=1 cargo run --bin any_error
+
+use hello_rust::any_error::{AnyError, Context, Result};
+use hello_rust::errors::{MathError, ParseError};
+use hello_rust::info;
+use std::fs;
+
+// f1 and f2 of box_dyn_error.rs, with inputs
+fn div(x: u32, y: u32) -> Result<u32, MathError> {
+    x.checked_div(y).ok_or(MathError::DivByZero)
+}
+
+fn parse(s: &str) -> Result<u32, ParseError> {
+    s.trim().parse().map_err(|_| ParseError::InvalidInt)
+}
+
+// Same as f3 in box_dyn_error.rs, Box<dyn Error> replaced by AnyError
+fn f3(x: &str, y: &str) -> Result<u32> {
+    let x = parse(x).context("parsing x")?;
+    let y = parse(y).with_context(|| format!("parsing y = {y:?}"))?;
+    let q = div(x, y).with_context(|| format!("dividing {x} by {y}"))?;
+    Ok(q)
+}
+
+// Sum of the numbers in a file, 1 decimal number per line
+fn sum_file(path: &str) -> Result<u32> {
+    let data = fs::read_to_string(path).with_context(|| format!("reading {path}"))?;
+    sum_lines(path, &data)
+}
+
+fn sum_lines(path: &str, data: &str) -> Result<u32> {
+    let mut sum: u32 = 0;
+    for (i, line) in data.lines().enumerate() {
+        let n = parse(line).with_context(|| format!("{path}:{}", i + 1))?;
+        sum = sum.checked_add(n).context("sum overflows u32")?;
+    }
+    Ok(sum)
+}
+
+fn main() -> Result<()> {
+    assert_eq!(f3("12", "4")?, 3);
+
+    // Display prints the numbered chain
+    let err = f3("12", "0").unwrap_err();
+    info!("{err}\n");
+    assert_eq!(
+        err.to_string(),
+        "dividing 12 by 0\nCaused by:\n    1: math error DivByZero"
+    );
+
+    // {:#} prints it on 1 line
+    let err = f3("12", "zero").unwrap_err();
+    info!("{err:#}\n");
+    assert_eq!(
+        format!("{err:#}"),
+        "parsing y = \"zero\": parse error InvalidInt"
+    );
+
+    // Walk the chain
+    let chain: Vec<String> = err.chain().map(|e| e.to_string()).collect();
+    assert_eq!(chain, ["parsing y = \"zero\"", "parse error InvalidInt"]);
+    assert_eq!(err.root_cause().to_string(), "parse error InvalidInt");
+
+    // Downcast to the concrete error, through the context
+    assert_eq!(
+        err.downcast_ref::<ParseError>(),
+        Some(&ParseError::InvalidInt)
+    );
+    assert!(err.downcast_ref::<MathError>().is_none());
+
+    let err = f3("1", "0").unwrap_err();
+    match err.downcast::<MathError>() {
+        Ok(e) => assert_eq!(e, MathError::DivByZero),
+        Err(_) => unreachable!(),
+    }
+    // A failed downcast gives the error back unchanged
+    let err = f3("1", "0").unwrap_err();
+    let err = err.downcast::<ParseError>().unwrap_err();
+    assert_eq!(format!("{err:#}"), "dividing 1 by 0: math error DivByZero");
+
+    // Context on Option
+    let args: Vec<String> = vec!["any_error".to_string()];
+    let err = args.get(1).context("missing file argument").unwrap_err();
+    assert_eq!(err.to_string(), "missing file argument");
+
+    // Context on io::Error and on an AnyError
+    assert_eq!(sum_file("data/box_dyn_error.txt")?, 15);
+    let err = sum_file("data/missing.txt")
+        .context("summing numbers")
+        .unwrap_err();
+    info!("{err}\n");
+    assert_eq!(err.chain().count(), 3);
+    assert!(err.downcast_ref::<std::io::Error>().is_some());
+    assert_eq!(
+        err.root_cause().to_string(),
+        "No such file or directory (os error 2)"
+    );
+
+    let err = sum_lines("input", "1\n2\nthree\n").unwrap_err();
+    info!("{err}\n");
+    assert_eq!(format!("{err:#}"), "input:3: parse error InvalidInt");
+
+    // Debug adds the backtrace when RUST_BACKTRACE=1
+    info!("{:?}", AnyError::msg("done").context("main"));
+    Ok(())
+}
@@ -1,5 +1,6 @@
 #![allow(unused)]
 
+use hello_rust::info;
 use std::sync::{Arc, Mutex, MutexGuard};
 use std::thread;
 
@@ -28,5 +29,5 @@ fn main() {
     t1.join().unwrap();
     t2.join().unwrap();
 
-    println!("{:?}", counter);
+    info!("{:?}", counter);
 }
@@ -1,9 +1,11 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 fn main() {
     // Array - fixed size, known at compile time
     let arr: [u32; 3] = [1, 2, 3];
-    println!("arr[0] = {}", arr[0]);
+    info!("arr[0] = {}", arr[0]);
 
     // Write
     let mut arr: [u32; 3] = [1, 2, 3];
@@ -11,7 +13,7 @@ fn main() {
 
     // All elements = 0
     let arr: [i32; 10] = [0; 10];
-    println!("{:?}", arr);
+    info!("{:?}", arr);
 
     // Slice - size not known at compile time
     let nums: [i32; 10] = [-1, 1, -2, 2, -3, 3, -4, 4, -5, 5];
@@ -20,19 +22,19 @@ fn main() {
     let s: &[i32] = &nums[0..3];
     // Optional 0 index
     let s = &nums[..3];
-    println!("{:?}", s);
+    info!("{:?}", s);
 
     // Middle 4
     let s = &nums[3..7];
-    println!("{:?}", s);
+    info!("{:?}", s);
 
     // Last 3
     let s = &nums[7..10];
     // Optional last index
     let s = &nums[7..];
-    println!("{:?}", s);
+    info!("{:?}", s);
 
     // All
     let s = &nums[..];
-    println!("{:?}", s);
+    info!("{:?}", s);
 }
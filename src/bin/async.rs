@@ -1,5 +1,6 @@
 #![allow(unused)]
 
+use hello_rust::info;
 use std::time::{Duration, Instant};
 use tokio::join;
 use tokio::task::JoinHandle;
@@ -45,7 +46,7 @@ async fn make_hamburger_seq() -> Hamburger {
     let cheese = get_cheese().await;
     // and then wait until cheese is ready
 
-    println!("🍔 is ready");
+    info!("🍔 is ready");
 
     Hamburger {
         tomato,
@@ -69,7 +70,7 @@ async fn make_hamburger() -> Hamburger {
     let (bun, patty, (tomato, lettuce), cheese) =
         join!(toast_bun(), cook_patty(), get_veggies(), get_cheese());
 
-    println!("🍔 is ready");
+    info!("🍔 is ready");
 
     Hamburger {
         tomato,
@@ -98,7 +99,7 @@ async fn main() {
     for i in 0..1000000 {
         handles.push(std::thread::spawn(move || {
             std::thread::sleep(Duration::from_millis(100));
-            println!("{i}: 🍔 is ready");
+            info!("{i}: 🍔 is ready");
         }));
     }
 
@@ -116,7 +117,7 @@ async fn main() {
             // Do not use std::thread:sleep.
             // It will block this thread and this code will run sequentially.
             tokio::time::sleep(Duration::from_millis(100)).await;
-            println!("{i}: 🍔 is ready");
+            info!("{i}: 🍔 is ready");
         };
 
         // Spawning executes future immediately
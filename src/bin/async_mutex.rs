@@ -1,5 +1,6 @@
 #![allow(unused)]
 
+use hello_rust::info;
 use std::sync::Arc;
 // use std::sync::Mutex;
 use tokio::join;
@@ -24,5 +25,5 @@ async fn main() {
     join!(f(logger.clone(), "f1"), f(logger.clone(), "f2"));
     f(logger.clone(), "f3").await;
 
-    println!("{:?}", logger);
+    info!("{:?}", logger);
 }
@@ -0,0 +1,662 @@
+#![allow(unused)]
+
+// Compact binary encoding
+// - Varint integers (LEB128, zigzag for signed)
+// - Length prefixed strings and vectors
+// - Tagged enum variants
+// - Message header with schema version
+// - Structs are length + field count + fields, new optional fields are added at the end
+//   (old decoders skip them, new decoders read None from old data)
+// - Optional fields always have a presence tag, so more can be added after them
+// - Bytes left after the message are an error
+
+use hello_rust::info;
+use std::fmt;
+use std::sync::mpsc;
+use std::thread;
+
+const MAGIC: &[u8; 2] = b"HR";
+const VERSION: u64 = 2;
+// Upper bound for any length prefix, independent of the input size
+const MAX_LEN: usize = 1 << 20;
+
+#[derive(Debug, PartialEq)]
+enum DecodeError {
+    UnexpectedEof,
+    BadMagic,
+    UnsupportedVersion(u64),
+    VarintOverflow,
+    LengthTooLarge { len: u64, max: usize },
+    InvalidUtf8,
+    InvalidTag { type_name: &'static str, tag: u64 },
+    OutOfRange,
+    // Required field not written by the sender
+    MissingField { index: u64 },
+    TrailingBytes(usize),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "decode error {:?}", self)
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+
+    fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    fn bytes(&mut self, n: usize) -> Result<&'a [u8], DecodeError> {
+        if n > self.remaining() {
+            return Err(DecodeError::UnexpectedEof);
+        }
+        let out = &self.buf[self.pos..self.pos + n];
+        self.pos += n;
+        Ok(out)
+    }
+
+    fn varint(&mut self) -> Result<u64, DecodeError> {
+        let mut out = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.bytes(1)?[0];
+            let bits = (byte & 0x7f) as u64;
+            // 10th byte can only carry 1 bit
+            if shift == 63 && bits > 1 {
+                return Err(DecodeError::VarintOverflow);
+            }
+            out |= bits << shift;
+            if byte & 0x80 == 0 {
+                return Ok(out);
+            }
+        }
+        Err(DecodeError::VarintOverflow)
+    }
+
+    // Length prefix checked against MAX_LEN and the bytes left.
+    // min_size = smallest encoded size of one element, so a hostile prefix
+    // can never make us allocate more than the input could hold.
+    fn len(&mut self, min_size: usize) -> Result<usize, DecodeError> {
+        let len = self.varint()?;
+        let max = (self.remaining() / min_size.max(1)).min(MAX_LEN);
+        if len > max as u64 {
+            return Err(DecodeError::LengthTooLarge { len, max });
+        }
+        Ok(len as usize)
+    }
+}
+
+fn write_varint(w: &mut Vec<u8>, mut n: u64) {
+    while n >= 0x80 {
+        w.push((n as u8) | 0x80);
+        n >>= 7;
+    }
+    w.push(n as u8);
+}
+
+trait Encode {
+    fn encode(&self, w: &mut Vec<u8>);
+}
+
+trait Decode: Sized {
+    fn decode(r: &mut Reader) -> Result<Self, DecodeError>;
+}
+
+impl Encode for u64 {
+    fn encode(&self, w: &mut Vec<u8>) {
+        write_varint(w, *self);
+    }
+}
+
+impl Decode for u64 {
+    fn decode(r: &mut Reader) -> Result<Self, DecodeError> {
+        r.varint()
+    }
+}
+
+impl Encode for u32 {
+    fn encode(&self, w: &mut Vec<u8>) {
+        write_varint(w, *self as u64);
+    }
+}
+
+impl Decode for u32 {
+    fn decode(r: &mut Reader) -> Result<Self, DecodeError> {
+        u32::try_from(r.varint()?).map_err(|_| DecodeError::OutOfRange)
+    }
+}
+
+impl Encode for u8 {
+    fn encode(&self, w: &mut Vec<u8>) {
+        w.push(*self);
+    }
+}
+
+impl Decode for u8 {
+    fn decode(r: &mut Reader) -> Result<Self, DecodeError> {
+        Ok(r.bytes(1)?[0])
+    }
+}
+
+// Zigzag: 0 -> 0, -1 -> 1, 1 -> 2, -2 -> 3 ...
+impl Encode for i64 {
+    fn encode(&self, w: &mut Vec<u8>) {
+        write_varint(w, ((self << 1) ^ (self >> 63)) as u64);
+    }
+}
+
+impl Decode for i64 {
+    fn decode(r: &mut Reader) -> Result<Self, DecodeError> {
+        let n = r.varint()?;
+        Ok(((n >> 1) as i64) ^ -((n & 1) as i64))
+    }
+}
+
+impl Encode for i32 {
+    fn encode(&self, w: &mut Vec<u8>) {
+        (*self as i64).encode(w);
+    }
+}
+
+impl Decode for i32 {
+    fn decode(r: &mut Reader) -> Result<Self, DecodeError> {
+        i32::try_from(i64::decode(r)?).map_err(|_| DecodeError::OutOfRange)
+    }
+}
+
+impl Encode for f32 {
+    fn encode(&self, w: &mut Vec<u8>) {
+        w.extend_from_slice(&self.to_le_bytes());
+    }
+}
+
+impl Decode for f32 {
+    fn decode(r: &mut Reader) -> Result<Self, DecodeError> {
+        Ok(f32::from_le_bytes(r.bytes(4)?.try_into().unwrap()))
+    }
+}
+
+impl Encode for bool {
+    fn encode(&self, w: &mut Vec<u8>) {
+        w.push(*self as u8);
+    }
+}
+
+impl Decode for bool {
+    fn decode(r: &mut Reader) -> Result<Self, DecodeError> {
+        match r.bytes(1)?[0] {
+            0 => Ok(false),
+            1 => Ok(true),
+            tag => Err(DecodeError::InvalidTag {
+                type_name: "bool",
+                tag: tag as u64,
+            }),
+        }
+    }
+}
+
+impl Encode for str {
+    fn encode(&self, w: &mut Vec<u8>) {
+        write_varint(w, self.len() as u64);
+        w.extend_from_slice(self.as_bytes());
+    }
+}
+
+impl Encode for String {
+    fn encode(&self, w: &mut Vec<u8>) {
+        self.as_str().encode(w);
+    }
+}
+
+impl Decode for String {
+    fn decode(r: &mut Reader) -> Result<Self, DecodeError> {
+        let len = r.len(1)?;
+        let bytes = r.bytes(len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| DecodeError::InvalidUtf8)
+    }
+}
+
+impl<T: Encode> Encode for Vec<T> {
+    fn encode(&self, w: &mut Vec<u8>) {
+        write_varint(w, self.len() as u64);
+        for item in self {
+            item.encode(w);
+        }
+    }
+}
+
+impl<T: Decode> Decode for Vec<T> {
+    fn decode(r: &mut Reader) -> Result<Self, DecodeError> {
+        // Every element takes at least 1 byte
+        let len = r.len(1)?;
+        let mut out = Vec::with_capacity(len);
+        for _ in 0..len {
+            out.push(T::decode(r)?);
+        }
+        Ok(out)
+    }
+}
+
+impl<T: Encode> Encode for Option<T> {
+    fn encode(&self, w: &mut Vec<u8>) {
+        match self {
+            None => w.push(0),
+            Some(v) => {
+                w.push(1);
+                v.encode(w);
+            }
+        }
+    }
+}
+
+impl<T: Decode> Decode for Option<T> {
+    fn decode(r: &mut Reader) -> Result<Self, DecodeError> {
+        match r.bytes(1)?[0] {
+            0 => Ok(None),
+            1 => Ok(Some(T::decode(r)?)),
+            tag => Err(DecodeError::InvalidTag {
+                type_name: "Option",
+                tag: tag as u64,
+            }),
+        }
+    }
+}
+
+struct FieldWriter {
+    count: u64,
+    body: Vec<u8>,
+}
+
+impl FieldWriter {
+    fn field<T: Encode + ?Sized>(&mut self, value: &T) {
+        self.count += 1;
+        value.encode(&mut self.body);
+    }
+}
+
+// Struct = length prefix + field count + fields
+fn encode_struct(w: &mut Vec<u8>, fields: impl FnOnce(&mut FieldWriter)) {
+    let mut f = FieldWriter {
+        count: 0,
+        body: Vec::new(),
+    };
+    fields(&mut f);
+    let mut count = Vec::new();
+    write_varint(&mut count, f.count);
+    write_varint(w, (count.len() + f.body.len()) as u64);
+    w.extend_from_slice(&count);
+    w.extend_from_slice(&f.body);
+}
+
+struct FieldReader<'a> {
+    r: Reader<'a>,
+    // Fields written by the sender
+    count: u64,
+    read: u64,
+}
+
+impl FieldReader<'_> {
+    // Field that every version writes
+    fn required<T: Decode>(&mut self) -> Result<T, DecodeError> {
+        if self.read == self.count {
+            return Err(DecodeError::MissingField { index: self.read });
+        }
+        self.read += 1;
+        T::decode(&mut self.r)
+    }
+
+    // Field added later, None if the sender is older
+    fn optional<T: Decode>(&mut self) -> Result<Option<T>, DecodeError> {
+        if self.read == self.count {
+            return Ok(None);
+        }
+        self.read += 1;
+        Option::<T>::decode(&mut self.r)
+    }
+}
+
+// Fields newer than the decoder are skipped.
+// Bytes left after the last field the sender wrote are an error.
+fn decode_struct<T>(
+    r: &mut Reader,
+    f: impl FnOnce(&mut FieldReader) -> Result<T, DecodeError>,
+) -> Result<T, DecodeError> {
+    let len = r.len(1)?;
+    let mut body = Reader::new(r.bytes(len)?);
+    let count = body.varint()?;
+    let mut fields = FieldReader {
+        r: body,
+        count,
+        read: 0,
+    };
+    let value = f(&mut fields)?;
+    if fields.read == fields.count && !fields.r.is_empty() {
+        return Err(DecodeError::TrailingBytes(fields.r.remaining()));
+    }
+    Ok(value)
+}
+
+// Message = magic + version + value
+fn to_bytes<T: Encode>(value: &T) -> Vec<u8> {
+    let mut w = MAGIC.to_vec();
+    write_varint(&mut w, VERSION);
+    value.encode(&mut w);
+    w
+}
+
+fn from_bytes<T: Decode>(buf: &[u8]) -> Result<T, DecodeError> {
+    let mut r = Reader::new(buf);
+    if r.bytes(2).map_err(|_| DecodeError::BadMagic)? != MAGIC {
+        return Err(DecodeError::BadMagic);
+    }
+    let version = r.varint()?;
+    if version == 0 || version > VERSION {
+        return Err(DecodeError::UnsupportedVersion(version));
+    }
+    let value = T::decode(&mut r)?;
+    if !r.is_empty() {
+        return Err(DecodeError::TrailingBytes(r.remaining()));
+    }
+    Ok(value)
+}
+
+// Types from struct.rs, enum.rs and async.rs
+#[derive(Debug, PartialEq)]
+struct Point {
+    x: f32,
+    y: f32,
+    // Added in version 2
+    label: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Circle {
+    center: Point,
+    radius: u32,
+}
+
+#[derive(Debug, PartialEq)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+    Rgba(u8, u8, u8, f32),
+    Hex(String),
+    Hsl { h: u8, s: u8, l: u8 },
+}
+
+#[derive(Debug, PartialEq)]
+struct Hamburger {
+    patties: u32,
+    cheese: bool,
+    toppings: Vec<String>,
+}
+
+impl Encode for Point {
+    fn encode(&self, w: &mut Vec<u8>) {
+        encode_struct(w, |f| {
+            f.field(&self.x);
+            f.field(&self.y);
+            f.field(&self.label);
+        });
+    }
+}
+
+impl Decode for Point {
+    fn decode(r: &mut Reader) -> Result<Self, DecodeError> {
+        decode_struct(r, |f| {
+            Ok(Point {
+                x: f.required()?,
+                y: f.required()?,
+                label: f.optional()?,
+            })
+        })
+    }
+}
+
+// Point as a future version would define it, with a second optional field
+#[derive(Debug, PartialEq)]
+struct PointV3 {
+    x: f32,
+    y: f32,
+    label: Option<String>,
+    z: Option<f32>,
+}
+
+impl Encode for PointV3 {
+    fn encode(&self, w: &mut Vec<u8>) {
+        encode_struct(w, |f| {
+            f.field(&self.x);
+            f.field(&self.y);
+            f.field(&self.label);
+            f.field(&self.z);
+        });
+    }
+}
+
+impl Decode for PointV3 {
+    fn decode(r: &mut Reader) -> Result<Self, DecodeError> {
+        decode_struct(r, |f| {
+            Ok(PointV3 {
+                x: f.required()?,
+                y: f.required()?,
+                label: f.optional()?,
+                z: f.optional()?,
+            })
+        })
+    }
+}
+
+impl Encode for Circle {
+    fn encode(&self, w: &mut Vec<u8>) {
+        encode_struct(w, |f| {
+            f.field(&self.center);
+            f.field(&self.radius);
+        });
+    }
+}
+
+impl Decode for Circle {
+    fn decode(r: &mut Reader) -> Result<Self, DecodeError> {
+        decode_struct(r, |f| {
+            Ok(Circle {
+                center: f.required()?,
+                radius: f.required()?,
+            })
+        })
+    }
+}
+
+// Enum = tag + variant fields
+impl Encode for Color {
+    fn encode(&self, w: &mut Vec<u8>) {
+        match self {
+            Color::Red => write_varint(w, 0),
+            Color::Green => write_varint(w, 1),
+            Color::Blue => write_varint(w, 2),
+            Color::Rgba(r, g, b, a) => {
+                write_varint(w, 3);
+                r.encode(w);
+                g.encode(w);
+                b.encode(w);
+                a.encode(w);
+            }
+            Color::Hex(s) => {
+                write_varint(w, 4);
+                s.encode(w);
+            }
+            Color::Hsl { h, s, l } => {
+                write_varint(w, 5);
+                h.encode(w);
+                s.encode(w);
+                l.encode(w);
+            }
+        }
+    }
+}
+
+impl Decode for Color {
+    fn decode(r: &mut Reader) -> Result<Self, DecodeError> {
+        match r.varint()? {
+            0 => Ok(Color::Red),
+            1 => Ok(Color::Green),
+            2 => Ok(Color::Blue),
+            3 => Ok(Color::Rgba(
+                u8::decode(r)?,
+                u8::decode(r)?,
+                u8::decode(r)?,
+                f32::decode(r)?,
+            )),
+            4 => Ok(Color::Hex(String::decode(r)?)),
+            5 => Ok(Color::Hsl {
+                h: u8::decode(r)?,
+                s: u8::decode(r)?,
+                l: u8::decode(r)?,
+            }),
+            tag => Err(DecodeError::InvalidTag {
+                type_name: "Color",
+                tag,
+            }),
+        }
+    }
+}
+
+impl Encode for Hamburger {
+    fn encode(&self, w: &mut Vec<u8>) {
+        encode_struct(w, |f| {
+            f.field(&self.patties);
+            f.field(&self.cheese);
+            f.field(&self.toppings);
+        });
+    }
+}
+
+impl Decode for Hamburger {
+    fn decode(r: &mut Reader) -> Result<Self, DecodeError> {
+        decode_struct(r, |f| {
+            Ok(Hamburger {
+                patties: f.required()?,
+                cheese: f.required()?,
+                toppings: f.required()?,
+            })
+        })
+    }
+}
+
+fn main() {
+    // Varint - small numbers take 1 byte
+    for n in [0u64, 1, 127, 128, 300, u64::MAX] {
+        let mut w = Vec::new();
+        n.encode(&mut w);
+        info!("{n} -> {w:02x?}");
+    }
+    let mut w = Vec::new();
+    (-1i64).encode(&mut w);
+    info!("-1 -> {w:02x?}");
+
+    let circle = Circle {
+        center: Point {
+            x: 1.0,
+            y: 2.0,
+            label: None,
+        },
+        radius: 3,
+    };
+    let bytes = to_bytes(&circle);
+    info!("{:?} -> {} bytes {:02x?}", circle, bytes.len(), bytes);
+    info!("{:?}", from_bytes::<Circle>(&bytes));
+
+    // Backward compatibility
+    // A version 1 Point (2 fields, no label) decodes with label = None
+    let v1: &[u8] = &[b'H', b'R', 1, 9, 2, 0, 0, 0x80, 0x3f, 0, 0, 0, 0x40];
+    let point = from_bytes::<Point>(v1).unwrap();
+    info!("v1 point = {point:?}");
+    assert_eq!(point.label, None);
+
+    // Forward compatibility, a second optional field after label
+    // - Old decoders skip z
+    // - New decoders read z = None from old data
+    // - label = None does not hide z
+    let v3 = PointV3 {
+        x: 1.0,
+        y: 2.0,
+        label: None,
+        z: Some(3.0),
+    };
+    let bytes = to_bytes(&v3);
+    assert_eq!(from_bytes::<PointV3>(&bytes), Ok(v3));
+    let point = from_bytes::<Point>(&bytes).unwrap();
+    assert_eq!((point.x, point.y, point.label), (1.0, 2.0, None));
+    let bytes = to_bytes(&Point {
+        x: 1.0,
+        y: 2.0,
+        label: Some("p".to_string()),
+    });
+    let v3 = from_bytes::<PointV3>(&bytes).unwrap();
+    assert_eq!((v3.label.as_deref(), v3.z), (Some("p"), None));
+
+    // Leftover bytes after the message or after the last field
+    let mut bytes = to_bytes(&circle);
+    bytes.push(0);
+    assert_eq!(
+        from_bytes::<Circle>(&bytes),
+        Err(DecodeError::TrailingBytes(1))
+    );
+    let bad: &[u8] = &[b'H', b'R', 1, 10, 2, 0, 0, 0x80, 0x3f, 0, 0, 0, 0x40, 7];
+    assert_eq!(from_bytes::<Point>(bad), Err(DecodeError::TrailingBytes(1)));
+    // Required field missing
+    let bad: &[u8] = &[b'H', b'R', 1, 5, 1, 0, 0, 0x80, 0x3f];
+    assert_eq!(
+        from_bytes::<Point>(bad),
+        Err(DecodeError::MissingField { index: 1 })
+    );
+
+    // Color variants
+    let colors = vec![
+        Color::Red,
+        Color::Rgba(100, 200, 0, 0.4),
+        Color::Hex(String::from("ffffff")),
+        Color::Hsl { h: 1, s: 2, l: 0 },
+    ];
+    let bytes = to_bytes(&colors);
+    info!("colors -> {} bytes", bytes.len());
+    info!("{:?}", from_bytes::<Vec<Color>>(&bytes));
+
+    // Hostile input - huge length prefix is rejected without allocating
+    let mut hostile = MAGIC.to_vec();
+    write_varint(&mut hostile, VERSION);
+    write_varint(&mut hostile, u64::MAX >> 1);
+    info!("{:?}", from_bytes::<Vec<String>>(&hostile));
+    info!("{:?}", from_bytes::<Color>(&[b'H', b'R', 2, 9]));
+    info!("{:?}", from_bytes::<Color>(&[b'H', b'R', 9, 0]));
+
+    // Send encoded messages over a channel (channel.rs)
+    let (tx, rx) = mpsc::channel::<Vec<u8>>();
+    thread::spawn(move || {
+        for i in 1..=3 {
+            let burger = Hamburger {
+                patties: i,
+                cheese: i % 2 == 1,
+                toppings: vec!["tomato".to_string(), "lettuce".to_string()],
+            };
+            tx.send(to_bytes(&burger)).unwrap();
+        }
+    });
+    for msg in rx {
+        info!("{} bytes -> {:?}", msg.len(), from_bytes::<Hamburger>(&msg));
+    }
+}
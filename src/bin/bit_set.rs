@@ -0,0 +1,335 @@
+#![allow(unused)]
+
+// BitSet
+// - Set of small integers, 1 bit per possible value (HashSet<u32> uses 4+ bytes per value)
+// - Backed by Vec<u64>, bit i of word w = value w * 64 + i
+// - Set algebra in place and as iterators
+// - rank / select
+
+use hello_rust::info;
+use std::collections::HashSet;
+use std::iter::Copied;
+use std::slice;
+
+#[derive(Debug, Clone, Default)]
+struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    // Room for values in 0..n without growing
+    fn with_capacity(n: usize) -> Self {
+        Self {
+            words: vec![0; n.div_ceil(64)],
+        }
+    }
+
+    fn insert(&mut self, value: usize) -> bool {
+        let (w, bit) = (value / 64, 1 << (value % 64));
+        if w >= self.words.len() {
+            self.words.resize(w + 1, 0);
+        }
+        let inserted = self.words[w] & bit == 0;
+        self.words[w] |= bit;
+        inserted
+    }
+
+    fn remove(&mut self, value: usize) -> bool {
+        let (w, bit) = (value / 64, 1 << (value % 64));
+        match self.words.get_mut(w) {
+            Some(word) if *word & bit != 0 => {
+                *word &= !bit;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn contains(&self, value: usize) -> bool {
+        self.words
+            .get(value / 64)
+            .is_some_and(|w| w & (1 << (value % 64)) != 0)
+    }
+
+    // popcount of every word
+    fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    fn clear(&mut self) {
+        self.words.clear();
+    }
+
+    // Remove trailing zero words
+    fn shrink_to_fit(&mut self) {
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+        self.words.shrink_to_fit();
+    }
+
+    fn iter(&self) -> Ones<Copied<slice::Iter<'_, u64>>> {
+        Ones::new(self.words.iter().copied())
+    }
+
+    // Word at i, 0 past the end
+    fn word(&self, i: usize) -> u64 {
+        self.words.get(i).copied().unwrap_or(0)
+    }
+
+    // In place set algebra
+    fn union_with(&mut self, other: &BitSet) {
+        if other.words.len() > self.words.len() {
+            self.words.resize(other.words.len(), 0);
+        }
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a |= b;
+        }
+    }
+
+    fn intersect_with(&mut self, other: &BitSet) {
+        for (i, a) in self.words.iter_mut().enumerate() {
+            *a &= other.word(i);
+        }
+    }
+
+    fn difference_with(&mut self, other: &BitSet) {
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a &= !b;
+        }
+    }
+
+    fn symmetric_difference_with(&mut self, other: &BitSet) {
+        if other.words.len() > self.words.len() {
+            self.words.resize(other.words.len(), 0);
+        }
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a ^= b;
+        }
+    }
+
+    // Lazy set algebra, combines words on the fly
+    fn combine<'a>(
+        &'a self,
+        other: &'a BitSet,
+        f: fn(u64, u64) -> u64,
+    ) -> Ones<impl Iterator<Item = u64> + 'a> {
+        let n = self.words.len().max(other.words.len());
+        Ones::new((0..n).map(move |i| f(self.word(i), other.word(i))))
+    }
+
+    fn union<'a>(&'a self, other: &'a BitSet) -> Ones<impl Iterator<Item = u64> + 'a> {
+        self.combine(other, |a, b| a | b)
+    }
+
+    fn intersection<'a>(&'a self, other: &'a BitSet) -> Ones<impl Iterator<Item = u64> + 'a> {
+        self.combine(other, |a, b| a & b)
+    }
+
+    fn difference<'a>(&'a self, other: &'a BitSet) -> Ones<impl Iterator<Item = u64> + 'a> {
+        self.combine(other, |a, b| a & !b)
+    }
+
+    fn symmetric_difference<'a>(
+        &'a self,
+        other: &'a BitSet,
+    ) -> Ones<impl Iterator<Item = u64> + 'a> {
+        self.combine(other, |a, b| a ^ b)
+    }
+
+    fn is_subset(&self, other: &BitSet) -> bool {
+        self.words
+            .iter()
+            .enumerate()
+            .all(|(i, &w)| w & !other.word(i) == 0)
+    }
+
+    fn is_superset(&self, other: &BitSet) -> bool {
+        other.is_subset(self)
+    }
+
+    fn is_disjoint(&self, other: &BitSet) -> bool {
+        self.words.iter().zip(&other.words).all(|(a, b)| a & b == 0)
+    }
+
+    // Number of values < value
+    fn rank(&self, value: usize) -> usize {
+        let (w, bit) = (value / 64, value % 64);
+        let full: usize = self
+            .words
+            .iter()
+            .take(w)
+            .map(|w| w.count_ones() as usize)
+            .sum();
+        let mask = (1u64 << bit) - 1;
+        full + (self.word(w) & mask).count_ones() as usize
+    }
+
+    // n-th smallest value, 0 based
+    fn select(&self, mut n: usize) -> Option<usize> {
+        for (i, &w) in self.words.iter().enumerate() {
+            let ones = w.count_ones() as usize;
+            if n < ones {
+                // Clear the lowest n bits, then take the lowest remaining one
+                let mut w = w;
+                for _ in 0..n {
+                    w &= w - 1;
+                }
+                return Some(i * 64 + w.trailing_zeros() as usize);
+            }
+            n -= ones;
+        }
+        None
+    }
+
+    fn min(&self) -> Option<usize> {
+        self.iter().next()
+    }
+
+    fn max(&self) -> Option<usize> {
+        self.words
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, &w)| w != 0)
+            .map(|(i, w)| i * 64 + 63 - w.leading_zeros() as usize)
+    }
+}
+
+// Ignores trailing zero words
+impl PartialEq for BitSet {
+    fn eq(&self, other: &Self) -> bool {
+        let n = self.words.len().max(other.words.len());
+        (0..n).all(|i| self.word(i) == other.word(i))
+    }
+}
+
+impl Eq for BitSet {}
+
+// Walks the set bits of each word with trailing_zeros
+struct Ones<I> {
+    words: I,
+    // Bits of the current word not yet returned
+    current: u64,
+    base: usize,
+}
+
+impl<I: Iterator<Item = u64>> Ones<I> {
+    fn new(words: I) -> Self {
+        Self {
+            words,
+            current: 0,
+            // First next() moves base to 0
+            base: usize::MAX - 63,
+        }
+    }
+}
+
+impl<I: Iterator<Item = u64>> Iterator for Ones<I> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.current == 0 {
+            self.current = self.words.next()?;
+            self.base = self.base.wrapping_add(64);
+        }
+        let bit = self.current.trailing_zeros() as usize;
+        // Clear the lowest set bit
+        self.current &= self.current - 1;
+        Some(self.base + bit)
+    }
+}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<T: IntoIterator<Item = usize>>(iter: T) -> Self {
+        let mut set = BitSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<usize> for BitSet {
+    fn extend<T: IntoIterator<Item = usize>>(&mut self, iter: T) {
+        for v in iter {
+            self.insert(v);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a BitSet {
+    type Item = usize;
+    type IntoIter = Ones<Copied<slice::Iter<'a, u64>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+fn main() {
+    // Same as hash_set.rs
+    let mut set = BitSet::new();
+
+    let inserted: bool = set.insert(1);
+    info!("inserted: {inserted}");
+    let inserted: bool = set.insert(1);
+    info!("inserted: {inserted}");
+
+    set.insert(2);
+    set.insert(3);
+
+    info!("1: {}", set.contains(1));
+    info!("2: {}", set.contains(2));
+    info!("3: {}", set.contains(3));
+    info!("4: {}", set.contains(4));
+
+    // Set algebra
+    let a: BitSet = [1, 2, 3, 64, 100].into_iter().collect();
+    let b: BitSet = [2, 3, 4, 100, 200].into_iter().collect();
+    info!("a | b = {:?}", a.union(&b).collect::<Vec<_>>());
+    info!("a & b = {:?}", a.intersection(&b).collect::<Vec<_>>());
+    info!("a - b = {:?}", a.difference(&b).collect::<Vec<_>>());
+    info!(
+        "a ^ b = {:?}",
+        a.symmetric_difference(&b).collect::<Vec<_>>()
+    );
+
+    let mut c = a.clone();
+    c.intersect_with(&b);
+    info!(
+        "c = a & b = {:?}, subset of a: {}",
+        c.iter().collect::<Vec<_>>(),
+        c.is_subset(&a)
+    );
+    info!("a superset of c: {}", a.is_superset(&c));
+
+    // rank / select
+    info!(
+        "len = {}, rank(100) = {}, select(3) = {:?}",
+        a.len(),
+        a.rank(100),
+        a.select(3)
+    );
+    info!("min = {:?}, max = {:?}", a.min(), a.max());
+
+    // Same results as HashSet
+    let values: Vec<usize> = (0..10_000).map(|i| (i * 7919) % 5000).collect();
+    let hash_set: HashSet<usize> = values.iter().copied().collect();
+    let bit_set: BitSet = values.iter().copied().collect();
+    let mut sorted: Vec<usize> = hash_set.iter().copied().collect();
+    sorted.sort();
+    assert_eq!(bit_set.iter().collect::<Vec<_>>(), sorted);
+    info!(
+        "{} values, HashSet ~ {} bytes, BitSet = {} bytes",
+        bit_set.len(),
+        hash_set.capacity() * std::mem::size_of::<usize>(),
+        bit_set.words.len() * 8
+    );
+}
@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 // Borrow - temporarily use a value without taking ownership
 // - Creates a reference (either mutable or immutable)
 // - Doesn't move ownership
@@ -22,7 +24,7 @@ fn main() {
     // This will not compile - multiple mutable reference to s at the same time
     // let s2 = &mut s;
     s1.push_str("🦀");
-    println!("{s}");
+    info!("{s}");
 
     // Mutable borrow - example 2
     // This will compile - multiple mutable reference to s but not simultaneously
@@ -33,14 +35,14 @@ fn main() {
     // so there is only one mutable reference (s2)
     let s2 = &mut s;
     s2.push_str("🦀");
-    println!("{s}");
+    info!("{s}");
 
     // Cannot borrow immutable and mutable simultaneously
     let mut s = String::from("rust");
     let s1 = &s;
     let s2 = &s;
     // let s3 = &mut s;
-    println!("s1 {s1}");
+    info!("s1 {s1}");
 
     // Reference must not outlive the value
     let s = String::from("rust");
@@ -53,7 +55,7 @@ fn main() {
     // Example 2 - force s to drop
     // std::mem::drop(s);
     // Example 3 - see the function dangle
-    println!("s1 {s1}");
+    info!("s1 {s1}");
 }
 
 // Reference must not outlive the value
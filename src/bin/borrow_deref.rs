@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 fn borrow(s: &mut String) {
     // Dereferencing doesn't transfer ownership for &T and &mut T
     *s += "!";
@@ -10,16 +12,16 @@ fn main() {
     let r = &mut x;
     // Dereference and modify
     *r += 1;
-    println!("{x}");
+    info!("{x}");
 
     let mut s = String::from("rust");
     let s1 = &mut s;
     *s1 += "!";
-    println!("{s}");
+    info!("{s}");
 
     let mut s = String::from("rust");
     borrow(&mut s);
-    println!("{s}");
+    info!("{s}");
 
     // Deref coercion
     let x = 1;
@@ -27,5 +29,5 @@ fn main() {
     let z = &x;
     // Automatically dereferenced in some situations
     let w = y + z;
-    println!("{w}");
+    info!("{w}");
 }
@@ -1,11 +1,13 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 fn take(s: String) {
-    println!("take {s}");
+    info!("take {s}");
 }
 
 fn borrow(s: &str) {
-    println!("borrow {s}");
+    info!("borrow {s}");
 }
 
 fn borrow_mut(s: &mut String) {
@@ -13,22 +15,22 @@ fn borrow_mut(s: &mut String) {
 }
 
 fn print_len(s: String) {
-    println!("length = {}", s.len());
+    info!("length = {}", s.len());
 }
 
 fn print_len_return_ownership(s: String) -> String {
-    println!("length = {}", s.len());
+    info!("length = {}", s.len());
     s
 }
 // Note - cannot return &str
 // This will not compile
 // fn print_len_return_ref(s: String) -> &str {
-//     println!("length = {}", s.len());
+//     info!("length = {}", s.len());
 //     &s
 // }
 
 fn print_len_borrow(s: &str) {
-    println!("length = {}", s.len());
+    info!("length = {}", s.len());
 }
 
 fn main() {
@@ -37,19 +39,19 @@ fn main() {
     take(s);
     // s is dropped after take(s)
     // This will not compile
-    // println!("{s}");
+    // info!("{s}");
 
     // Borrow -> doesn't move ownership
     let s = String::from("rust");
     // Rust automatically casts &String into &str
     borrow(&s);
     // s is not dropped after borrow(&s) so s can be printed
-    println!("{s}");
+    info!("{s}");
 
     // Mutable borrow -> doesn't move ownership
     let mut s = String::from("rust");
     borrow_mut(&mut s);
-    println!("{s}");
+    info!("{s}");
 
     // Modify a function in 3 steps
     // 1. Take ownership
@@ -61,18 +63,18 @@ fn main() {
     let s = String::from("rust");
     print_len(s);
     // THis will not compile
-    // println!("{s}");
+    // info!("{s}");
 
     // Example 2
     // Return ownership
     let s = String::from("rust");
     // Ownership of s is returned
     let s = print_len_return_ownership(s);
-    println!("after return {s}");
+    info!("after return {s}");
 
     // Example 3
     // Borrow
     let s = String::from("rust");
     print_len_borrow(&s);
-    println!("after borrow {s}");
+    info!("after borrow {s}");
 }
@@ -1,7 +1,9 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 fn borrow(s: &[i32]) {
-    println!("borrow {:?}", s);
+    info!("borrow {:?}", s);
 }
 
 fn borrow_mut(s: &mut [i32]) {
@@ -18,16 +20,16 @@ fn main() {
     let a: [i32; 5] = [1, 2, 3, 4, 5];
     let s: &[i32] = &a[0..2];
     borrow(s);
-    println!("slice {:?}", s);
+    info!("slice {:?}", s);
 
     // Borrow mut
     let mut a: [i32; 5] = [1, 2, 3, 4, 5];
     let s: &mut [i32] = &mut a[1..3];
     borrow_mut(s);
-    println!("slice {:?}", s);
+    info!("slice {:?}", s);
 
     // Example of function input and output
     let a: [i32; 5] = [1, 2, 3, 4, 5];
     let (s0, s1) = split_at(&a, 2);
-    println!("{:?}, {:?}", s0, s1);
+    info!("{:?}, {:?}", s0, s1);
 }
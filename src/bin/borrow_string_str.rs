@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 // String and str
 
 // String
@@ -46,12 +48,12 @@ fn main() {
     // Moves ownership into take_string
     take_string(s);
     // This won't compile
-    // println!("String: {s}");
+    // info!("String: {s}");
 
     // Mutable
     let mut s = String::from("hello");
     s += "!";
-    println!("mut String: {s}");
+    info!("mut String: {s}");
 
     // &String
     let s = String::from("hello");
@@ -60,13 +62,13 @@ fn main() {
     borrow_string(s1);
     // Can be coerced into &str
     borrow_str(&s);
-    println!("&String: {s}");
+    info!("&String: {s}");
 
     // &mut String
     let mut s = String::from("hello");
     let s1: &mut String = &mut s;
     s1.push_str("!");
-    println!("&mut String: {s}");
+    info!("&mut String: {s}");
 
     // str - string slice
     // - Dynamically sized type / unsized type
@@ -84,7 +86,7 @@ fn main() {
     // - immutable borrow
     let s: &str = "hello";
     borrow_str(s);
-    println!("&str: {s}");
+    info!("&str: {s}");
 
     // &mut str
     // Possible to create &mut str but uncommon. Use mut String.
@@ -29,24 +29,26 @@ struct Tree {
 }
 
 use crate::List::{Cons, Nil};
+use hello_rust::info;
 
 fn main() {
     // Box - allocate data to heap
     let b: Box<i32> = Box::new(0);
     // Dereference to get the inner value
     let v = *b;
-    println!("box: {}", v);
+    info!("box: {}", v);
 
     // List
     // 3 -> 2 -> 1 -> Nil
     let mut list = Cons(3, Box::new(Cons(2, Box::new(Cons(1, Box::new(Nil))))));
     // Example - print all values in list
+    let mut line = String::new();
     while let Cons(i, tail) = list {
-        print!("{} -> ", i);
+        line += &format!("{} -> ", i);
         // Dereference a box
         list = *tail;
     }
-    println!("Nil");
+    info!("{line}Nil");
 
     // Tree
     let tree = Tree {
@@ -68,7 +70,7 @@ fn main() {
     };
     // No need to dereference Box<Tree>
     // Rust automatically dereferences struct fields
-    println!(
+    info!(
         "tree.left.right.val: {:?}",
         tree.left.unwrap().right.unwrap().val
     );
@@ -1,75 +1,403 @@
 #![allow(unused)]
 
-// Implementing std::error::Error for MathError and ParseError
-#[derive(Debug)]
-enum MathError {
-    DivByZero,
+// MathError and ParseError implement std::error::Error (src/errors.rs)
+// Box<dyn Error> holds either, but not where the error came from,
+// any_error.rs returns them as AnyError with context
+use hello_rust::errors::{MathError, ParseError};
+
+fn f1() -> Result<u32, MathError> {
+    Err(MathError::DivByZero)
+}
+
+fn f2() -> Result<u32, ParseError> {
+    Err(ParseError::InvalidInt)
 }
 
+fn f3() -> Result<(), Box<dyn std::error::Error>> {
+    f1()?;
+    f2()?;
+    Ok(())
+}
+
+// Practical example, sum the numbers in files
+// cargo run --bin box_dyn_error -- data/box_dyn_error.txt
+// cargo run --bin box_dyn_error -- --json a.txt b.txt
+// seq 1 100 | cargo run --bin box_dyn_error
+// cargo run --bin box_dyn_error -- --check    (check the data/box_dyn_error*.txt fixtures)
+//
+// - 1 number per line, decimal, 0x 0o 0b, 1_000, +1, -1
+// - Empty lines and # comments are skipped
+// - Every bad line is reported as file:line:col, then the exit code is 1
+use hello_rust::info;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+
+#[derive(Debug, Clone, PartialEq)]
+enum NumError {
+    NoDigits,
+    InvalidDigit(char, u32),
+    TooLarge,
+    SumOverflow,
+}
+
+impl fmt::Display for NumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NumError::NoDigits => write!(f, "no digits"),
+            NumError::InvalidDigit(c, radix) => write!(f, "invalid digit {c:?} for base {radix}"),
+            NumError::TooLarge => write!(f, "number does not fit in i128"),
+            NumError::SumOverflow => write!(f, "sum overflows i128"),
+        }
+    }
+}
+
+impl Error for NumError {}
+
+// data/box_dyn_error.txt:3:2: invalid digit 'z' for base 10
 #[derive(Debug)]
-enum ParseError {
-    InvalidInt,
+struct LineError {
+    path: String,
+    line: usize,
+    col: usize,
+    reason: Box<dyn Error>,
 }
 
-impl std::fmt::Display for MathError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "math error {:?}", self)
+impl fmt::Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: {}",
+            self.path, self.line, self.col, self.reason
+        )
     }
 }
 
-impl std::error::Error for MathError {}
+impl Error for LineError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self.reason.as_ref())
+    }
+}
 
-impl std::fmt::Display for ParseError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "parse error {:?}", self)
+// Err = reason and char offset of the bad char
+fn parse_number(s: &str) -> Result<i128, (NumError, usize)> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut i = 0;
+    let neg = chars.first() == Some(&'-');
+    if matches!(chars.first(), Some('-' | '+')) {
+        i += 1;
+    }
+    let radix = match (chars.get(i), chars.get(i + 1)) {
+        (Some('0'), Some('x' | 'X')) => 16,
+        (Some('0'), Some('o' | 'O')) => 8,
+        (Some('0'), Some('b' | 'B')) => 2,
+        _ => 10,
+    };
+    if radix != 10 {
+        i += 2;
+    }
+    // 1_000 but not _1
+    if chars.get(i) == Some(&'_') {
+        return Err((NumError::InvalidDigit('_', radix), i));
+    }
+    let mut mag: u128 = 0;
+    let mut digits = 0;
+    for (j, &c) in chars.iter().enumerate().skip(i) {
+        if c == '_' {
+            continue;
+        }
+        let Some(d) = c.to_digit(radix) else {
+            return Err((NumError::InvalidDigit(c, radix), j));
+        };
+        mag = mag
+            .checked_mul(radix as u128)
+            .and_then(|m| m.checked_add(d as u128))
+            .ok_or((NumError::TooLarge, 0))?;
+        digits += 1;
     }
+    if digits == 0 {
+        return Err((NumError::NoDigits, chars.len()));
+    }
+    let n = if neg {
+        0i128.checked_sub_unsigned(mag)
+    } else {
+        i128::try_from(mag).ok()
+    };
+    n.ok_or((NumError::TooLarge, 0))
 }
 
-impl std::error::Error for ParseError {}
+#[derive(Debug, Default)]
+struct Summary {
+    values: Vec<i128>,
+    sum: i128,
+}
 
-fn f1() -> Result<u32, MathError> {
-    Err(MathError::DivByZero)
+impl Summary {
+    fn add(&mut self, n: i128) -> Result<(), NumError> {
+        self.sum = self.sum.checked_add(n).ok_or(NumError::SumOverflow)?;
+        self.values.push(n);
+        Ok(())
+    }
+
+    fn stats(&self) -> Option<Stats> {
+        let mut v = self.values.clone();
+        v.sort_unstable();
+        let n = v.len();
+        let median = match n {
+            0 => return None,
+            _ if n % 2 == 1 => v[n / 2] as f64,
+            _ => v[n / 2 - 1] as f64 / 2.0 + v[n / 2] as f64 / 2.0,
+        };
+        Some(Stats {
+            count: n,
+            sum: self.sum,
+            min: v[0],
+            max: v[n - 1],
+            mean: self.sum as f64 / n as f64,
+            median,
+        })
+    }
 }
 
-fn f2() -> Result<u32, ParseError> {
-    Err(ParseError::InvalidInt)
+#[derive(Debug, PartialEq)]
+struct Stats {
+    count: usize,
+    sum: i128,
+    min: i128,
+    max: i128,
+    mean: f64,
+    median: f64,
 }
 
-fn f3() -> Result<(), Box<dyn std::error::Error>> {
-    f1()?;
-    f2()?;
-    Ok(())
+// Bad lines are pushed to errors, only a read error stops
+fn sum_lines(
+    path: &str,
+    mut input: impl BufRead,
+    summary: &mut Summary,
+    errors: &mut Vec<LineError>,
+) -> Result<(), io::Error> {
+    let mut buf = Vec::new();
+    let mut line_no = 0;
+    loop {
+        buf.clear();
+        if input.read_until(b'\n', &mut buf)? == 0 {
+            return Ok(());
+        }
+        line_no += 1;
+        let mut error = |col: usize, reason: Box<dyn Error>| {
+            errors.push(LineError {
+                path: path.to_string(),
+                line: line_no,
+                col,
+                reason,
+            })
+        };
+        let line = match std::str::from_utf8(&buf) {
+            Ok(line) => line,
+            Err(e) => {
+                let col = String::from_utf8_lossy(&buf[..e.valid_up_to()])
+                    .chars()
+                    .count();
+                error(col + 1, Box::new(e));
+                continue;
+            }
+        };
+        let line = line.split('#').next().unwrap();
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+        let text = trimmed.trim_end();
+        if text.is_empty() {
+            continue;
+        }
+        // 1 based column in chars
+        let col = line[..indent].chars().count() + 1;
+        match parse_number(text) {
+            Ok(n) => {
+                if let Err(e) = summary.add(n) {
+                    error(col, Box::new(e));
+                }
+            }
+            Err((e, offset)) => error(col + offset, Box::new(e)),
+        }
+    }
 }
 
-// Practical example
-use std::env;
-use std::fs::File;
-use std::io::Read;
-use std::num::ParseIntError;
+fn print_text(stats: &Option<Stats>) {
+    let Some(s) = stats else {
+        info!("count  0");
+        return;
+    };
+    info!("count  {}", s.count);
+    info!("sum    {}", s.sum);
+    info!("min    {}", s.min);
+    info!("max    {}", s.max);
+    info!("mean   {}", s.mean);
+    info!("median {}", s.median);
+}
 
-fn read(src_path: &str) -> Result<Vec<String>, std::io::Error> {
-    let mut src_file = File::open(src_path)?;
-    let mut data = String::new();
-    src_file.read_to_string(&mut data)?;
+fn json(stats: &Option<Stats>) -> String {
+    match stats {
+        Some(s) => format!(
+            "{{\"count\":{},\"sum\":{},\"min\":{},\"max\":{},\"mean\":{:?},\"median\":{:?}}}",
+            s.count, s.sum, s.min, s.max, s.mean, s.median
+        ),
+        None => "{\"count\":0,\"sum\":0,\"min\":null,\"max\":null,\"mean\":null,\"median\":null}"
+            .to_string(),
+    }
+}
 
-    let lines: Vec<String> = data.trim().split('\n').map(|s| s.to_string()).collect();
+fn sum_str(input: &str) -> (Summary, Vec<LineError>) {
+    let mut summary = Summary::default();
+    let mut errors = Vec::new();
+    sum_lines("input", input.as_bytes(), &mut summary, &mut errors).unwrap();
+    (summary, errors)
+}
+
+fn check_fixtures() -> Result<(), Box<dyn Error>> {
+    let mut summary = Summary::default();
+    let mut errors = Vec::new();
+    let path = "data/box_dyn_error.txt";
+    sum_lines(
+        path,
+        BufReader::new(File::open(path)?),
+        &mut summary,
+        &mut errors,
+    )?;
+    assert!(errors.is_empty());
+    let stats = summary.stats().unwrap();
+    assert_eq!(
+        (stats.count, stats.sum, stats.min, stats.max),
+        (5, 15, 1, 5)
+    );
+    assert_eq!((stats.mean, stats.median), (3.0, 3.0));
+
+    let mut summary = Summary::default();
+    let mut errors = Vec::new();
+    let path = "data/box_dyn_error_bad.txt";
+    sum_lines(
+        path,
+        BufReader::new(File::open(path)?),
+        &mut summary,
+        &mut errors,
+    )?;
+    let got: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+    let expected = [
+        "data/box_dyn_error_bad.txt:6:2: invalid digit 'z' for base 10",
+        "data/box_dyn_error_bad.txt:7:3: invalid digit 'g' for base 16",
+        "data/box_dyn_error_bad.txt:8:1: invalid digit '_' for base 10",
+        "data/box_dyn_error_bad.txt:9:2: no digits",
+        "data/box_dyn_error_bad.txt:10:5: invalid digit '.' for base 10",
+        "data/box_dyn_error_bad.txt:11:1: number does not fit in i128",
+        "data/box_dyn_error_bad.txt:12:3: invalid digit ' ' for base 10",
+    ];
+    assert_eq!(got, expected);
+    let stats = summary.stats().unwrap();
+    // 0xff + 1_000 - 42 + 0b101 + 0o17 + 10 + -3
+    assert_eq!(stats.count, 7);
+    assert_eq!(stats.sum, 255 + 1_000 - 42 + 5 + 15 + 10 - 3);
+    assert_eq!((stats.min, stats.max, stats.median), (-42, 1000, 10.0));
+
+    // i128 limits
+    let (summary, errors) = sum_str("-0x8000_0000_0000_0000_0000_0000_0000_0000\n");
+    assert!(errors.is_empty());
+    assert_eq!(summary.sum, i128::MIN);
+    let (_, errors) = sum_str("0x8000_0000_0000_0000_0000_0000_0000_0000\n");
+    assert_eq!(
+        errors[0].to_string(),
+        "input:1:1: number does not fit in i128"
+    );
+    let max = i128::MAX.to_string();
+    let (summary, errors) = sum_str(&format!("{max}\n1\n-1\n"));
+    assert_eq!(errors[0].to_string(), "input:2:1: sum overflows i128");
+    assert_eq!(summary.sum, i128::MAX - 1);
+    // The reason is the source of the error
+    assert!(errors[0].source().unwrap().is::<NumError>());
+
+    // Invalid UTF-8 and no trailing newline
+    let mut summary = Summary::default();
+    let mut errors = Vec::new();
+    sum_lines("input", &b"1\n2\xff\n3"[..], &mut summary, &mut errors)?;
+    assert_eq!(
+        errors[0].to_string().lines().next(),
+        Some("input:2:2: invalid utf-8 sequence of 1 bytes from index 1")
+    );
+    assert_eq!(summary.sum, 4);
+
+    // Empty input
+    let (summary, errors) = sum_str("# nothing\n\n");
+    assert!(summary.stats().is_none() && errors.is_empty());
+    assert_eq!(
+        json(&summary.stats()),
+        "{\"count\":0,\"sum\":0,\"min\":null,\"max\":null,\"mean\":null,\"median\":null}"
+    );
+
+    let (summary, _) = sum_str("1\n2\n");
+    assert_eq!(
+        json(&summary.stats()),
+        "{\"count\":2,\"sum\":3,\"min\":1,\"max\":2,\"mean\":1.5,\"median\":1.5}"
+    );
+
+    // f3 fails with the MathError of f1, only its type is left
+    let err = f3().unwrap_err();
+    assert_eq!(err.to_string(), "math error DivByZero");
+    assert!(err.is::<MathError>());
 
-    Ok(lines)
+    info!("fixtures ok");
+    Ok(())
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let args: Vec<String> = env::args().collect();
-    // read returns Result<Vec<String>, std::io::Error>
-    let lines = read(&args[1])?;
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut paths = Vec::new();
+    let mut json_output = false;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--json" => json_output = true,
+            "--check" => return check_fixtures(),
+            "-h" | "--help" => {
+                info!("usage: box_dyn_error [--json] [FILE...]");
+                info!("reads stdin if there is no FILE or FILE is -");
+                return Ok(());
+            }
+            _ if arg.starts_with("--") => return Err(format!("unknown option {arg}").into()),
+            _ => paths.push(arg),
+        }
+    }
+    if paths.is_empty() {
+        paths.push("-".to_string());
+    }
 
-    let mut sum: i32 = 0;
-    for line in lines {
-        // parse returns Result<i32, ParseIntError>
-        let num: i32 = line.parse()?;
-        sum += num;
+    let mut summary = Summary::default();
+    let mut errors = Vec::new();
+    let mut failed_files = 0;
+    for path in &paths {
+        // Box<dyn Error> holds both io::Error and LineError
+        let result: Result<(), Box<dyn Error>> = if path == "-" {
+            sum_lines("<stdin>", io::stdin().lock(), &mut summary, &mut errors).map_err(Into::into)
+        } else {
+            File::open(path)
+                .and_then(|f| sum_lines(path, BufReader::new(f), &mut summary, &mut errors))
+                .map_err(Into::into)
+        };
+        if let Err(e) = result {
+            eprintln!("{path}: {e}");
+            failed_files += 1;
+        }
+    }
+    for e in &errors {
+        eprintln!("{e}");
     }
 
-    println!("{}", sum);
+    let stats = summary.stats();
+    if json_output {
+        info!("{}", json(&stats));
+    } else {
+        print_text(&stats);
+    }
 
-    Ok(())
+    match (errors.len(), failed_files) {
+        (0, 0) => Ok(()),
+        (lines, files) => Err(format!("{lines} bad lines, {files} unreadable files").into()),
+    }
 }
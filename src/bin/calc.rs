@@ -0,0 +1,1433 @@
+#![allow(unused)]
+
+// Calculator REPL with the semantics of Rust's operators (operators.rs, scalar.rs)
+// - Tokenizer -> Pratt parser -> evaluator
+// - Integer and float types with literal suffixes, 1u8 0xffi16 1_000 1.5e3f32
+// - Unsuffixed literals take their type from the other operand, or i32 / f64
+// - All operators with Rust's precedence, `as` casts
+// - Overflow mode: panic (debug build), checked, wrapping or saturating
+// - Variables: let x = 1u8, x += 2
+//
+// cargo run --bin calc            interactive
+// cargo run --bin calc -- "1 + 2" "-1 % 3" ":mode wrapping" "255u8 + 1"
+
+use hello_rust::diagnostic::{Diagnostic, Renderer, Source, Span as DiagSpan};
+use hello_rust::info;
+use std::collections::HashMap;
+use std::env;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IntTy {
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    Isize,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    Usize,
+}
+
+impl IntTy {
+    fn bits(self) -> u32 {
+        match self {
+            IntTy::I8 | IntTy::U8 => 8,
+            IntTy::I16 | IntTy::U16 => 16,
+            IntTy::I32 | IntTy::U32 => 32,
+            IntTy::I64 | IntTy::U64 | IntTy::Isize | IntTy::Usize => 64,
+            IntTy::I128 | IntTy::U128 => 128,
+        }
+    }
+
+    fn signed(self) -> bool {
+        matches!(
+            self,
+            IntTy::I8 | IntTy::I16 | IntTy::I32 | IntTy::I64 | IntTy::I128 | IntTy::Isize
+        )
+    }
+
+    // Range of a signed type
+    fn min(self) -> i128 {
+        i128::MIN >> (128 - self.bits())
+    }
+
+    fn max(self) -> i128 {
+        i128::MAX >> (128 - self.bits())
+    }
+
+    // Max of an unsigned type, also the mask of the low bits
+    fn max_u(self) -> u128 {
+        u128::MAX >> (128 - self.bits())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FloatTy {
+    F32,
+    F64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Ty {
+    Int(IntTy),
+    Float(FloatTy),
+    Bool,
+    Char,
+}
+
+impl Ty {
+    fn parse(s: &str) -> Option<Ty> {
+        use IntTy::*;
+        let t = match s {
+            "i8" => Ty::Int(I8),
+            "i16" => Ty::Int(I16),
+            "i32" => Ty::Int(I32),
+            "i64" => Ty::Int(I64),
+            "i128" => Ty::Int(I128),
+            "isize" => Ty::Int(Isize),
+            "u8" => Ty::Int(U8),
+            "u16" => Ty::Int(U16),
+            "u32" => Ty::Int(U32),
+            "u64" => Ty::Int(U64),
+            "u128" => Ty::Int(U128),
+            "usize" => Ty::Int(Usize),
+            "f32" => Ty::Float(FloatTy::F32),
+            "f64" => Ty::Float(FloatTy::F64),
+            "bool" => Ty::Bool,
+            "char" => Ty::Char,
+            _ => return None,
+        };
+        Some(t)
+    }
+}
+
+impl fmt::Display for Ty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Ty::Int(t) => return write!(f, "{}", format!("{t:?}").to_lowercase()),
+            Ty::Float(FloatTy::F32) => "f32",
+            Ty::Float(FloatTy::F64) => "f64",
+            Ty::Bool => "bool",
+            Ty::Char => "char",
+        };
+        f.write_str(s)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Value {
+    // Signed values are stored sign extended, unsigned values zero extended
+    Int(i128, IntTy),
+    // u128 does not fit into i128
+    U128(u128),
+    Float(f64, FloatTy),
+    Bool(bool),
+    Char(char),
+}
+
+impl Value {
+    fn ty(&self) -> Ty {
+        match self {
+            Value::Int(_, t) => Ty::Int(*t),
+            Value::U128(_) => Ty::Int(IntTy::U128),
+            Value::Float(_, t) => Ty::Float(*t),
+            Value::Bool(_) => Ty::Bool,
+            Value::Char(_) => Ty::Char,
+        }
+    }
+
+    // Integer from its low bits, truncated to the size of ty
+    fn from_bits(bits: u128, ty: IntTy) -> Value {
+        let n = ty.bits();
+        if ty == IntTy::U128 {
+            Value::U128(bits)
+        } else if ty.signed() {
+            // Shift left then arithmetic shift right to sign extend
+            Value::Int(((bits << (128 - n)) as i128) >> (128 - n), ty)
+        } else {
+            Value::Int((bits & ty.max_u()) as i128, ty)
+        }
+    }
+
+    fn bits(&self) -> Option<u128> {
+        match *self {
+            Value::Int(v, _) => Some(v as u128),
+            Value::U128(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Int(v, _) => write!(f, "{v}"),
+            Value::U128(v) => write!(f, "{v}"),
+            Value::Float(v, FloatTy::F32) => write!(f, "{}", *v as f32),
+            Value::Float(v, FloatTy::F64) => write!(f, "{v}"),
+            Value::Bool(v) => write!(f, "{v}"),
+            Value::Char(v) => write!(f, "{v:?}"),
+        }
+    }
+}
+
+// Position in the input, in chars
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Span {
+    start: usize,
+    end: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ErrorKind {
+    Error(String),
+    // Overflow in checked mode, printed as None
+    None,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Error {
+    kind: ErrorKind,
+    span: Span,
+}
+
+fn error<T>(span: Span, msg: impl Into<String>) -> Result<T, Error> {
+    Err(Error {
+        kind: ErrorKind::Error(msg.into()),
+        span,
+    })
+}
+
+// Tokenizer
+
+#[derive(Debug, Clone, PartialEq)]
+enum Tok {
+    Int(u128, Option<Ty>),
+    Float(f64, Option<Ty>),
+    Char(char),
+    Ident(String),
+    Op(&'static str),
+    Eof,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Token {
+    tok: Tok,
+    span: Span,
+}
+
+// Longest first
+const OPS: &[&str] = &[
+    "<<=", ">>=", "&&", "||", "<<", ">>", "==", "!=", "<=", ">=", "+=", "-=", "*=", "/=", "%=",
+    "^=", "&=", "|=", "+", "-", "*", "/", "%", "^", "!", "&", "|", "<", ">", "=", "(", ")", "::",
+    ":",
+];
+
+fn tokenize(src: &str) -> Result<Vec<Token>, Error> {
+    let chars: Vec<char> = src.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        let tok = if c.is_ascii_digit() {
+            let (tok, end) = number(&chars, i)?;
+            i = end;
+            tok
+        } else if c.is_alphabetic() || c == '_' {
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            Tok::Ident(chars[start..i].iter().collect())
+        } else if c == '\'' {
+            let (c, end) = char_literal(&chars, i)?;
+            i = end;
+            Tok::Char(c)
+        } else {
+            let rest: String = chars[i..chars.len().min(i + 3)].iter().collect();
+            match OPS.iter().find(|op| rest.starts_with(*op)) {
+                Some(op) => {
+                    i += op.len();
+                    Tok::Op(op)
+                }
+                None => {
+                    return error(
+                        Span { start, end: i + 1 },
+                        format!("unexpected character `{c}`"),
+                    )
+                }
+            }
+        };
+        tokens.push(Token {
+            tok,
+            span: Span { start, end: i },
+        });
+    }
+    tokens.push(Token {
+        tok: Tok::Eof,
+        span: Span {
+            start: chars.len(),
+            end: chars.len() + 1,
+        },
+    });
+    Ok(tokens)
+}
+
+// 123 1_000u8 0xff 0b1010i8 0o17 1.5 1e3 2.5e-3f32
+fn number(chars: &[char], start: usize) -> Result<(Tok, usize), Error> {
+    let mut i = start;
+    let radix = match chars.get(i + 1) {
+        Some('x') if chars[i] == '0' => 16,
+        Some('o') if chars[i] == '0' => 8,
+        Some('b') if chars[i] == '0' => 2,
+        _ => 10,
+    };
+    if radix != 10 {
+        i += 2;
+    }
+    let digits_start = i;
+    while i < chars.len() && (chars[i].is_digit(radix) || chars[i] == '_') {
+        i += 1;
+    }
+    let mut is_float = false;
+    if radix == 10 {
+        // 1.5 but not 1..2 or 1.max
+        if chars.get(i) == Some(&'.') && chars.get(i + 1).is_some_and(|c| c.is_ascii_digit()) {
+            is_float = true;
+            i += 1;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '_') {
+                i += 1;
+            }
+        }
+        if matches!(chars.get(i), Some('e' | 'E')) {
+            let sign = usize::from(matches!(chars.get(i + 1), Some('+' | '-')));
+            if chars.get(i + 1 + sign).is_some_and(|c| c.is_ascii_digit()) {
+                is_float = true;
+                i += 1 + sign;
+                while i < chars.len() && chars[i].is_ascii_digit() {
+                    i += 1;
+                }
+            }
+        }
+    }
+    let text: String = chars[digits_start..i]
+        .iter()
+        .filter(|&&c| c != '_')
+        .collect();
+    let suffix_start = i;
+    while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+        i += 1;
+    }
+    let suffix: String = chars[suffix_start..i].iter().collect();
+    let span = Span { start, end: i };
+    let suffix = if suffix.is_empty() {
+        None
+    } else {
+        match Ty::parse(&suffix) {
+            Some(t @ (Ty::Int(_) | Ty::Float(_))) => Some(t),
+            _ => {
+                return error(
+                    Span {
+                        start: suffix_start,
+                        end: i,
+                    },
+                    format!("invalid suffix `{suffix}` for number literal"),
+                )
+            }
+        }
+    };
+    if text.is_empty() {
+        return error(span, "no valid digits found for number");
+    }
+    let tok = if is_float || matches!(suffix, Some(Ty::Float(_))) {
+        if let Some(Ty::Int(_)) = suffix {
+            return error(span, "integer suffix on a float literal");
+        }
+        if radix != 10 {
+            return error(span, "float literals must be decimal");
+        }
+        Tok::Float(text.parse().unwrap(), suffix)
+    } else {
+        match u128::from_str_radix(&text, radix) {
+            Ok(v) => Tok::Int(v, suffix),
+            Err(_) => return error(span, "integer literal is too large"),
+        }
+    };
+    Ok((tok, i))
+}
+
+// 'a' '\n' '\'' '\u{1F980}'
+fn char_literal(chars: &[char], start: usize) -> Result<(char, usize), Error> {
+    let span = |end| Span { start, end };
+    let mut i = start + 1;
+    let c = match chars.get(i) {
+        Some('\\') => {
+            i += 1;
+            match chars.get(i) {
+                Some('n') => '\n',
+                Some('t') => '\t',
+                Some('r') => '\r',
+                Some('0') => '\0',
+                Some('\\') => '\\',
+                Some('\'') => '\'',
+                Some('u') if chars.get(i + 1) == Some(&'{') => {
+                    let close = chars[i..].iter().position(|&c| c == '}');
+                    let Some(close) = close else {
+                        return error(span(chars.len()), "unterminated unicode escape");
+                    };
+                    let hex: String = chars[i + 2..i + close].iter().collect();
+                    i += close;
+                    match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                        Some(c) => c,
+                        None => return error(span(i + 1), "invalid unicode escape"),
+                    }
+                }
+                _ => return error(span(i + 1), "unknown character escape"),
+            }
+        }
+        Some(&c) if c != '\'' => c,
+        _ => return error(span(i + 1), "empty character literal"),
+    };
+    i += 1;
+    if chars.get(i) != Some(&'\'') {
+        return error(span(i), "unterminated character literal");
+    }
+    Ok((c, i + 1))
+}
+
+// Parser
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    BitAnd,
+    BitOr,
+    BitXor,
+    Shl,
+    Shr,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    And,
+    Or,
+}
+
+impl BinOp {
+    fn is_comparison(self) -> bool {
+        matches!(
+            self,
+            BinOp::Eq | BinOp::Ne | BinOp::Lt | BinOp::Le | BinOp::Gt | BinOp::Ge
+        )
+    }
+
+    // Verb of Rust's overflow panic message, "attempt to add with overflow"
+    fn verb(self) -> &'static str {
+        match self {
+            BinOp::Add => "add",
+            BinOp::Sub => "subtract",
+            BinOp::Mul => "multiply",
+            BinOp::Div => "divide",
+            BinOp::Rem => "calculate the remainder",
+            BinOp::Shl => "shift left",
+            BinOp::Shr => "shift right",
+            _ => "compute",
+        }
+    }
+
+    // Operator and (left, right) binding power, higher binds tighter
+    // Left associative: right = left + 1
+    fn infix(op: &str) -> Option<(BinOp, u8, u8)> {
+        let op = match op {
+            "||" => (BinOp::Or, 1, 2),
+            "&&" => (BinOp::And, 3, 4),
+            "==" => (BinOp::Eq, 5, 6),
+            "!=" => (BinOp::Ne, 5, 6),
+            "<" => (BinOp::Lt, 5, 6),
+            "<=" => (BinOp::Le, 5, 6),
+            ">" => (BinOp::Gt, 5, 6),
+            ">=" => (BinOp::Ge, 5, 6),
+            "|" => (BinOp::BitOr, 7, 8),
+            "^" => (BinOp::BitXor, 9, 10),
+            "&" => (BinOp::BitAnd, 11, 12),
+            "<<" => (BinOp::Shl, 13, 14),
+            ">>" => (BinOp::Shr, 13, 14),
+            "+" => (BinOp::Add, 15, 16),
+            "-" => (BinOp::Sub, 15, 16),
+            "*" => (BinOp::Mul, 17, 18),
+            "/" => (BinOp::Div, 17, 18),
+            "%" => (BinOp::Rem, 17, 18),
+            _ => return None,
+        };
+        Some(op)
+    }
+
+    // x += 1 -> Add
+    fn compound(op: &str) -> Option<BinOp> {
+        let op = op.strip_suffix('=')?;
+        match op {
+            "+" | "-" | "*" | "/" | "%" | "&" | "|" | "^" | "<<" | ">>" => {
+                Some(Self::infix(op).unwrap().0)
+            }
+            _ => None,
+        }
+    }
+}
+
+// Binds tighter than all binary operators
+const AS_BP: u8 = 19;
+// Binds tighter than as, -1 as u8 = (-1) as u8
+const UNARY_BP: u8 = 21;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum UnOp {
+    Neg,
+    Not,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ExprKind {
+    Int(u128, Option<Ty>),
+    Float(f64, Option<Ty>),
+    Bool(bool),
+    Char(char),
+    Var(String),
+    Unary(UnOp, Box<Expr>),
+    Binary(BinOp, Box<Expr>, Box<Expr>),
+    Cast(Box<Expr>, Ty),
+    // u8::MAX
+    Const(Value),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Expr {
+    kind: ExprKind,
+    span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Stmt {
+    Expr(Expr),
+    // let x: ty = expr
+    Let(String, Option<Ty>, Expr),
+    // x = expr, x += expr
+    Assign(String, Option<BinOp>, Expr, Span),
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos]
+    }
+
+    fn next(&mut self) -> Token {
+        let t = self.tokens[self.pos].clone();
+        if t.tok != Tok::Eof {
+            self.pos += 1;
+        }
+        t
+    }
+
+    fn expect_op(&mut self, op: &str) -> Result<Token, Error> {
+        let t = self.next();
+        match t.tok {
+            Tok::Op(o) if o == op => Ok(t),
+            _ => error(t.span, format!("expected `{op}`")),
+        }
+    }
+
+    fn ident(&mut self) -> Result<(String, Span), Error> {
+        let t = self.next();
+        match t.tok {
+            Tok::Ident(name) if !matches!(name.as_str(), "as" | "let" | "true" | "false") => {
+                Ok((name, t.span))
+            }
+            _ => error(t.span, "expected a name"),
+        }
+    }
+
+    fn ty(&mut self) -> Result<Ty, Error> {
+        let t = self.next();
+        match &t.tok {
+            Tok::Ident(name) => match Ty::parse(name) {
+                Some(ty) => Ok(ty),
+                None => error(t.span, format!("unknown type `{name}`")),
+            },
+            _ => error(t.span, "expected a type"),
+        }
+    }
+
+    fn stmt(&mut self) -> Result<Stmt, Error> {
+        let stmt = match (
+            &self.peek().tok,
+            &self.tokens.get(self.pos + 1).map(|t| &t.tok),
+        ) {
+            (Tok::Ident(k), _) if k == "let" => {
+                self.next();
+                let (name, _) = self.ident()?;
+                let ty = if self.peek().tok == Tok::Op(":") {
+                    self.next();
+                    Some(self.ty()?)
+                } else {
+                    None
+                };
+                self.expect_op("=")?;
+                Stmt::Let(name, ty, self.expr(0)?)
+            }
+            (Tok::Ident(_), Some(Tok::Op(op))) if *op == "=" || BinOp::compound(op).is_some() => {
+                let (name, span) = self.ident()?;
+                let op = match self.next().tok {
+                    Tok::Op(op) => BinOp::compound(op),
+                    _ => unreachable!(),
+                };
+                Stmt::Assign(name, op, self.expr(0)?, span)
+            }
+            _ => Stmt::Expr(self.expr(0)?),
+        };
+        let t = self.peek();
+        if t.tok != Tok::Eof {
+            return error(t.span, "expected an operator");
+        }
+        Ok(stmt)
+    }
+
+    // Pratt parser, parses operators that bind at least as tight as min_bp
+    fn expr(&mut self, min_bp: u8) -> Result<Expr, Error> {
+        let mut lhs = self.prefix()?;
+        // Set when lhs is a comparison parsed by this loop, a < b < c is an error
+        let mut comparison = false;
+        loop {
+            let t = self.peek().clone();
+            match &t.tok {
+                Tok::Ident(k) if k == "as" => {
+                    if AS_BP < min_bp {
+                        break;
+                    }
+                    self.next();
+                    let ty = self.ty()?;
+                    let span = Span {
+                        start: lhs.span.start,
+                        end: self.tokens[self.pos - 1].span.end,
+                    };
+                    lhs = Expr {
+                        kind: ExprKind::Cast(Box::new(lhs), ty),
+                        span,
+                    };
+                    comparison = false;
+                }
+                Tok::Op(op) => {
+                    let Some((op, l_bp, r_bp)) = BinOp::infix(op) else {
+                        break;
+                    };
+                    if l_bp < min_bp {
+                        break;
+                    }
+                    if comparison && op.is_comparison() {
+                        return error(t.span, "comparison operators cannot be chained");
+                    }
+                    self.next();
+                    let rhs = self.expr(r_bp)?;
+                    comparison = op.is_comparison();
+                    lhs = Expr {
+                        kind: ExprKind::Binary(op, Box::new(lhs), Box::new(rhs)),
+                        // The span of a binary expression is its operator
+                        span: t.span,
+                    };
+                }
+                _ => break,
+            }
+        }
+        Ok(lhs)
+    }
+
+    fn prefix(&mut self) -> Result<Expr, Error> {
+        let t = self.next();
+        let kind = match t.tok {
+            Tok::Int(v, ty) => ExprKind::Int(v, ty),
+            Tok::Float(v, ty) => ExprKind::Float(v, ty),
+            Tok::Char(c) => ExprKind::Char(c),
+            Tok::Ident(name) => match name.as_str() {
+                "true" => ExprKind::Bool(true),
+                "false" => ExprKind::Bool(false),
+                "as" | "let" => return error(t.span, format!("unexpected keyword `{name}`")),
+                _ if self.peek().tok == Tok::Op("::") => {
+                    self.next();
+                    let (c, span) = self.ident()?;
+                    let v = Ty::parse(&name).and_then(|ty| constant(ty, &c));
+                    let Some(v) = v else {
+                        return error(span, format!("no constant `{c}` in `{name}`"));
+                    };
+                    ExprKind::Const(v)
+                }
+                _ => ExprKind::Var(name),
+            },
+            Tok::Op("(") => {
+                let e = self.expr(0)?;
+                self.expect_op(")")?;
+                return Ok(e);
+            }
+            Tok::Op(op @ ("-" | "!")) => {
+                let op = if op == "-" { UnOp::Neg } else { UnOp::Not };
+                ExprKind::Unary(op, Box::new(self.expr(UNARY_BP)?))
+            }
+            Tok::Eof => return error(t.span, "unexpected end of input"),
+            Tok::Op(op) => return error(t.span, format!("unexpected `{op}`")),
+        };
+        Ok(Expr { kind, span: t.span })
+    }
+}
+
+// Evaluator
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    // Like a debug build
+    Panic,
+    // checked_* returns None
+    Checked,
+    Wrapping,
+    Saturating,
+}
+
+// Type of an expression before evaluating it, literals without a suffix are not known yet
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum StaticTy {
+    Known(Ty),
+    IntLit,
+    FloatLit,
+}
+
+struct Calc {
+    vars: HashMap<String, Value>,
+    mode: Mode,
+}
+
+impl Calc {
+    fn new() -> Self {
+        Self {
+            vars: HashMap::new(),
+            mode: Mode::Panic,
+        }
+    }
+
+    fn run(&mut self, line: &str) -> Result<Option<Value>, Error> {
+        let mut parser = Parser {
+            tokens: tokenize(line)?,
+            pos: 0,
+        };
+        match parser.stmt()? {
+            Stmt::Expr(e) => self.eval(&e, None).map(Some),
+            Stmt::Let(name, ty, e) => {
+                let v = self.eval(&e, ty)?;
+                if let Some(ty) = ty.filter(|&ty| ty != v.ty()) {
+                    return error(e.span, format!("expected {ty}, found {}", v.ty()));
+                }
+                self.vars.insert(name, v);
+                Ok(None)
+            }
+            Stmt::Assign(name, op, e, span) => {
+                let Some(&old) = self.vars.get(&name) else {
+                    return error(span, format!("cannot find value `{name}`"));
+                };
+                let v = match op {
+                    Some(op) => {
+                        let rhs = if matches!(op, BinOp::Shl | BinOp::Shr) {
+                            self.eval(&e, None)?
+                        } else {
+                            self.eval(&e, Some(old.ty()))?
+                        };
+                        self.binary(op, old, rhs, span)?
+                    }
+                    None => self.eval(&e, Some(old.ty()))?,
+                };
+                if v.ty() != old.ty() {
+                    return error(e.span, format!("expected {}, found {}", old.ty(), v.ty()));
+                }
+                self.vars.insert(name, v);
+                Ok(None)
+            }
+        }
+    }
+
+    fn static_ty(&self, e: &Expr) -> StaticTy {
+        match &e.kind {
+            ExprKind::Int(_, Some(t)) | ExprKind::Float(_, Some(t)) => StaticTy::Known(*t),
+            ExprKind::Int(_, None) => StaticTy::IntLit,
+            ExprKind::Float(_, None) => StaticTy::FloatLit,
+            ExprKind::Bool(_) => StaticTy::Known(Ty::Bool),
+            ExprKind::Char(_) => StaticTy::Known(Ty::Char),
+            // Unknown variables are reported by eval
+            ExprKind::Var(name) => self
+                .vars
+                .get(name)
+                .map_or(StaticTy::IntLit, |v| StaticTy::Known(v.ty())),
+            ExprKind::Unary(_, e) => self.static_ty(e),
+            ExprKind::Binary(op, l, r) => match op {
+                _ if op.is_comparison() => StaticTy::Known(Ty::Bool),
+                BinOp::And | BinOp::Or => StaticTy::Known(Ty::Bool),
+                BinOp::Shl | BinOp::Shr => self.static_ty(l),
+                _ => unify(self.static_ty(l), self.static_ty(r)),
+            },
+            ExprKind::Cast(_, t) => StaticTy::Known(*t),
+            ExprKind::Const(v) => StaticTy::Known(v.ty()),
+        }
+    }
+
+    // hint = type expected by the parent, used for literals without a suffix
+    fn eval(&self, e: &Expr, hint: Option<Ty>) -> Result<Value, Error> {
+        match &e.kind {
+            &ExprKind::Int(v, ty) => int_literal(v, false, ty.or(hint), e.span),
+            &ExprKind::Float(v, ty) => {
+                let ty = match ty.or(hint) {
+                    Some(Ty::Float(t)) => t,
+                    _ => FloatTy::F64,
+                };
+                Ok(float(v, ty))
+            }
+            ExprKind::Bool(b) => Ok(Value::Bool(*b)),
+            ExprKind::Const(v) => Ok(*v),
+            ExprKind::Char(c) => Ok(Value::Char(*c)),
+            ExprKind::Var(name) => match self.vars.get(name) {
+                Some(v) => Ok(*v),
+                None => error(e.span, format!("cannot find value `{name}`")),
+            },
+            ExprKind::Unary(UnOp::Neg, inner) => {
+                // -128i8 is a literal, 128i8 alone is out of range
+                if let ExprKind::Int(v, ty) = inner.kind {
+                    return int_literal(v, true, ty.or(hint), e.span);
+                }
+                let v = self.eval(inner, hint)?;
+                self.neg(v, e.span)
+            }
+            ExprKind::Unary(UnOp::Not, inner) => match self.eval(inner, hint)? {
+                Value::Bool(b) => Ok(Value::Bool(!b)),
+                Value::Int(v, t) => Ok(Value::from_bits(!(v as u128), t)),
+                Value::U128(v) => Ok(Value::U128(!v)),
+                v => error(
+                    e.span,
+                    format!("cannot apply unary operator `!` to type `{}`", v.ty()),
+                ),
+            },
+            ExprKind::Binary(op @ (BinOp::And | BinOp::Or), l, r) => {
+                let l = self.eval_bool(l)?;
+                // Short circuit
+                if l == (*op == BinOp::Or) {
+                    return Ok(Value::Bool(l));
+                }
+                Ok(Value::Bool(self.eval_bool(r)?))
+            }
+            ExprKind::Binary(op @ (BinOp::Shl | BinOp::Shr), l, r) => {
+                // The shift amount can be any integer type
+                let l = self.eval(l, hint)?;
+                let r = self.eval(r, None)?;
+                self.binary(*op, l, r, e.span)
+            }
+            ExprKind::Binary(op, l, r) => {
+                let ty = match unify(self.static_ty(l), self.static_ty(r)) {
+                    StaticTy::Known(t) => Some(t),
+                    _ if op.is_comparison() => None,
+                    _ => hint,
+                };
+                let l = self.eval(l, ty)?;
+                let r = self.eval(r, ty)?;
+                self.binary(*op, l, r, e.span)
+            }
+            ExprKind::Cast(inner, ty) => {
+                let v = self.eval(inner, Some(*ty))?;
+                cast(v, *ty).ok_or_else(|| Error {
+                    kind: ErrorKind::Error(format!("casting `{}` as `{ty}` is invalid", v.ty())),
+                    span: e.span,
+                })
+            }
+        }
+    }
+
+    fn eval_bool(&self, e: &Expr) -> Result<bool, Error> {
+        match self.eval(e, Some(Ty::Bool))? {
+            Value::Bool(b) => Ok(b),
+            v => error(e.span, format!("expected bool, found {}", v.ty())),
+        }
+    }
+
+    // Result of an integer operation that overflowed, depends on the mode
+    // wrapped = result truncated to the type, saturated = None if Rust has no saturating version
+    fn overflow(
+        &self,
+        op: &str,
+        wrapped: Value,
+        saturated: Option<Value>,
+        span: Span,
+    ) -> Result<Value, Error> {
+        let panic = || error(span, format!("attempt to {op} with overflow"));
+        match self.mode {
+            Mode::Panic => panic(),
+            Mode::Checked => Err(Error {
+                kind: ErrorKind::None,
+                span,
+            }),
+            Mode::Wrapping => Ok(wrapped),
+            Mode::Saturating => saturated.map_or_else(panic, Ok),
+        }
+    }
+
+    fn neg(&self, v: Value, span: Span) -> Result<Value, Error> {
+        match v {
+            Value::Float(f, t) => Ok(float(-f, t)),
+            Value::Int(x, t) if t.signed() => {
+                if x == t.min() {
+                    let max = Value::Int(t.max(), t);
+                    self.overflow("negate", v, Some(max), span)
+                } else {
+                    Ok(Value::Int(-x, t))
+                }
+            }
+            v => error(
+                span,
+                format!("cannot apply unary operator `-` to type `{}`", v.ty()),
+            ),
+        }
+    }
+
+    fn binary(&self, op: BinOp, l: Value, r: Value, span: Span) -> Result<Value, Error> {
+        if matches!(op, BinOp::Shl | BinOp::Shr) {
+            return self.shift(op, l, r, span);
+        }
+        if l.ty() != r.ty() {
+            return error(span, format!("mismatched types, {} and {}", l.ty(), r.ty()));
+        }
+        if op.is_comparison() {
+            return compare(op, l, r).map(Value::Bool).ok_or_else(|| Error {
+                kind: ErrorKind::Error(format!("cannot compare {}", l.ty())),
+                span,
+            });
+        }
+        let v = match (l, r) {
+            (Value::Int(x, t), Value::Int(y, _)) if t.signed() => self.signed(op, x, y, t, span),
+            (Value::Int(x, t), Value::Int(y, _)) => {
+                self.unsigned(op, x as u128, y as u128, t, span)
+            }
+            (Value::U128(x), Value::U128(y)) => self.unsigned(op, x, y, IntTy::U128, span),
+            (Value::Float(x, t), Value::Float(y, _)) => float_op(op, x, y, t).map(Ok),
+            (Value::Bool(x), Value::Bool(y)) => match op {
+                BinOp::BitAnd => Some(Ok(Value::Bool(x & y))),
+                BinOp::BitOr => Some(Ok(Value::Bool(x | y))),
+                BinOp::BitXor => Some(Ok(Value::Bool(x ^ y))),
+                _ => None,
+            },
+            _ => None,
+        };
+        // None = the operator is not defined for the type
+        v.unwrap_or_else(|| {
+            error(
+                span,
+                format!("cannot {} values of type {}", op.verb(), l.ty()),
+            )
+        })
+    }
+
+    // x and y are within the range of t, stored in i128
+    fn signed(
+        &self,
+        op: BinOp,
+        x: i128,
+        y: i128,
+        t: IntTy,
+        span: Span,
+    ) -> Option<Result<Value, Error>> {
+        if matches!(op, BinOp::Div | BinOp::Rem) && y == 0 {
+            return Some(div_by_zero(self.mode, op, span));
+        }
+        // Exact result, None if it does not fit into i128 (only possible for i128)
+        let exact = match op {
+            BinOp::Add => x.checked_add(y),
+            BinOp::Sub => x.checked_sub(y),
+            BinOp::Mul => x.checked_mul(y),
+            BinOp::Div => x.checked_div(y),
+            // MIN % -1 overflows like MIN / -1 in Rust
+            BinOp::Rem => x.checked_div(y).map(|_| x % y),
+            BinOp::BitAnd => Some(x & y),
+            BinOp::BitOr => Some(x | y),
+            BinOp::BitXor => Some(x ^ y),
+            _ => return None,
+        };
+        let exact = match op {
+            BinOp::Rem if x == t.min() && y == -1 => None,
+            _ => exact,
+        };
+        if let Some(v) = exact.filter(|v| (t.min()..=t.max()).contains(v)) {
+            return Some(Ok(Value::Int(v, t)));
+        }
+        let wrapped = match op {
+            BinOp::Add => x.wrapping_add(y),
+            BinOp::Sub => x.wrapping_sub(y),
+            BinOp::Mul => x.wrapping_mul(y),
+            BinOp::Div => x.wrapping_div(y),
+            _ => 0,
+        };
+        let wrapped = Value::from_bits(wrapped as u128, t);
+        // The sign of the exact result decides the side to saturate to
+        let high = match op {
+            BinOp::Add => Some(y > 0),
+            BinOp::Sub => Some(y < 0),
+            BinOp::Mul => Some((x < 0) == (y < 0)),
+            BinOp::Div => Some(true),
+            // No saturating_rem in Rust
+            _ => None,
+        };
+        let saturated = high.map(|high| Value::Int(if high { t.max() } else { t.min() }, t));
+        Some(self.overflow(op.verb(), wrapped, saturated, span))
+    }
+
+    fn unsigned(
+        &self,
+        op: BinOp,
+        x: u128,
+        y: u128,
+        t: IntTy,
+        span: Span,
+    ) -> Option<Result<Value, Error>> {
+        if matches!(op, BinOp::Div | BinOp::Rem) && y == 0 {
+            return Some(div_by_zero(self.mode, op, span));
+        }
+        let exact = match op {
+            BinOp::Add => x.checked_add(y),
+            BinOp::Sub => x.checked_sub(y),
+            BinOp::Mul => x.checked_mul(y),
+            BinOp::Div => Some(x / y),
+            BinOp::Rem => Some(x % y),
+            BinOp::BitAnd => Some(x & y),
+            BinOp::BitOr => Some(x | y),
+            BinOp::BitXor => Some(x ^ y),
+            _ => return None,
+        };
+        if let Some(v) = exact.filter(|&v| v <= t.max_u()) {
+            return Some(Ok(Value::from_bits(v, t)));
+        }
+        let wrapped = match op {
+            BinOp::Add => x.wrapping_add(y),
+            BinOp::Sub => x.wrapping_sub(y),
+            _ => x.wrapping_mul(y),
+        };
+        let saturated = if op == BinOp::Sub { 0 } else { t.max_u() };
+        Some(self.overflow(
+            op.verb(),
+            Value::from_bits(wrapped, t),
+            Some(Value::from_bits(saturated, t)),
+            span,
+        ))
+    }
+
+    // Overflows if the amount is negative or >= the number of bits
+    fn shift(&self, op: BinOp, l: Value, r: Value, span: Span) -> Result<Value, Error> {
+        let (Some(bits), Ty::Int(t)) = (l.bits(), l.ty()) else {
+            return error(span, format!("cannot {} a {}", op.verb(), l.ty()));
+        };
+        let amount: i128 = match r {
+            Value::Int(v, _) => v,
+            Value::U128(v) => i128::try_from(v).unwrap_or(i128::MAX),
+            _ => return error(span, format!("cannot {} by a {}", op.verb(), r.ty())),
+        };
+        let n = t.bits();
+        let shift = |amount: u32| match op {
+            BinOp::Shl => Value::from_bits(bits << amount, t),
+            // Signed values are sign extended, so >> on i128 is an arithmetic shift
+            _ if t.signed() => Value::from_bits(((bits as i128) >> amount) as u128, t),
+            _ => Value::from_bits(bits >> amount, t),
+        };
+        if (0..n as i128).contains(&amount) {
+            return Ok(shift(amount as u32));
+        }
+        // wrapping_shl masks the amount, there is no saturating_shl
+        let wrapped = shift((amount as u32) & (n - 1));
+        self.overflow(op.verb(), wrapped, None, span)
+    }
+}
+
+fn unify(a: StaticTy, b: StaticTy) -> StaticTy {
+    match (a, b) {
+        (StaticTy::Known(t), _) | (_, StaticTy::Known(t)) => StaticTy::Known(t),
+        (StaticTy::FloatLit, _) | (_, StaticTy::FloatLit) => StaticTy::FloatLit,
+        _ => StaticTy::IntLit,
+    }
+}
+
+// checked_div and checked_rem return None, the other modes panic
+fn div_by_zero(mode: Mode, op: BinOp, span: Span) -> Result<Value, Error> {
+    if mode == Mode::Checked {
+        Err(Error {
+            kind: ErrorKind::None,
+            span,
+        })
+    } else if op == BinOp::Div {
+        error(span, "attempt to divide by zero")
+    } else {
+        error(
+            span,
+            "attempt to calculate the remainder with a divisor of zero",
+        )
+    }
+}
+
+// Integer literal, i32 if there is no suffix and no hint
+fn int_literal(v: u128, negative: bool, ty: Option<Ty>, span: Span) -> Result<Value, Error> {
+    let t = match ty {
+        Some(Ty::Int(t)) => t,
+        // 1f32
+        Some(Ty::Float(t)) => {
+            let f = v as f64;
+            return Ok(float(if negative { -f } else { f }, t));
+        }
+        _ => IntTy::I32,
+    };
+    let fits = match (negative, t.signed()) {
+        (false, true) => v <= t.max() as u128,
+        (false, false) => v <= t.max_u(),
+        (true, true) => v <= t.min().unsigned_abs(),
+        (true, false) => {
+            return error(
+                span,
+                format!("cannot apply unary operator `-` to type `{}`", Ty::Int(t)),
+            )
+        }
+    };
+    if !fits {
+        return error(span, format!("literal out of range for `{}`", Ty::Int(t)));
+    }
+    let bits = if negative { v.wrapping_neg() } else { v };
+    Ok(Value::from_bits(bits, t))
+}
+
+// f32 values are rounded to f32 precision
+fn float(v: f64, t: FloatTy) -> Value {
+    match t {
+        FloatTy::F32 => Value::Float(v as f32 as f64, t),
+        FloatTy::F64 => Value::Float(v, t),
+    }
+}
+
+fn float_op(op: BinOp, x: f64, y: f64, t: FloatTy) -> Option<Value> {
+    let v = match (op, t) {
+        (BinOp::Add, FloatTy::F32) => (x as f32 + y as f32) as f64,
+        (BinOp::Sub, FloatTy::F32) => (x as f32 - y as f32) as f64,
+        (BinOp::Mul, FloatTy::F32) => (x as f32 * y as f32) as f64,
+        (BinOp::Div, FloatTy::F32) => (x as f32 / y as f32) as f64,
+        (BinOp::Rem, FloatTy::F32) => (x as f32 % y as f32) as f64,
+        (BinOp::Add, _) => x + y,
+        (BinOp::Sub, _) => x - y,
+        (BinOp::Mul, _) => x * y,
+        (BinOp::Div, _) => x / y,
+        (BinOp::Rem, _) => x % y,
+        _ => return None,
+    };
+    Some(Value::Float(v, t))
+}
+
+fn compare(op: BinOp, l: Value, r: Value) -> Option<bool> {
+    use std::cmp::Ordering;
+    let ord = match (l, r) {
+        (Value::Int(x, _), Value::Int(y, _)) => x.partial_cmp(&y),
+        (Value::U128(x), Value::U128(y)) => x.partial_cmp(&y),
+        (Value::Float(x, _), Value::Float(y, _)) => x.partial_cmp(&y),
+        (Value::Bool(x), Value::Bool(y)) => x.partial_cmp(&y),
+        (Value::Char(x), Value::Char(y)) => x.partial_cmp(&y),
+        _ => return None,
+    };
+    // NaN is not equal, less or greater than anything
+    Some(match op {
+        BinOp::Eq => ord == Some(Ordering::Equal),
+        BinOp::Ne => ord != Some(Ordering::Equal),
+        BinOp::Lt => ord == Some(Ordering::Less),
+        BinOp::Le => matches!(ord, Some(Ordering::Less | Ordering::Equal)),
+        BinOp::Gt => ord == Some(Ordering::Greater),
+        _ => matches!(ord, Some(Ordering::Greater | Ordering::Equal)),
+    })
+}
+
+// MIN, MAX and BITS of integers, a few of the float constants
+fn constant(ty: Ty, name: &str) -> Option<Value> {
+    let v = match (ty, name) {
+        (Ty::Int(t), "MIN") if t.signed() => Value::Int(t.min(), t),
+        (Ty::Int(t), "MIN") => Value::from_bits(0, t),
+        (Ty::Int(t), "MAX") if t.signed() => Value::Int(t.max(), t),
+        (Ty::Int(t), "MAX") => Value::from_bits(t.max_u(), t),
+        (Ty::Int(t), "BITS") => Value::Int(t.bits() as i128, IntTy::U32),
+        (Ty::Float(FloatTy::F32), "MIN") => Value::Float(f32::MIN as f64, FloatTy::F32),
+        (Ty::Float(FloatTy::F32), "MAX") => Value::Float(f32::MAX as f64, FloatTy::F32),
+        (Ty::Float(FloatTy::F32), "EPSILON") => Value::Float(f32::EPSILON as f64, FloatTy::F32),
+        (Ty::Float(FloatTy::F64), "MIN") => Value::Float(f64::MIN, FloatTy::F64),
+        (Ty::Float(FloatTy::F64), "MAX") => Value::Float(f64::MAX, FloatTy::F64),
+        (Ty::Float(FloatTy::F64), "EPSILON") => Value::Float(f64::EPSILON, FloatTy::F64),
+        (Ty::Float(t), "NAN") => Value::Float(f64::NAN, t),
+        (Ty::Float(t), "INFINITY") => Value::Float(f64::INFINITY, t),
+        (Ty::Char, "MAX") => Value::Char(char::MAX),
+        _ => return None,
+    };
+    Some(v)
+}
+
+// Rules of `as`
+// - int -> int truncates or extends
+// - float -> int rounds toward zero and saturates, NaN -> 0
+// - bool and char -> int, u8 -> char
+fn cast(v: Value, ty: Ty) -> Option<Value> {
+    let v = match (v, ty) {
+        (Value::Float(f, _), Ty::Float(t)) => float(f, t),
+        (Value::Float(f, _), Ty::Int(t)) => {
+            if t.signed() {
+                Value::Int((f as i128).clamp(t.min(), t.max()), t)
+            } else {
+                Value::from_bits((f as u128).min(t.max_u()), t)
+            }
+        }
+        (Value::Int(x, _), Ty::Float(t)) => float(x as f64, t),
+        (Value::U128(x), Ty::Float(t)) => float(x as f64, t),
+        (Value::Int(x, _), Ty::Int(t)) => Value::from_bits(x as u128, t),
+        (Value::U128(x), Ty::Int(t)) => Value::from_bits(x, t),
+        (Value::Bool(b), Ty::Int(t)) => Value::from_bits(b as u128, t),
+        (Value::Char(c), Ty::Int(t)) => Value::from_bits(c as u128, t),
+        (Value::Int(x, IntTy::U8), Ty::Char) => Value::Char(x as u8 as char),
+        (v, ty) if v.ty() == ty => v,
+        _ => return None,
+    };
+    Some(v)
+}
+
+fn print_error(line: &str, err: &Error) {
+    let ErrorKind::Error(msg) = &err.kind else {
+        return;
+    };
+    // Spans are in chars, diagnostics in bytes
+    let byte = |i: usize| line.char_indices().nth(i).map_or(line.len(), |(b, _)| b);
+    let span = DiagSpan::new(byte(err.span.start), byte(err.span.end));
+    let d = Diagnostic::error(msg.as_str()).with_primary(span, "");
+    eprint!(
+        "{}",
+        Renderer::auto().render(&d, &Source::new("<input>", line))
+    );
+}
+
+// Output of a result, "" for a statement
+// Overflow in checked mode is not an error, it prints None
+fn show(mode: Mode, result: Result<Option<Value>, Error>) -> Result<String, Error> {
+    match result {
+        Ok(Some(v)) if mode == Mode::Checked => Ok(format!("Some({v}): {}", v.ty())),
+        Ok(Some(v)) => Ok(format!("{v}: {}", v.ty())),
+        Ok(None) => Ok(String::new()),
+        Err(Error {
+            kind: ErrorKind::None,
+            ..
+        }) => Ok("None".to_string()),
+        Err(e) => Err(e),
+    }
+}
+
+fn eval_line(calc: &mut Calc, line: &str) {
+    match show(calc.mode, calc.run(line)) {
+        Ok(out) if out.is_empty() => {}
+        Ok(out) => info!("{out}"),
+        Err(e) => print_error(line, &e),
+    }
+}
+
+const HELP: &str = "\
+expressions   1 + 2 * 3, -1 % 3, 0xffu8 >> 4, 255u8 as i8, 1.5f32 / 3.0, i8::MIN
+variables     let x = 1u8, let y: i64 = 2, x += 1, x
+:mode M       overflow mode, M = panic, checked, wrapping or saturating
+:vars         list variables
+:help         this help
+:quit         exit";
+
+// REPL line, false to quit
+fn run_line(calc: &mut Calc, line: &str) -> bool {
+    match line.split_whitespace().collect::<Vec<_>>().as_slice() {
+        [] => {}
+        [":quit" | ":q"] => return false,
+        [":help"] => info!("{HELP}"),
+        [":vars"] => {
+            let mut vars: Vec<_> = calc.vars.iter().collect();
+            vars.sort_by_key(|(name, _)| name.as_str());
+            for (name, v) in vars {
+                info!("{name} = {v}: {}", v.ty());
+            }
+        }
+        [":mode"] => info!("{:?}", calc.mode),
+        [":mode", m] => match *m {
+            "panic" => calc.mode = Mode::Panic,
+            "checked" => calc.mode = Mode::Checked,
+            "wrapping" => calc.mode = Mode::Wrapping,
+            "saturating" => calc.mode = Mode::Saturating,
+            _ => info!("unknown mode {m}"),
+        },
+        [cmd, ..] if cmd.starts_with(':') => info!("unknown command {cmd}, :help for help"),
+        _ => eval_line(calc, line),
+    }
+    true
+}
+
+// Line -> output, errors as "error: message"
+// The lines run in order on one Calc, so :mode and let carry over
+const CASES: &[(&str, &str)] = &[
+    // Precedence and associativity
+    ("1 + 2 * 3", "7: i32"),
+    ("(1 + 2) * 3", "9: i32"),
+    ("2 - 3 - 4", "-5: i32"),
+    ("2 * 3 % 4", "2: i32"),
+    ("1 << 2 + 1", "8: i32"),
+    ("1 + 2 << 1", "6: i32"),
+    ("6 & 3 | 8", "10: i32"),
+    ("1 | 2 ^ 3 & 4", "3: i32"),
+    ("1 < 2 && 2 < 1 || true", "true: bool"),
+    ("-2 * 3", "-6: i32"),
+    ("!0u8", "255: u8"),
+    ("2 + 3 as u8", "5: u8"),
+    (
+        "1 < 2 == true",
+        "error: comparison operators cannot be chained",
+    ),
+    // % and / truncate toward zero
+    ("-1 % 3", "-1: i32"),
+    ("7 % -3", "1: i32"),
+    ("-7 / 2", "-3: i32"),
+    // - binds tighter than as, and the literal becomes a u8 like in rustc
+    (
+        "-1 as u8",
+        "error: cannot apply unary operator `-` to type `u8`",
+    ),
+    ("300 as u8", "error: literal out of range for `u8`"),
+    ("255u8 as i8", "-1: i8"),
+    ("u128::MAX as i8", "-1: i8"),
+    ("-1.5 as u8", "0: u8"),
+    ("1e10 as i32", "2147483647: i32"),
+    ("f64::NAN as i32", "0: i32"),
+    ("'a' as u8 + 1", "98: u8"),
+    ("97u8 as char", "'a': char"),
+    // Types
+    ("1.5f32 / 3.0", "0.5: f32"),
+    ("0.1 + 0.2", "0.30000000000000004: f64"),
+    ("1.0 / 0.0", "inf: f64"),
+    ("0xffu8", "255: u8"),
+    ("256u8", "error: literal out of range for `u8`"),
+    ("1u8 + 1i32", "error: mismatched types, u8 and i32"),
+    (
+        "i128::MIN",
+        "-170141183460469231731687303715884105728: i128",
+    ),
+    // Syntax and names
+    ("1 +", "error: unexpected end of input"),
+    ("(1", "error: expected `)`"),
+    ("1 2", "error: expected an operator"),
+    ("x", "error: cannot find value `x`"),
+    ("let x = 5u8", ""),
+    ("x += 250", ""),
+    ("x", "255: u8"),
+    // Overflow, panic mode like a debug build
+    ("x += 1", "error: attempt to add with overflow"),
+    ("255u8 + 1", "error: attempt to add with overflow"),
+    ("i8::MIN - 1", "error: attempt to subtract with overflow"),
+    ("-i8::MIN", "error: attempt to negate with overflow"),
+    ("i32::MIN / -1", "error: attempt to divide with overflow"),
+    ("1u8 << 8", "error: attempt to shift left with overflow"),
+    ("1 / 0", "error: attempt to divide by zero"),
+    (
+        "1 % 0",
+        "error: attempt to calculate the remainder with a divisor of zero",
+    ),
+    (":mode checked", ""),
+    ("2 + 2", "Some(4): i32"),
+    ("255u8 + 1", "None"),
+    ("i8::MIN - 1", "None"),
+    ("1u8 << 8", "None"),
+    ("1 / 0", "None"),
+    ("1 % 0", "None"),
+    (":mode wrapping", ""),
+    ("255u8 + 1", "0: u8"),
+    ("i8::MIN - 1", "127: i8"),
+    ("-i8::MIN", "-128: i8"),
+    ("1u8 << 9", "2: u8"),
+    ("i32::MIN / -1", "-2147483648: i32"),
+    ("i32::MIN % -1", "0: i32"),
+    ("1 / 0", "error: attempt to divide by zero"),
+    (":mode saturating", ""),
+    ("255u8 + 1", "255: u8"),
+    ("0u8 - 1", "0: u8"),
+    ("i8::MIN - 1", "-128: i8"),
+    ("-i8::MIN", "127: i8"),
+    ("i8::MAX * 2", "127: i8"),
+    ("i32::MIN / -1", "2147483647: i32"),
+    // No saturating_shl or saturating_rem in Rust
+    ("1u8 << 9", "error: attempt to shift left with overflow"),
+    (
+        "i32::MIN % -1",
+        "error: attempt to calculate the remainder with overflow",
+    ),
+];
+
+fn check_cases() {
+    let mut calc = Calc::new();
+    for &(line, expected) in CASES {
+        if line.starts_with(':') {
+            assert!(run_line(&mut calc, line));
+            continue;
+        }
+        let out = match show(calc.mode, calc.run(line)) {
+            Ok(out) => out,
+            Err(Error {
+                kind: ErrorKind::Error(msg),
+                ..
+            }) => format!("error: {msg}"),
+            Err(e) => unreachable!("{e:?}"),
+        };
+        assert_eq!(out, expected, "{line}");
+    }
+}
+
+fn main() {
+    check_cases();
+    let mut calc = Calc::new();
+    // Each arg is a line, cargo run --bin calc -- ":mode wrapping" "255u8 + 1"
+    let args: Vec<String> = env::args().skip(1).collect();
+    if !args.is_empty() {
+        for line in &args {
+            info!("> {line}");
+            if !run_line(&mut calc, line) {
+                break;
+            }
+        }
+        return;
+    }
+
+    info!("calc, :help for help");
+    let stdin = io::stdin();
+    loop {
+        print!("> ");
+        io::stdout().flush().unwrap();
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).unwrap_or(0) == 0 {
+            info!("");
+            break;
+        }
+        if !run_line(&mut calc, line.trim()) {
+            break;
+        }
+    }
+}
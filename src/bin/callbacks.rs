@@ -0,0 +1,535 @@
+#![allow(unused)]
+
+// Registry of callbacks, stores closures long term
+// - Persistent FnMut handlers and one-shot FnOnce handlers
+// - Higher priority runs first, then registration order
+// - on / once return a Subscription, dropping it removes the handler
+// - A handler can register, remove or invoke handlers while it runs
+//   - Handlers added during an invoke run from the next invoke
+//   - A handler removed during an invoke is skipped if it has not run yet
+//   - A handler is not re-entered, a nested invoke skips the running handler
+// - Results are combined by a Strategy
+//   - CollectAll      every result
+//   - FirstSome       stop at the first Some
+//   - UntilErr        stop at the first Err
+
+use hello_rust::info;
+use std::cell::{Cell, RefCell};
+use std::ops::ControlFlow;
+use std::rc::{Rc, Weak};
+
+type MutFn<Args, Ret> = Box<dyn FnMut(&Args) -> Ret>;
+type OnceFn<Args, Ret> = Box<dyn FnOnce(&Args) -> Ret>;
+
+enum Handler<Args, Ret> {
+    // None while the handler is running
+    Persistent(Option<MutFn<Args, Ret>>),
+    Once(OnceFn<Args, Ret>),
+}
+
+struct Entry<Args, Ret> {
+    id: u64,
+    priority: i32,
+    handler: Handler<Args, Ret>,
+}
+
+struct Inner<Args, Ret> {
+    next_id: u64,
+    // Sorted by priority, highest first, then by id
+    entries: Vec<Entry<Args, Ret>>,
+}
+
+// Removed from the registry to be called without holding the borrow
+enum Taken<Args, Ret> {
+    Persistent(MutFn<Args, Ret>),
+    Once(OnceFn<Args, Ret>),
+}
+
+pub struct Callbacks<Args, Ret = ()> {
+    inner: Rc<RefCell<Inner<Args, Ret>>>,
+}
+
+// Handle that does not keep the registry alive, for handlers that register handlers
+pub struct WeakCallbacks<Args, Ret = ()> {
+    inner: Weak<RefCell<Inner<Args, Ret>>>,
+}
+
+impl<Args, Ret> WeakCallbacks<Args, Ret> {
+    pub fn upgrade(&self) -> Option<Callbacks<Args, Ret>> {
+        self.inner.upgrade().map(|inner| Callbacks { inner })
+    }
+}
+
+#[must_use = "the handler is removed when the Subscription is dropped"]
+pub struct Subscription {
+    id: u64,
+    unsubscribe: Option<Box<dyn FnOnce()>>,
+}
+
+impl Subscription {
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    // Keep the handler registered for the life of the registry
+    pub fn forget(mut self) {
+        self.unsubscribe = None;
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        if let Some(unsubscribe) = self.unsubscribe.take() {
+            unsubscribe();
+        }
+    }
+}
+
+// How the results of the handlers are combined
+pub trait Strategy<Ret> {
+    type Output;
+
+    // Break skips the remaining handlers
+    fn push(&mut self, ret: Ret) -> ControlFlow<()>;
+
+    fn finish(self) -> Self::Output;
+}
+
+pub struct CollectAll<Ret>(Vec<Ret>);
+
+impl<Ret> Default for CollectAll<Ret> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Ret> CollectAll<Ret> {
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<Ret> Strategy<Ret> for CollectAll<Ret> {
+    type Output = Vec<Ret>;
+
+    fn push(&mut self, ret: Ret) -> ControlFlow<()> {
+        self.0.push(ret);
+        ControlFlow::Continue(())
+    }
+
+    fn finish(self) -> Vec<Ret> {
+        self.0
+    }
+}
+
+pub struct FirstSome<T>(Option<T>);
+
+impl<T> Default for FirstSome<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FirstSome<T> {
+    pub fn new() -> Self {
+        Self(None)
+    }
+}
+
+impl<T> Strategy<Option<T>> for FirstSome<T> {
+    type Output = Option<T>;
+
+    fn push(&mut self, ret: Option<T>) -> ControlFlow<()> {
+        match ret {
+            Some(v) => {
+                self.0 = Some(v);
+                ControlFlow::Break(())
+            }
+            None => ControlFlow::Continue(()),
+        }
+    }
+
+    fn finish(self) -> Option<T> {
+        self.0
+    }
+}
+
+// Ok values until the first Err
+pub struct UntilErr<T, E> {
+    ok: Vec<T>,
+    err: Option<E>,
+}
+
+impl<T, E> Default for UntilErr<T, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, E> UntilErr<T, E> {
+    pub fn new() -> Self {
+        Self {
+            ok: Vec::new(),
+            err: None,
+        }
+    }
+}
+
+impl<T, E> Strategy<Result<T, E>> for UntilErr<T, E> {
+    type Output = Result<Vec<T>, E>;
+
+    fn push(&mut self, ret: Result<T, E>) -> ControlFlow<()> {
+        match ret {
+            Ok(v) => {
+                self.ok.push(v);
+                ControlFlow::Continue(())
+            }
+            Err(e) => {
+                self.err = Some(e);
+                ControlFlow::Break(())
+            }
+        }
+    }
+
+    fn finish(self) -> Result<Vec<T>, E> {
+        match self.err {
+            Some(e) => Err(e),
+            None => Ok(self.ok),
+        }
+    }
+}
+
+impl<Args: 'static, Ret: 'static> Default for Callbacks<Args, Ret> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Args: 'static, Ret: 'static> Callbacks<Args, Ret> {
+    pub fn new() -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(Inner {
+                next_id: 0,
+                entries: Vec::new(),
+            })),
+        }
+    }
+
+    pub fn downgrade(&self) -> WeakCallbacks<Args, Ret> {
+        WeakCallbacks {
+            inner: Rc::downgrade(&self.inner),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.borrow().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn on(&self, f: impl FnMut(&Args) -> Ret + 'static) -> Subscription {
+        self.on_with_priority(0, f)
+    }
+
+    pub fn on_with_priority(
+        &self,
+        priority: i32,
+        f: impl FnMut(&Args) -> Ret + 'static,
+    ) -> Subscription {
+        self.add(priority, Handler::Persistent(Some(Box::new(f))))
+    }
+
+    // Called at most once, then removed
+    pub fn once(&self, f: impl FnOnce(&Args) -> Ret + 'static) -> Subscription {
+        self.once_with_priority(0, f)
+    }
+
+    pub fn once_with_priority(
+        &self,
+        priority: i32,
+        f: impl FnOnce(&Args) -> Ret + 'static,
+    ) -> Subscription {
+        self.add(priority, Handler::Once(Box::new(f)))
+    }
+
+    fn add(&self, priority: i32, handler: Handler<Args, Ret>) -> Subscription {
+        let mut inner = self.inner.borrow_mut();
+        let id = inner.next_id;
+        inner.next_id += 1;
+        // After the handlers with the same priority
+        let i = inner.entries.partition_point(|e| e.priority >= priority);
+        inner.entries.insert(
+            i,
+            Entry {
+                id,
+                priority,
+                handler,
+            },
+        );
+        let weak = Rc::downgrade(&self.inner);
+        Subscription {
+            id,
+            unsubscribe: Some(Box::new(move || {
+                if let Some(inner) = weak.upgrade() {
+                    let removed = {
+                        let mut inner = inner.borrow_mut();
+                        let i = inner.entries.iter().position(|e| e.id == id);
+                        i.map(|i| inner.entries.remove(i))
+                    };
+                    // Dropped after the borrow, the closure may own Subscriptions
+                    drop(removed);
+                }
+            })),
+        }
+    }
+
+    fn take(&self, id: u64) -> Option<Taken<Args, Ret>> {
+        let mut inner = self.inner.borrow_mut();
+        let i = inner.entries.iter().position(|e| e.id == id)?;
+        match &mut inner.entries[i].handler {
+            Handler::Persistent(f) => f.take().map(Taken::Persistent),
+            Handler::Once(_) => match inner.entries.remove(i).handler {
+                Handler::Once(f) => Some(Taken::Once(f)),
+                Handler::Persistent(_) => unreachable!(),
+            },
+        }
+    }
+
+    fn put_back(&self, id: u64, f: MutFn<Args, Ret>) {
+        let removed = {
+            let mut inner = self.inner.borrow_mut();
+            match inner.entries.iter_mut().find(|e| e.id == id) {
+                Some(e) => {
+                    e.handler = Handler::Persistent(Some(f));
+                    None
+                }
+                // Unsubscribed while it was running
+                None => Some(f),
+            }
+        };
+        drop(removed);
+    }
+
+    // Calls the handlers registered before this call, in priority order
+    pub fn invoke_with<S: Strategy<Ret>>(&self, args: &Args, mut strategy: S) -> S::Output {
+        let ids: Vec<u64> = self.inner.borrow().entries.iter().map(|e| e.id).collect();
+        for id in ids {
+            let ret = match self.take(id) {
+                Some(Taken::Persistent(mut f)) => {
+                    let ret = f(args);
+                    self.put_back(id, f);
+                    ret
+                }
+                Some(Taken::Once(f)) => f(args),
+                // Removed or running
+                None => continue,
+            };
+            if strategy.push(ret).is_break() {
+                break;
+            }
+        }
+        strategy.finish()
+    }
+
+    pub fn invoke(&self, args: &Args) -> Vec<Ret> {
+        self.invoke_with(args, CollectAll::new())
+    }
+}
+
+impl<Args: 'static, T: 'static> Callbacks<Args, Option<T>> {
+    pub fn first_some(&self, args: &Args) -> Option<T> {
+        self.invoke_with(args, FirstSome::new())
+    }
+}
+
+impl<Args: 'static, T: 'static, E: 'static> Callbacks<Args, Result<T, E>> {
+    pub fn until_err(&self, args: &Args) -> Result<Vec<T>, E> {
+        self.invoke_with(args, UntilErr::new())
+    }
+}
+
+fn main() {
+    // Priorities, then registration order
+    let cb: Callbacks<u32, String> = Callbacks::new();
+    let a = cb.on(|x| format!("a {x}"));
+    let b = cb.on_with_priority(10, |x| format!("b {x}"));
+    let c = cb.on(|x| format!("c {x}"));
+    let d = cb.on_with_priority(-1, |x| format!("d {x}"));
+    assert_eq!(cb.invoke(&1), ["b 1", "a 1", "c 1", "d 1"]);
+
+    // Dropping the Subscription removes the handler
+    drop(a);
+    assert_eq!(cb.invoke(&2), ["b 2", "c 2", "d 2"]);
+    assert_eq!(cb.len(), 3);
+
+    // FnMut keeps state between calls
+    let cb: Callbacks<i32> = Callbacks::new();
+    let total = Rc::new(Cell::new(0));
+    let mut calls = 0;
+    let sum = {
+        let total = total.clone();
+        cb.on(move |x| {
+            calls += 1;
+            total.set(total.get() + x);
+            info!("call {calls}, total {}", total.get());
+        })
+    };
+    cb.invoke(&1);
+    cb.invoke(&2);
+    assert_eq!(total.get(), 3);
+
+    // FnOnce, can move a captured value out
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let message = String::from("first event only");
+    let l = log.clone();
+    let once = cb.once(move |_| l.borrow_mut().push(message));
+    assert_eq!(cb.len(), 2);
+    cb.invoke(&0);
+    cb.invoke(&0);
+    assert_eq!(*log.borrow(), ["first event only"]);
+    assert_eq!(cb.len(), 1);
+    // Already removed, dropping is fine
+    drop(once);
+
+    // A once handler that is dropped before it runs is never called
+    let l = log.clone();
+    drop(cb.once(move |_| l.borrow_mut().push("never".to_string())));
+    cb.invoke(&0);
+    assert_eq!(log.borrow().len(), 1);
+
+    // forget keeps the handler without holding the Subscription
+    let l = log.clone();
+    cb.on(move |x| l.borrow_mut().push(format!("forgotten {x}")))
+        .forget();
+    cb.invoke(&5);
+    assert_eq!(log.borrow().last().unwrap(), "forgotten 5");
+
+    // Re-entrant: a handler registers a new handler while being invoked
+    let cb: Callbacks<&'static str> = Callbacks::new();
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let subs = Rc::new(RefCell::new(Vec::new()));
+    let (weak, l, s) = (cb.downgrade(), log.clone(), subs.clone());
+    let _register = cb.on(move |event| {
+        l.borrow_mut().push(format!("register on {event}"));
+        let Some(cb) = weak.upgrade() else { return };
+        let l = l.clone();
+        let n = s.borrow().len();
+        s.borrow_mut()
+            .push(cb.on(move |event| l.borrow_mut().push(format!("new {n} on {event}"))));
+    });
+    cb.invoke(&"e1");
+    // Added during e1, runs from e2
+    assert_eq!(*log.borrow(), ["register on e1"]);
+    cb.invoke(&"e2");
+    assert_eq!(
+        *log.borrow(),
+        ["register on e1", "register on e2", "new 0 on e2"]
+    );
+    // The registry does not keep itself alive through its handlers
+    let weak = cb.downgrade();
+    drop(cb);
+    assert!(weak.upgrade().is_none());
+    // Subscriptions outliving the registry are fine
+    drop(subs);
+
+    // A handler removes a later handler, which is skipped
+    let cb: Callbacks<(), &'static str> = Callbacks::new();
+    let later: Rc<RefCell<Option<Subscription>>> = Rc::new(RefCell::new(None));
+    let l = later.clone();
+    let _first = cb.on(move |_| {
+        l.borrow_mut().take();
+        "first"
+    });
+    *later.borrow_mut() = Some(cb.on(|_| "later"));
+    assert_eq!(cb.invoke(&()), ["first"]);
+    assert_eq!(cb.len(), 1);
+
+    // A handler removes itself
+    let cb: Callbacks<()> = Callbacks::new();
+    let me: Rc<RefCell<Option<Subscription>>> = Rc::new(RefCell::new(None));
+    let m = me.clone();
+    *me.borrow_mut() = Some(cb.on(move |_| drop(m.borrow_mut().take())));
+    cb.invoke(&());
+    assert!(cb.is_empty());
+
+    // Nested invoke skips the handler that is running
+    let cb: Callbacks<u32, u32> = Callbacks::new();
+    let weak = cb.downgrade();
+    let _outer = cb.on(move |&depth| {
+        if depth == 0 {
+            let inner = weak.upgrade().unwrap().invoke(&1);
+            // Only the other handler ran
+            assert_eq!(inner, [100]);
+        }
+        depth
+    });
+    let _other = cb.on(|&depth| 100 + depth - 1);
+    assert_eq!(cb.invoke(&0), [0, 99]);
+
+    // First non-None, the remaining handlers are not called
+    let router: Callbacks<&'static str, Option<String>> = Callbacks::new();
+    let called = Rc::new(Cell::new(0));
+    let c = called.clone();
+    let _api = router.on_with_priority(2, move |path| {
+        c.set(c.get() + 1);
+        path.strip_prefix("/api/").map(|p| format!("api {p}"))
+    });
+    let c = called.clone();
+    let _files = router.on_with_priority(1, move |path| {
+        c.set(c.get() + 1);
+        path.ends_with(".html").then(|| format!("file {path}"))
+    });
+    let c = called.clone();
+    let _fallback = router.on(move |path| {
+        c.set(c.get() + 1);
+        Some(format!("404 {path}"))
+    });
+    assert_eq!(router.first_some(&"/api/users").unwrap(), "api users");
+    assert_eq!(called.get(), 1);
+    assert_eq!(
+        router.first_some(&"/index.html").unwrap(),
+        "file /index.html"
+    );
+    assert_eq!(router.first_some(&"/nope").unwrap(), "404 /nope");
+    assert_eq!(called.get(), 1 + 2 + 3);
+
+    // Short-circuit on Err, the later validators do not run
+    let validators: Callbacks<String, Result<usize, String>> = Callbacks::new();
+    let ran = Rc::new(Cell::new(0));
+    let r = ran.clone();
+    let _not_empty = validators.on(move |s| {
+        r.set(r.get() + 1);
+        if s.is_empty() {
+            Err("empty".to_string())
+        } else {
+            Ok(s.len())
+        }
+    });
+    let r = ran.clone();
+    let _ascii = validators.on(move |s| {
+        r.set(r.get() + 1);
+        match s.chars().position(|c| !c.is_ascii()) {
+            Some(i) => Err(format!("non ASCII char at {i}")),
+            None => Ok(s.len()),
+        }
+    });
+    assert_eq!(validators.until_err(&"rust".to_string()), Ok(vec![4, 4]));
+    assert_eq!(ran.get(), 2);
+    assert_eq!(
+        validators.until_err(&String::new()),
+        Err("empty".to_string())
+    );
+    assert_eq!(ran.get(), 3);
+    assert_eq!(
+        validators.until_err(&"crab 🦀".to_string()),
+        Err("non ASCII char at 5".to_string())
+    );
+
+    // Any strategy can be passed to invoke_with
+    let n = validators.invoke_with(&"ok".to_string(), CollectAll::new());
+    assert_eq!(n, [Ok(2), Ok(2)]);
+
+    info!("callbacks ok");
+}
@@ -0,0 +1,597 @@
+#![allow(unused)]
+
+// Software rasterizer
+// - Canvas = RGBA framebuffer (Vec<u8>, 4 bytes per pixel)
+// - Draw Point, Square and Circle from the other examples
+// - Write binary PPM and PNG using only std
+//
+// Writes canvas.ppm and canvas.png to target/, or to the given directory
+// cargo run --bin canvas -- /tmp
+
+use hello_rust::info;
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+// Color from enum.rs
+#[derive(Debug, PartialEq)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+    Rgba(u8, u8, u8, f32),
+    Hex(String),
+    Hsl { h: u8, s: u8, l: u8 },
+}
+
+impl Color {
+    // Convert to [r, g, b, a]
+    fn to_rgba(&self) -> [u8; 4] {
+        match self {
+            Color::Red => [255, 0, 0, 255],
+            Color::Green => [0, 255, 0, 255],
+            Color::Blue => [0, 0, 255, 255],
+            Color::Rgba(r, g, b, a) => [*r, *g, *b, (a.clamp(0.0, 1.0) * 255.0).round() as u8],
+            Color::Hex(hex) => {
+                let hex = hex.trim_start_matches('#');
+                let byte = |i: usize| {
+                    hex.get(i..i + 2)
+                        .and_then(|s| u8::from_str_radix(s, 16).ok())
+                        .unwrap_or(0)
+                };
+                let a = if hex.len() == 8 { byte(6) } else { 255 };
+                [byte(0), byte(2), byte(4), a]
+            }
+            // h in 0..=255 maps to 0..360 degrees, s and l are percentages
+            Color::Hsl { h, s, l } => {
+                let h = *h as f32 / 255.0 * 360.0;
+                let s = (*s).min(100) as f32 / 100.0;
+                let l = (*l).min(100) as f32 / 100.0;
+                let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+                let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+                let m = l - c / 2.0;
+                let (r, g, b) = match h as u32 {
+                    0..=59 => (c, x, 0.0),
+                    60..=119 => (x, c, 0.0),
+                    120..=179 => (0.0, c, x),
+                    180..=239 => (0.0, x, c),
+                    240..=299 => (x, 0.0, c),
+                    _ => (c, 0.0, x),
+                };
+                let to_u8 = |v: f32| ((v + m) * 255.0).round() as u8;
+                [to_u8(r), to_u8(g), to_u8(b), 255]
+            }
+        }
+    }
+}
+
+// Shapes from struct.rs and trait_qualified.rs
+#[derive(Debug, Clone, Copy)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Debug)]
+struct Circle {
+    center: Point,
+    radius: u32,
+}
+
+struct Square {
+    color: String,
+    top: i32,
+    left: i32,
+    size: u32,
+}
+
+struct Canvas {
+    width: usize,
+    height: usize,
+    // RGBA, row major
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    // PNG does not allow a 0 width or height
+    fn new(width: usize, height: usize) -> Self {
+        assert!(width > 0 && height > 0, "empty canvas {width}x{height}");
+        Self {
+            width,
+            height,
+            pixels: vec![0; width * height * 4],
+        }
+    }
+
+    fn fill(&mut self, color: &Color) {
+        let c = color.to_rgba();
+        for px in self.pixels.chunks_exact_mut(4) {
+            px.copy_from_slice(&c);
+        }
+    }
+
+    fn get(&self, x: usize, y: usize) -> [u8; 4] {
+        let i = (y * self.width + x) * 4;
+        [
+            self.pixels[i],
+            self.pixels[i + 1],
+            self.pixels[i + 2],
+            self.pixels[i + 3],
+        ]
+    }
+
+    // Alpha compositing ("source over")
+    // coverage in 0.0..=1.0 scales the source alpha (used for anti-aliasing)
+    fn blend(&mut self, x: i64, y: i64, src: [u8; 4], coverage: f32) {
+        if x < 0 || y < 0 || x >= self.width as i64 || y >= self.height as i64 {
+            return;
+        }
+        let i = (y as usize * self.width + x as usize) * 4;
+        let sa = src[3] as f32 / 255.0 * coverage.clamp(0.0, 1.0);
+        if sa <= 0.0 {
+            return;
+        }
+        let da = self.pixels[i + 3] as f32 / 255.0;
+        let oa = sa + da * (1.0 - sa);
+        for (dst, s) in self.pixels[i..i + 3].iter_mut().zip(src) {
+            let (s, d) = (s as f32, *dst as f32);
+            *dst = ((s * sa + d * da * (1.0 - sa)) / oa).round() as u8;
+        }
+        self.pixels[i + 3] = (oa * 255.0).round() as u8;
+    }
+
+    fn set_pixel(&mut self, x: i64, y: i64, color: &Color) {
+        self.blend(x, y, color.to_rgba(), 1.0);
+    }
+
+    // Bresenham's line algorithm
+    fn line(&mut self, p0: Point, p1: Point, color: &Color) {
+        let c = color.to_rgba();
+        let (mut x0, mut y0) = (p0.x.round() as i64, p0.y.round() as i64);
+        let (x1, y1) = (p1.x.round() as i64, p1.y.round() as i64);
+        let dx = (x1 - x0).abs();
+        let dy = -(y1 - y0).abs();
+        let sx = if x0 < x1 { 1 } else { -1 };
+        let sy = if y0 < y1 { 1 } else { -1 };
+        let mut err = dx + dy;
+        loop {
+            self.blend(x0, y0, c, 1.0);
+            if x0 == x1 && y0 == y1 {
+                break;
+            }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x0 += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y0 += sy;
+            }
+        }
+    }
+
+    // Xiaolin Wu's anti-aliased line
+    fn line_aa(&mut self, p0: Point, p1: Point, color: &Color) {
+        let c = color.to_rgba();
+        let (mut x0, mut y0, mut x1, mut y1) = (p0.x, p0.y, p1.x, p1.y);
+        let steep = (y1 - y0).abs() > (x1 - x0).abs();
+        if steep {
+            std::mem::swap(&mut x0, &mut y0);
+            std::mem::swap(&mut x1, &mut y1);
+        }
+        if x0 > x1 {
+            std::mem::swap(&mut x0, &mut x1);
+            std::mem::swap(&mut y0, &mut y1);
+        }
+        let dx = x1 - x0;
+        let gradient = if dx == 0.0 { 1.0 } else { (y1 - y0) / dx };
+        let mut y = y0 + gradient * (x0.round() - x0);
+        for x in x0.round() as i64..=x1.round() as i64 {
+            let fy = y.floor();
+            let frac = y - fy;
+            let (a, b) = (fy as i64, fy as i64 + 1);
+            if steep {
+                self.blend(a, x, c, 1.0 - frac);
+                self.blend(b, x, c, frac);
+            } else {
+                self.blend(x, a, c, 1.0 - frac);
+                self.blend(x, b, c, frac);
+            }
+            y += gradient;
+        }
+    }
+
+    fn rect(&mut self, x: i64, y: i64, w: u32, h: u32, color: &Color) {
+        let (w, h) = (w as i64, h as i64);
+        if w == 0 || h == 0 {
+            return;
+        }
+        let c = color.to_rgba();
+        for i in x..x + w {
+            self.blend(i, y, c, 1.0);
+            if h > 1 {
+                self.blend(i, y + h - 1, c, 1.0);
+            }
+        }
+        for j in y + 1..y + h - 1 {
+            self.blend(x, j, c, 1.0);
+            if w > 1 {
+                self.blend(x + w - 1, j, c, 1.0);
+            }
+        }
+    }
+
+    fn fill_rect(&mut self, x: i64, y: i64, w: u32, h: u32, color: &Color) {
+        let c = color.to_rgba();
+        for j in y..y + h as i64 {
+            for i in x..x + w as i64 {
+                self.blend(i, j, c, 1.0);
+            }
+        }
+    }
+
+    // Midpoint circle algorithm
+    fn circle(&mut self, circle: &Circle, color: &Color) {
+        let c = color.to_rgba();
+        let (cx, cy) = (
+            circle.center.x.round() as i64,
+            circle.center.y.round() as i64,
+        );
+        let mut x = circle.radius as i64;
+        let mut y = 0;
+        let mut err = 1 - x;
+        while x >= y {
+            for (dx, dy) in [
+                (x, y),
+                (y, x),
+                (-y, x),
+                (-x, y),
+                (-x, -y),
+                (-y, -x),
+                (y, -x),
+                (x, -y),
+            ] {
+                self.blend(cx + dx, cy + dy, c, 1.0);
+            }
+            y += 1;
+            if err < 0 {
+                err += 2 * y + 1;
+            } else {
+                x -= 1;
+                err += 2 * (y - x) + 1;
+            }
+        }
+    }
+
+    // Filled circle, anti-aliased edge
+    // Coverage is estimated from the distance between the pixel center and the edge
+    fn fill_circle(&mut self, circle: &Circle, color: &Color) {
+        let c = color.to_rgba();
+        let (cx, cy) = (circle.center.x, circle.center.y);
+        let r = circle.radius as f32;
+        let (x0, x1) = ((cx - r - 1.0).floor() as i64, (cx + r + 1.0).ceil() as i64);
+        let (y0, y1) = ((cy - r - 1.0).floor() as i64, (cy + r + 1.0).ceil() as i64);
+        for y in y0..=y1 {
+            for x in x0..=x1 {
+                let dx = x as f32 + 0.5 - cx;
+                let dy = y as f32 + 0.5 - cy;
+                let d = (dx * dx + dy * dy).sqrt();
+                let coverage = (r - d + 0.5).clamp(0.0, 1.0);
+                self.blend(x, y, c, coverage);
+            }
+        }
+    }
+
+    // Scanline polygon fill (even-odd rule)
+    fn fill_polygon(&mut self, points: &[Point], color: &Color) {
+        if points.len() < 3 {
+            return;
+        }
+        let c = color.to_rgba();
+        let min_y = points.iter().map(|p| p.y).fold(f32::INFINITY, f32::min);
+        let max_y = points.iter().map(|p| p.y).fold(f32::NEG_INFINITY, f32::max);
+        let mut xs: Vec<f32> = Vec::new();
+        for y in min_y.floor() as i64..=max_y.ceil() as i64 {
+            // Sample at the pixel center
+            let sy = y as f32 + 0.5;
+            xs.clear();
+            for i in 0..points.len() {
+                let a = points[i];
+                let b = points[(i + 1) % points.len()];
+                // Half open interval so shared vertices are counted once
+                if (a.y <= sy && sy < b.y) || (b.y <= sy && sy < a.y) {
+                    xs.push(a.x + (sy - a.y) / (b.y - a.y) * (b.x - a.x));
+                }
+            }
+            xs.sort_by(|a, b| a.total_cmp(b));
+            for pair in xs.chunks_exact(2) {
+                let start = (pair[0] - 0.5).ceil() as i64;
+                let end = (pair[1] - 0.5).floor() as i64;
+                for x in start..=end {
+                    self.blend(x, y, c, 1.0);
+                }
+            }
+        }
+    }
+
+    // Alpha of each pixel, '.' = 0, '#' = 255, else 1..=9 in tenths
+    fn alpha_map(&self) -> String {
+        let mut out = String::new();
+        for row in self.pixels.chunks_exact(self.width * 4) {
+            for px in row.chunks_exact(4) {
+                out.push(match px[3] {
+                    0 => '.',
+                    255 => '#',
+                    a => char::from(b'0' + (a as u32 * 10 / 255).clamp(1, 9) as u8),
+                });
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    fn draw_point(&mut self, p: &Point, color: &Color) {
+        self.set_pixel(p.x.round() as i64, p.y.round() as i64, color);
+    }
+
+    fn draw_square(&mut self, square: &Square) {
+        // Square.color is a hex string or a name
+        let color = match square.color.as_str() {
+            "red" => Color::Red,
+            "green" => Color::Green,
+            "blue" => Color::Blue,
+            hex => Color::Hex(hex.to_string()),
+        };
+        self.fill_rect(
+            square.left as i64,
+            square.top as i64,
+            square.size,
+            square.size,
+            &color,
+        );
+    }
+
+    // Binary PPM (P6), alpha is composited over black
+    fn write_ppm<W: Write>(&self, mut w: W) -> io::Result<()> {
+        write!(w, "P6\n{} {}\n255\n", self.width, self.height)?;
+        let mut rgb = Vec::with_capacity(self.width * self.height * 3);
+        for px in self.pixels.chunks_exact(4) {
+            let a = px[3] as u32;
+            for c in &px[..3] {
+                rgb.push(((*c as u32 * a + 127) / 255) as u8);
+            }
+        }
+        w.write_all(&rgb)
+    }
+
+    // PNG with uncompressed (stored) deflate blocks
+    fn write_png<W: Write>(&self, mut w: W) -> io::Result<()> {
+        w.write_all(b"\x89PNG\r\n\x1a\n")?;
+
+        let mut ihdr = Vec::with_capacity(13);
+        ihdr.extend_from_slice(&(self.width as u32).to_be_bytes());
+        ihdr.extend_from_slice(&(self.height as u32).to_be_bytes());
+        // bit depth 8, color type 6 (RGBA), compression, filter, interlace
+        ihdr.extend_from_slice(&[8, 6, 0, 0, 0]);
+        write_chunk(&mut w, b"IHDR", &ihdr)?;
+
+        // Each scanline starts with filter type 0 (none)
+        let stride = self.width * 4;
+        let mut raw = Vec::with_capacity((stride + 1) * self.height);
+        for row in self.pixels.chunks_exact(stride) {
+            raw.push(0);
+            raw.extend_from_slice(row);
+        }
+        write_chunk(&mut w, b"IDAT", &zlib_stored(&raw))?;
+        write_chunk(&mut w, b"IEND", &[])
+    }
+}
+
+fn write_chunk<W: Write>(w: &mut W, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    w.write_all(&(data.len() as u32).to_be_bytes())?;
+    w.write_all(kind)?;
+    w.write_all(data)?;
+    let crc = crc32(&[kind.as_slice(), data].concat());
+    w.write_all(&crc.to_be_bytes())
+}
+
+// zlib stream made of stored (uncompressed) deflate blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const MAX_BLOCK: usize = 65535;
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(MAX_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        // Empty final block
+        out.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        out.push(last as u8);
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&(!len).to_le_bytes());
+        out.extend_from_slice(block);
+    }
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xffff_ffffu32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % MOD;
+        b = (b + a) % MOD;
+    }
+    (b << 16) | a
+}
+
+// Expected pixels of each primitive on a small transparent canvas
+fn check_golden() {
+    let p = |x: f32, y: f32| Point { x, y };
+
+    let mut c = Canvas::new(8, 5);
+    c.line(p(0.0, 0.0), p(7.0, 4.0), &Color::Red);
+    assert_eq!(
+        c.alpha_map(),
+        "\
+#.......
+.##.....
+...##...
+.....##.
+.......#
+"
+    );
+
+    // Each column is split between the 2 pixels around the line
+    let mut c = Canvas::new(8, 5);
+    c.line_aa(p(0.0, 0.5), p(7.0, 3.5), &Color::Red);
+    assert_eq!(
+        c.alpha_map(),
+        "\
+51......
+5962....
+..3773..
+....2694
+......15
+"
+    );
+
+    let mut c = Canvas::new(9, 9);
+    c.circle(
+        &Circle {
+            center: p(4.0, 4.0),
+            radius: 3,
+        },
+        &Color::Red,
+    );
+    assert_eq!(
+        c.alpha_map(),
+        "\
+.........
+...###...
+..#...#..
+.#.....#.
+.#.....#.
+.#.....#.
+..#...#..
+...###...
+.........
+"
+    );
+
+    let mut c = Canvas::new(9, 9);
+    c.fill_circle(
+        &Circle {
+            center: p(4.5, 4.5),
+            radius: 3,
+        },
+        &Color::Red,
+    );
+    assert_eq!(
+        c.alpha_map(),
+        "\
+.........
+...353...
+..6###6..
+.3#####3.
+.5#####5.
+.3#####3.
+..6###6..
+...353...
+.........
+"
+    );
+
+    // Pixels whose center is inside
+    let mut c = Canvas::new(9, 6);
+    c.fill_polygon(&[p(0.0, 0.0), p(9.0, 0.0), p(0.0, 6.0)], &Color::Red);
+    assert_eq!(
+        c.alpha_map(),
+        "\
+########.
+#######..
+#####....
+####.....
+##.......
+#........
+"
+    );
+
+    // 50% red over blue
+    let mut c = Canvas::new(1, 1);
+    c.fill(&Color::Blue);
+    c.set_pixel(0, 0, &Color::Rgba(255, 0, 0, 0.5));
+    assert_eq!(c.get(0, 0), [128, 0, 127, 255]);
+}
+
+fn main() -> io::Result<()> {
+    check_golden();
+    let mut canvas = Canvas::new(200, 150);
+    canvas.fill(&Color::Hex("ffffff".to_string()));
+
+    let square = Square {
+        color: "blue".to_string(),
+        top: 20,
+        left: 20,
+        size: 60,
+    };
+    canvas.draw_square(&square);
+    canvas.rect(15, 15, 70, 70, &Color::Hsl { h: 0, s: 0, l: 20 });
+
+    // Semi transparent circle on top of the square
+    let circle = Circle {
+        center: Point { x: 80.0, y: 80.0 },
+        radius: 40,
+    };
+    canvas.fill_circle(&circle, &Color::Rgba(255, 0, 0, 0.5));
+    canvas.circle(&circle, &Color::Red);
+
+    // Triangle
+    let triangle = [
+        Point { x: 130.0, y: 130.0 },
+        Point { x: 190.0, y: 130.0 },
+        Point { x: 160.0, y: 70.0 },
+    ];
+    canvas.fill_polygon(&triangle, &Color::Green);
+
+    canvas.line(
+        Point { x: 0.0, y: 0.0 },
+        Point { x: 199.0, y: 149.0 },
+        &Color::Blue,
+    );
+    canvas.line_aa(
+        Point { x: 0.0, y: 149.0 },
+        Point { x: 199.0, y: 10.0 },
+        &Color::Blue,
+    );
+    canvas.draw_point(&Point { x: 100.0, y: 10.0 }, &Color::Red);
+
+    info!("pixel at (50, 50) = {:?}", canvas.get(50, 50));
+    info!("pixel at (80, 80) = {:?}", canvas.get(80, 80));
+    // Checksum of the whole image, changes if any primitive changes
+    assert_eq!(crc32(&canvas.pixels), 0xdc4e9b17);
+
+    let dir = PathBuf::from(env::args().nth(1).unwrap_or("target".to_string()));
+    fs::create_dir_all(&dir)?;
+    let (ppm, png) = (dir.join("canvas.ppm"), dir.join("canvas.png"));
+    canvas.write_ppm(File::create(&ppm)?)?;
+    canvas.write_png(File::create(&png)?)?;
+    info!("wrote {} and {}", ppm.display(), png.display());
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 #![allow(unused)]
 
+use hello_rust::info;
 // mpsc - multi producer, single consumer
 use std::sync::mpsc;
 use std::sync::mpsc::{Receiver, Sender, TryRecvError};
@@ -13,7 +14,7 @@ fn main() {
     // Unwrap Result - Err if receiver is dropped
     tx.send("hello".to_string()).unwrap();
     let res = rx.recv();
-    println!("{:?}", res);
+    info!("{:?}", res);
 
     // This will fail
     // std::mem::drop(rx);
@@ -28,9 +29,9 @@ fn main() {
     });
 
     // recv block main thread until a new message arrives
-    println!("waiting...");
+    info!("waiting...");
     let res = rx.recv();
-    println!("received: {:?}", res);
+    info!("received: {:?}", res);
 
     // try_recv - doesn't block main thread
     let (tx, rx): (Sender<String>, Receiver<String>) = mpsc::channel();
@@ -43,12 +44,12 @@ fn main() {
     loop {
         // try_recv doesn't block main thread
         match rx.try_recv() {
-            Ok(msg) => println!("{msg}"),
+            Ok(msg) => info!("{msg}"),
             Err(TryRecvError::Empty) => {
-                println!("no message");
+                info!("no message");
             }
             Err(TryRecvError::Disconnected) => {
-                println!("disconnected");
+                info!("disconnected");
                 break;
             }
         }
@@ -67,9 +68,9 @@ fn main() {
 
     loop {
         match rx.recv() {
-            Ok(msg) => println!("{msg}"),
+            Ok(msg) => info!("{msg}"),
             Err(err) => {
-                println!("{err:?}");
+                info!("{err:?}");
                 break;
             }
         }
@@ -85,7 +86,7 @@ fn main() {
     });
 
     for msg in rx {
-        println!("for loop: {msg}");
+        info!("for loop: {msg}");
     }
 
     // Multiple producer, single consumer
@@ -115,5 +116,5 @@ fn main() {
     }
 
     // No need to join the threads
-    println!("{s}");
+    info!("{s}");
 }
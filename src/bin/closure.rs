@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 // Closures intro
 fn main() {
     // Closures can capture variables
@@ -9,7 +11,7 @@ fn main() {
     // Inferred
     let f = |i| i + v;
 
-    println!("closure inferred: {}", f(-1));
+    info!("closure inferred: {}", f(-1));
 
     // Inferred type is locked
     let f = |x| x;
@@ -20,5 +22,5 @@ fn main() {
     // Map example
     let vals = vec![1, 2, 3];
     let x = 1;
-    println!("{:?}", vals.iter().map(|v| v + x));
+    info!("{:?}", vals.iter().map(|v| v + x));
 }
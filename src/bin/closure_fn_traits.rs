@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 fn f_once<F: FnOnce() -> T, T>(f: F) -> T {
     f()
     // Cannot call more than once
@@ -19,7 +21,7 @@ fn f_fn<F: Fn() -> T, T>(f: F) -> T {
 }
 
 fn func() -> u32 {
-    println!("func");
+    info!("func");
     32
 }
 
@@ -39,13 +41,13 @@ fn main() {
     // FnOnce
     let v = vec![1, 2, 3];
     let f = || {
-        println!("fn once {:?}", v);
+        info!("fn once {:?}", v);
         v.len()
     };
-    println!("before fn once {:?}", v);
-    println!("fn once return: {}", f_once(f));
-    println!("fn once return: {}", f_once(f));
-    println!("after fn once {:?}", v);
+    info!("before fn once {:?}", v);
+    info!("fn once return: {}", f_once(f));
+    info!("fn once return: {}", f_once(f));
+    info!("after fn once {:?}", v);
 
     // FnMut
     let mut v = vec![1, 2, 3];
@@ -55,21 +57,21 @@ fn main() {
         v.len()
     };
     // TODO: why this doesn't compile
-    // println!("before mut {:?}", v);
-    println!("fn mut return: {}", f_mut(&mut f));
-    println!("fn mut return: {}", f_mut(&mut f));
-    println!("after fn mut {:?}", v);
+    // info!("before mut {:?}", v);
+    info!("fn mut return: {}", f_mut(&mut f));
+    info!("fn mut return: {}", f_mut(&mut f));
+    info!("after fn mut {:?}", v);
 
     // Fn
     let v = vec![1, 2, 3];
     let f = || {
-        println!("fn {:?}", v);
+        info!("fn {:?}", v);
         v.len()
     };
-    println!("before fn {:?}", v);
-    println!("fn return: {}", f_fn(f));
-    println!("fn return: {}", f_fn(f));
-    println!("after fn {:?}", v);
+    info!("before fn {:?}", v);
+    info!("fn return: {}", f_fn(f));
+    info!("fn return: {}", f_fn(f));
+    info!("after fn {:?}", v);
 
     // normal function as input
     f_fn(func);
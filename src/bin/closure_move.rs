@@ -1,3 +1,4 @@
+use hello_rust::info;
 use std::thread;
 
 fn main() {
@@ -9,34 +10,34 @@ fn main() {
 
     // borrow immutable
     let list = vec![1, 2, 3];
-    println!("Before defining closure: {list:?}");
+    info!("Before defining closure: {list:?}");
 
-    let f_borrow_immut = || println!("From closure: {list:?}");
+    let f_borrow_immut = || info!("From closure: {list:?}");
 
-    println!("Before calling closure: {list:?}");
+    info!("Before calling closure: {list:?}");
     f_borrow_immut();
-    println!("After calling closure: {list:?}");
+    info!("After calling closure: {list:?}");
 
     // borrowing mutable
-    println!("--- mutable borrow ---");
+    info!("--- mutable borrow ---");
 
     let mut list = vec![1, 2, 3];
-    println!("Before defining closure: {list:?}");
+    info!("Before defining closure: {list:?}");
 
     // Captures mutable ref
     let mut borrows_mutably = || list.push(7);
 
     borrows_mutably();
-    println!("After calling closure: {list:?}");
+    info!("After calling closure: {list:?}");
 
     // taking ownership
     // move is useful for thread
-    println!("--- move ---");
+    info!("--- move ---");
 
     let list = vec![1, 2, 3];
-    println!("Before defining closure: {list:?}");
+    info!("Before defining closure: {list:?}");
 
-    thread::spawn(move || println!("From thread: {list:?}"))
+    thread::spawn(move || info!("From thread: {list:?}"))
         .join()
         .unwrap();
 }
@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 // Closure as function output
 
 /*
@@ -16,7 +18,7 @@ fn fn_out_move() -> impl Fn() {
     let s = "hello".to_string();
     // Must move ownership into closure
     move || {
-        println!("fn_out_move: {s}")
+        info!("fn_out_move: {s}")
         // Cannot return s (s is owned by this closure)
         // and this closure can be called more than once
         // s
@@ -29,7 +31,7 @@ fn fn_mut_out() -> impl FnMut() {
     let mut s = "hello".to_string();
     move || {
         s += "🦀";
-        println!("fn_mut_out: {}", s);
+        info!("fn_mut_out: {}", s);
         // Cannot return s (s is owned by this closure)
         // and this closure can be called more than once
         // s
@@ -50,7 +52,7 @@ fn fn_mut_return_copy() -> impl FnMut() -> u32 {
 fn fn_once_out() -> impl FnOnce() -> String {
     let s = "hello".to_string();
     move || {
-        println!("fn_once_out: {}", s);
+        info!("fn_once_out: {}", s);
         // Can return s because this closure can only be called once
         s
     }
@@ -61,9 +63,9 @@ fn main() {
     let f = fn_out();
     // Call more than once
     let z = f(1);
-    println!("main: z = {z}");
+    info!("main: z = {z}");
     let z = f(2);
-    println!("main: z = {z}");
+    info!("main: z = {z}");
 
     // Fn move
     let f = fn_out_move();
@@ -78,15 +80,15 @@ fn main() {
     // FnMut return value
     let mut f = fn_mut_return_copy();
     let z = f();
-    println!("z: {z}");
+    info!("z: {z}");
     let z = f();
-    println!("z: {z}");
+    info!("z: {z}");
     let z = f();
-    println!("z: {z}");
+    info!("z: {z}");
 
     let f = fn_once_out();
     let s = f();
-    println!("main: {s}");
+    info!("main: {s}");
     // Cannot call twice
     // f();
 }
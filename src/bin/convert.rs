@@ -0,0 +1,326 @@
+#![allow(unused)]
+
+// Casts that report what `as` did, hello_rust::convert (src/convert.rs)
+// cargo run --bin convert
+
+use hello_rust::convert::{Cast, CastReport, Effects, Prim, Repr};
+use hello_rust::info;
+
+// Boundary values of each type
+trait Samples: Sized {
+    fn samples() -> Vec<Self>;
+}
+
+// ±2^(n-1), 2^n and their neighbors for every integer size
+fn int_candidates() -> Vec<i128> {
+    let mut v = vec![0, 1, -1, 2, -2, 44, 127, 255, 256, 300, -300];
+    for n in [7, 8, 15, 16, 23, 24, 31, 32, 53, 54, 63, 64, 126] {
+        let p = 1i128 << n;
+        v.extend([p - 1, p, p + 1, -p - 1, -p, -p + 1]);
+    }
+    v.extend([i128::MIN, i128::MIN + 1, i128::MAX - 1, i128::MAX]);
+    v
+}
+
+macro_rules! int_samples {
+    ($($t:ident),*) => {$(
+        impl Samples for $t {
+            fn samples() -> Vec<Self> {
+                let mut v: Vec<$t> = int_candidates()
+                    .into_iter()
+                    .filter_map(|x| $t::try_from(x).ok())
+                    .collect();
+                // Above i128::MAX
+                let big = [u128::MAX, u128::MAX - 1, 1 << 127];
+                v.extend(big.iter().filter_map(|&x| $t::try_from(x).ok()));
+                v.extend([$t::MIN, $t::MAX]);
+                v
+            }
+        }
+    )*};
+}
+
+int_samples!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+fn float_candidates() -> Vec<f64> {
+    let mut v = vec![
+        0.0,
+        0.1,
+        0.5,
+        1.0,
+        1.5,
+        2.5,
+        255.5,
+        300.0,
+        16777217.0,
+        9007199254740993.0,
+        1e30,
+        1e300,
+        f32::MAX as f64,
+        f32::MIN_POSITIVE as f64,
+        f64::MAX,
+        f64::MIN_POSITIVE,
+        f64::EPSILON,
+        f64::INFINITY,
+    ];
+    for n in [7, 8, 15, 16, 24, 31, 32, 53, 63, 64, 127, 128] {
+        let p = 2f64.powi(n);
+        v.extend([p - 1.0, p, p + 1.0]);
+    }
+    let neg: Vec<f64> = v.iter().map(|x| -x).collect();
+    v.extend(neg);
+    v.push(f64::NAN);
+    v
+}
+
+impl Samples for f32 {
+    fn samples() -> Vec<Self> {
+        let mut v: Vec<f32> = float_candidates().into_iter().map(|x| x as f32).collect();
+        v.extend([f32::MAX, f32::MIN, f32::EPSILON, 16777216.0, 16777218.0]);
+        v
+    }
+}
+
+impl Samples for f64 {
+    fn samples() -> Vec<Self> {
+        float_candidates()
+    }
+}
+
+// Oracles, written against the exact value instead of `as`
+
+fn is_nan<T: Prim>(x: T) -> bool {
+    matches!(x.repr(), Repr::Float(f) if f.is_nan())
+}
+
+fn same<T: Prim>(a: T, b: T) -> bool {
+    a == b || (is_nan(a) && is_nan(b))
+}
+
+fn mag<T: Prim>(x: T) -> u128 {
+    match x.repr() {
+        Repr::Int { mag, .. } => mag,
+        Repr::Float(_) => unreachable!(),
+    }
+}
+
+// Integer value fits into the integer type T
+fn in_range<T: Prim>(neg: bool, m: u128) -> bool {
+    if neg && m != 0 {
+        T::MIN.repr().is_negative() && m <= mag(T::MIN)
+    } else {
+        m <= mag(T::MAX)
+    }
+}
+
+// x is representable as T without any change
+fn exact_in<T: Prim>(x: Repr) -> bool {
+    // 2^128
+    let limit = 2f64.powi(128);
+    let digits = if T::BITS == 32 { 24 } else { 53 };
+    match x {
+        Repr::Float(f) if f.is_nan() => false,
+        Repr::Int { mag, .. } if T::IS_FLOAT => {
+            mag == 0 || 128 - mag.leading_zeros() - mag.trailing_zeros() <= digits
+        }
+        Repr::Float(f) if T::IS_FLOAT => T::BITS == 64 || f.is_infinite() || f as f32 as f64 == f,
+        Repr::Int { neg, mag } => in_range::<T>(neg, mag),
+        Repr::Float(f) => {
+            f.is_finite()
+                && f.fract() == 0.0
+                && f.abs() < limit
+                && in_range::<T>(f < 0.0, f.abs() as u128)
+        }
+    }
+}
+
+fn saturating_oracle<S: Prim + Cast<T>, T: Prim>(x: S) -> T {
+    let r = x.repr();
+    if exact_in::<T>(r) {
+        return x.cast();
+    }
+    let neg = r.is_negative();
+    let out_of_range = match r {
+        // Rounded to infinity
+        _ if T::IS_FLOAT => is_inf(x.cast()) && !is_inf(x),
+        Repr::Float(f) if f.is_nan() => return T::from_u128(0),
+        Repr::Float(f) => {
+            !f.is_finite() || f.abs() >= 2f64.powi(128) || {
+                let t = f.trunc();
+                !in_range::<T>(t < 0.0, t.abs() as u128)
+            }
+        }
+        Repr::Int { neg, mag } => !in_range::<T>(neg, mag),
+    };
+    match (out_of_range, neg) {
+        (true, true) => T::MIN,
+        (true, false) => T::MAX,
+        // Only rounded
+        _ => x.cast(),
+    }
+}
+
+fn is_inf<T: Prim>(x: T) -> bool {
+    matches!(x.repr(), Repr::Float(f) if f.is_infinite())
+}
+
+// None if the oracle has no answer
+fn wrapping_oracle<S: Prim + Cast<T>, T: Prim>(x: S) -> Option<T> {
+    match x.repr() {
+        Repr::Float(f) if !T::IS_FLOAT => {
+            if !f.is_finite() {
+                return Some(T::from_u128(0));
+            }
+            let t = f.trunc();
+            if t.abs() >= 2f64.powi(128) {
+                return None;
+            }
+            let m = t.abs() as u128;
+            Some(T::from_u128(if t < 0.0 { m.wrapping_neg() } else { m }))
+        }
+        // int -> int wraps, everything else is the same as `as`
+        _ => Some(x.cast()),
+    }
+}
+
+fn check_pair<S: Prim + Samples + Cast<T>, T: Prim>() -> usize {
+    let mut n = 0;
+    for x in S::samples() {
+        let r = x.cast_explain();
+        let fail = |what: &str| panic!("{what}: {r} {:?}", r.effects);
+        if !same(r.value, x.cast()) {
+            fail("explain value");
+        }
+        let exact = exact_in::<T>(x.repr());
+        if r.effects.is_exact() != exact {
+            fail("explain exact");
+        }
+        match x.cast_checked() {
+            Some(v) if !exact || !same(v, x.cast()) => fail("checked"),
+            None if exact => fail("checked"),
+            _ => {}
+        }
+        if !same(x.cast_saturating(), saturating_oracle::<S, T>(x)) {
+            fail("saturating");
+        }
+        if let Some(w) = wrapping_oracle::<S, T>(x) {
+            if !same(x.cast_wrapping(), w) {
+                fail("wrapping");
+            }
+        }
+        n += 1;
+    }
+    n
+}
+
+macro_rules! check_matrix {
+    ($($s:ident),*) => {
+        check_matrix!(@each [$($s),*] $($s),*)
+    };
+    (@each $all:tt $($s:ident),*) => {
+        0 $(+ check_matrix!(@pairs $s => $all))*
+    };
+    (@pairs $s:ident => [$($t:ident),*]) => {
+        0 $(+ check_pair::<$s, $t>())*
+    };
+}
+
+fn explain<S: Cast<T>, T: Prim>(x: S) -> CastReport<S, T> {
+    let r = x.cast_explain();
+    info!("{r}");
+    r
+}
+
+fn main() {
+    // What `as` does silently
+    let r = explain::<i32, u32>(-1);
+    assert_eq!(r.value, 4294967295);
+    assert!(r.effects.sign_flipped && !r.effects.truncated);
+
+    let r = explain::<i32, u8>(300);
+    assert_eq!(r.value, 44);
+    assert!(r.effects.truncated);
+
+    let r = explain::<i32, u8>(-1);
+    assert!(r.effects.truncated && r.effects.sign_flipped);
+
+    let r = explain::<i32, i8>(128);
+    assert_eq!(r.value, -128);
+
+    let r = explain::<f64, i32>(2.5);
+    assert_eq!(r.value, 2);
+    assert!(r.effects.rounded && !r.effects.saturated);
+
+    let r = explain::<f64, i32>(1e10);
+    assert_eq!(r.value, i32::MAX);
+    assert!(r.effects.saturated);
+
+    let r = explain::<f64, u8>(-1.5);
+    assert_eq!(r.value, 0);
+    assert!(r.effects.rounded && r.effects.saturated);
+
+    let r = explain::<f64, u8>(f64::NAN);
+    assert_eq!(r.value, 0);
+    assert!(r.effects.nan);
+
+    let r = explain::<i32, f32>(16777217);
+    assert_eq!(r.value, 16777216.0);
+    assert!(r.effects.rounded);
+
+    let r = explain::<u128, f32>(u128::MAX);
+    assert!(r.value.is_infinite() && r.effects.infinity);
+
+    let r = explain::<f64, f32>(1e40);
+    assert!(r.effects.infinity);
+
+    let r = explain::<f64, f32>(0.1);
+    assert!(r.effects.rounded);
+
+    let r = explain::<u8, f64>(255);
+    assert!(r.effects.is_exact());
+
+    // checked, saturating, wrapping
+    let x: Option<u8> = 300i32.cast_checked();
+    assert_eq!(x, None);
+    let x: Option<u8> = 255i32.cast_checked();
+    assert_eq!(x, Some(255));
+    let x: Option<u32> = (-1i32).cast_checked();
+    assert_eq!(x, None);
+    let x: Option<f32> = 16777216i64.cast_checked();
+    assert_eq!(x, Some(16777216.0));
+    let x: Option<f32> = 16777217i64.cast_checked();
+    assert_eq!(x, None);
+    let x: Option<i32> = 3.0f64.cast_checked();
+    assert_eq!(x, Some(3));
+
+    let x: u8 = 300i32.cast_saturating();
+    assert_eq!(x, 255);
+    let x: u32 = (-1i32).cast_saturating();
+    assert_eq!(x, 0);
+    let x: i8 = (-1000i64).cast_saturating();
+    assert_eq!(x, -128);
+    let x: f32 = f64::MAX.cast_saturating();
+    assert_eq!(x, f32::MAX);
+    let x: f32 = u128::MAX.cast_saturating();
+    assert_eq!(x, f32::MAX);
+
+    let x: u8 = 300i32.cast_wrapping();
+    assert_eq!(x, 44);
+    let x: u8 = 300.0f64.cast_wrapping();
+    assert_eq!(x, 44);
+    let x: u64 = (-1.0f64).cast_wrapping();
+    assert_eq!(x, u64::MAX);
+    let x: i8 = 200.9f32.cast_wrapping();
+    assert_eq!(x, -56);
+    // 2^70 + 2^20, the low 64 bits are 2^20
+    let x: u64 = (2f64.powi(70) + 2f64.powi(20)).cast_wrapping();
+    assert_eq!(x, 1 << 20);
+    let x: u128 = 2f64.powi(130).cast_wrapping();
+    assert_eq!(x, 0);
+    let x: i128 = (-2f64.powi(127)).cast_wrapping();
+    assert_eq!(x, i128::MIN);
+
+    // Every pair of the 14 types at the boundaries
+    let n = check_matrix!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+    info!("{n} casts checked");
+}
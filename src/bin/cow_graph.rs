@@ -0,0 +1,235 @@
+#![allow(unused)]
+
+// Copy on write Graph
+// - Graph in trait_clone_copy.rs holds Vec, clone = deep copy
+// - CowGraph stores vertices and edges in chunks behind Arc
+// - Clone = copy a few pointers, O(1)
+// - First write after a clone copies only the chunk that is written (Arc::make_mut)
+
+use hello_rust::info;
+use std::sync::Arc;
+use std::time::Instant;
+
+const CHUNK_SIZE: usize = 1024;
+
+// Vec<T> split into chunks, each chunk shared with Arc
+#[derive(Debug, Clone)]
+struct Chunked<T> {
+    chunks: Arc<Vec<Arc<Vec<T>>>>,
+    len: usize,
+}
+
+impl<T: Clone> Chunked<T> {
+    fn new() -> Self {
+        Self {
+            chunks: Arc::new(Vec::new()),
+            len: 0,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn get(&self, i: usize) -> Option<&T> {
+        if i >= self.len {
+            return None;
+        }
+        Some(&self.chunks[i / CHUNK_SIZE][i % CHUNK_SIZE])
+    }
+
+    // Copies the chunk if it is shared with a clone
+    fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        if i >= self.len {
+            return None;
+        }
+        let chunks = Arc::make_mut(&mut self.chunks);
+        let chunk = Arc::make_mut(&mut chunks[i / CHUNK_SIZE]);
+        Some(&mut chunk[i % CHUNK_SIZE])
+    }
+
+    fn push(&mut self, value: T) {
+        let chunks = Arc::make_mut(&mut self.chunks);
+        match chunks.last_mut() {
+            Some(last) if last.len() < CHUNK_SIZE => Arc::make_mut(last).push(value),
+            _ => {
+                let mut chunk = Vec::with_capacity(CHUNK_SIZE);
+                chunk.push(value);
+                chunks.push(Arc::new(chunk));
+            }
+        }
+        self.len += 1;
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &T> {
+        self.chunks.iter().flat_map(|chunk| chunk.iter())
+    }
+
+    // Number of chunks that are not shared with any clone
+    fn unique_chunks(&self) -> usize {
+        self.chunks
+            .iter()
+            .filter(|c| Arc::strong_count(c) == 1)
+            .count()
+    }
+}
+
+// Same data as Graph in trait_clone_copy.rs
+#[derive(Debug, Clone)]
+struct Graph {
+    vertices: Vec<u32>,
+    edges: Vec<(u32, u32)>,
+}
+
+#[derive(Debug, Clone)]
+struct CowGraph {
+    vertices: Chunked<u32>,
+    edges: Chunked<(u32, u32)>,
+    // Incremented on every mutation
+    version: u64,
+}
+
+impl CowGraph {
+    fn new() -> Self {
+        Self {
+            vertices: Chunked::new(),
+            edges: Chunked::new(),
+            version: 0,
+        }
+    }
+
+    fn add_vertex(&mut self, v: u32) {
+        self.vertices.push(v);
+        self.version += 1;
+    }
+
+    fn add_edge(&mut self, from: u32, to: u32) {
+        self.edges.push((from, to));
+        self.version += 1;
+    }
+
+    fn set_vertex(&mut self, i: usize, v: u32) {
+        *self
+            .vertices
+            .get_mut(i)
+            .expect("vertex index out of bounds") = v;
+        self.version += 1;
+    }
+
+    fn set_edge(&mut self, i: usize, edge: (u32, u32)) {
+        *self.edges.get_mut(i).expect("edge index out of bounds") = edge;
+        self.version += 1;
+    }
+
+    fn version(&self) -> u64 {
+        self.version
+    }
+
+    // Read only view of the graph at this version
+    // O(1), later writes to self don't change the snapshot
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            graph: self.clone(),
+        }
+    }
+
+    fn to_graph(&self) -> Graph {
+        Graph {
+            vertices: self.vertices.iter().copied().collect(),
+            edges: self.edges.iter().copied().collect(),
+        }
+    }
+}
+
+impl From<&Graph> for CowGraph {
+    fn from(g: &Graph) -> Self {
+        let mut out = CowGraph::new();
+        for &v in &g.vertices {
+            out.vertices.push(v);
+        }
+        for &e in &g.edges {
+            out.edges.push(e);
+        }
+        out
+    }
+}
+
+struct Snapshot {
+    graph: CowGraph,
+}
+
+impl Snapshot {
+    fn version(&self) -> u64 {
+        self.graph.version
+    }
+
+    fn vertex(&self, i: usize) -> Option<u32> {
+        self.graph.vertices.get(i).copied()
+    }
+
+    fn edge(&self, i: usize) -> Option<(u32, u32)> {
+        self.graph.edges.get(i).copied()
+    }
+}
+
+// Run f n times, return average time in nanoseconds
+fn bench<T>(n: u32, mut f: impl FnMut() -> T) -> u128 {
+    let start = Instant::now();
+    for _ in 0..n {
+        std::hint::black_box(f());
+    }
+    start.elapsed().as_nanos() / n as u128
+}
+
+fn main() {
+    let mut g = CowGraph::new();
+    for v in 0..5 {
+        g.add_vertex(v);
+    }
+    g.add_edge(0, 1);
+    g.add_edge(1, 2);
+
+    let snap = g.snapshot();
+    g.set_vertex(0, 100);
+    g.add_edge(2, 3);
+    info!(
+        "snapshot v{} vertex 0 = {:?}",
+        snap.version(),
+        snap.vertex(0)
+    );
+    info!(
+        "current  v{} vertex 0 = {:?}",
+        g.version(),
+        g.vertices.get(0)
+    );
+    info!("{:?}", g.to_graph());
+
+    // Large graph
+    let n = 1_000_000;
+    let graph = Graph {
+        vertices: (0..n).collect(),
+        edges: (0..n).map(|i| (i, (i + 1) % n)).collect(),
+    };
+    let cow = CowGraph::from(&graph);
+
+    // Only the written chunk is copied
+    let mut g1 = cow.clone();
+    g1.set_vertex(0, 42);
+    info!(
+        "chunks = {}, copied after 1 write = {}",
+        g1.vertices.chunks.len(),
+        g1.vertices.unique_chunks()
+    );
+
+    let deep = bench(10, || graph.clone());
+    let shallow = bench(10, || cow.clone());
+    let clone_write = bench(10, || {
+        let mut g = cow.clone();
+        g.set_vertex(n as usize / 2, 0);
+        g.set_edge(0, (0, 0));
+        g
+    });
+    info!("deep clone          {:>12} ns", deep);
+    info!("cow clone           {:>12} ns", shallow);
+    info!("cow clone + 2 write {:>12} ns", clone_write);
+}
@@ -0,0 +1,207 @@
+#![allow(unused)]
+
+// rustc style diagnostics with hello_rust::diagnostic (src/diagnostic.rs)
+// cargo run --bin diagnostic
+// cargo run --bin diagnostic -- --json
+
+use hello_rust::diagnostic::{Diagnostic, Renderer, Source, Span};
+use hello_rust::info;
+use std::env;
+use std::fs;
+use std::num::{IntErrorKind, ParseIntError};
+
+// ParseIntError { kind: InvalidDigit } does not say where, find the bad char
+fn check_line(src: &Source, line_start: usize, line: &str) -> Option<Diagnostic> {
+    let text = line.trim();
+    let start = line_start + (line.len() - line.trim_start().len());
+    let span = Span::new(start, start + text.len());
+    let err: ParseIntError = text.parse::<i64>().err()?;
+    let d = match err.kind() {
+        IntErrorKind::InvalidDigit => {
+            let (i, c) = text
+                .char_indices()
+                .find(|&(i, c)| !(c.is_ascii_digit() || (i == 0 && "+-".contains(c))))
+                .unwrap_or((0, ' '));
+            let bad = Span::new(start + i, start + i + c.len_utf8());
+            Diagnostic::error(err.to_string())
+                .with_code("E0001")
+                .with_primary(bad, "not a digit")
+                .with_label(span, "in this number")
+                .with_help(format!("remove {c:?}"))
+        }
+        IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => Diagnostic::error(err.to_string())
+            .with_code("E0002")
+            .with_primary(span, "does not fit in i64")
+            .with_note(format!("the range of i64 is {} to {}", i64::MIN, i64::MAX)),
+        _ => Diagnostic::error(err.to_string()).with_primary(span, ""),
+    };
+    Some(d)
+}
+
+fn parse_all(src: &Source) -> Vec<Diagnostic> {
+    let mut errors = Vec::new();
+    let mut offset = 0;
+    for line in src.text.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        // Skip empty lines and # comment lines
+        let text = content.trim();
+        if !text.is_empty() && !text.starts_with('#') {
+            if let Some(d) = check_line(src, offset, content) {
+                errors.push(d);
+            }
+        }
+        offset += line.len();
+    }
+    errors
+}
+
+fn main() {
+    let json = env::args().any(|a| a == "--json");
+    let plain = Renderer::new(false);
+
+    let numbers = Source::new("numbers.txt", "1\n2\n3z\n 99999999999999999999\n");
+    let src = &numbers;
+    let errors = parse_all(src);
+    assert_eq!(errors.len(), 2);
+    assert_eq!(
+        plain.render(&errors[0], src),
+        "\
+error[E0001]: invalid digit found in string
+ --> numbers.txt:3:2
+  |
+3 | 3z
+  | -^ not a digit
+  | |
+  | in this number
+  |
+  = help: remove 'z'
+"
+    );
+    assert_eq!(
+        plain.render(&errors[1], src),
+        "\
+error[E0002]: number too large to fit in target type
+ --> numbers.txt:4:2
+  |
+4 |  99999999999999999999
+  |  ^^^^^^^^^^^^^^^^^^^^ does not fit in i64
+  |
+  = note: the range of i64 is -9223372036854775808 to 9223372036854775807
+"
+    );
+
+    // Multi-line spans and labels on different lines
+    let code = "fn main() {\n    let x: u8 = 300;\n    let y = x\n        + 1;\n}\n";
+    let src = Source::new("src/main.rs", code);
+    let at = |s: &str| code.find(s).unwrap();
+    let d = Diagnostic::warning("unused variable `y`")
+        .with_primary(Span::new(at("y ="), at("y =") + 1), "not used")
+        .with_label(Span::new(at("x\n"), at("1;") + 1), "this value")
+        .with_label(
+            Span::new(at("300"), at("300") + 3),
+            "literal out of range for `u8`",
+        )
+        .with_help("prefix it with an underscore: `_y`");
+    let out = plain.render(&d, &src);
+    assert_eq!(
+        out,
+        "\
+warning: unused variable `y`
+ --> src/main.rs:3:9
+  |
+2 |       let x: u8 = 300;
+  |                   --- literal out of range for `u8`
+3 |       let y = x
+  |           ^ not used
+  |  _____________-
+4 | |         + 1;
+  | |___________- this value
+  |
+  = help: prefix it with an underscore: `_y`
+"
+    );
+
+    // Whole function, the span starts at the indent
+    let d = Diagnostic::error("function is never called")
+        .with_primary(Span::new(0, code.len() - 1), "defined here")
+        .with_note("`#[warn(dead_code)]` on by default");
+    let out = plain.render(&d, &src);
+    assert_eq!(
+        out,
+        "\
+error: function is never called
+ --> src/main.rs:1:1
+  |
+1 | / fn main() {
+2 | |     let x: u8 = 300;
+3 | |     let y = x
+4 | |         + 1;
+5 | | }
+  | |_^ defined here
+  |
+  = note: `#[warn(dead_code)]` on by default
+"
+    );
+
+    // A tab is 4 columns, the column in the header is in chars
+    let src = Source::new("tab.rs", "\tx = 'é';\n");
+    let d = Diagnostic::error("tab").with_primary(Span::new(6, 8), "2 bytes");
+    assert_eq!(
+        plain.render(&d, &src),
+        "error: tab\n --> tab.rs:1:7\n  |\n1 |     x = 'é';\n  |          ^ 2 bytes\n"
+    );
+
+    // 2 labels start in the same column, they share 1 |
+    let src = Source::new("same.rs", "let abc = 1;\n");
+    let d = Diagnostic::error("same column")
+        .with_label(Span::new(0, 5), "statement")
+        .with_label(Span::new(0, 1), "keyword")
+        .with_primary(Span::new(7, 8), "here");
+    let out = plain.render(&d, &src);
+    assert_eq!(
+        out,
+        "\
+error: same column
+ --> same.rs:1:8
+  |
+1 | let abc = 1;
+  | -----  ^ here
+  | |
+  | keyword
+  | |
+  | statement
+"
+    );
+
+    // Far apart lines are separated by ...
+    let long: String = (1..=12).map(|i| format!("line {i}\n")).collect();
+    let src = Source::new("long.txt", &long);
+    let at = |s: &str| long.find(s).unwrap();
+    let d = Diagnostic::error("lines 2 and 11")
+        .with_primary(Span::new(at("line 2"), at("line 2") + 6), "first")
+        .with_label(Span::new(at("line 11"), at("line 11") + 7), "second");
+    let out = plain.render(&d, &src);
+    assert!(out.contains("\n...\n11 | line 11\n"));
+    assert!(out.starts_with("error: lines 2 and 11\n  --> long.txt:2:1\n"));
+
+    // Comment lines are skipped, every other line is a decimal i64
+    let file = "data/diagnostic_bad.txt";
+    let text = fs::read_to_string(file).unwrap();
+    let src = Source::new(file, &text);
+    let errors = parse_all(&src);
+    let lines: Vec<usize> = errors
+        .iter()
+        .map(|d| src.line_of(d.primary().unwrap().span.start) + 1)
+        .collect();
+    assert_eq!(lines, [3, 5, 6]);
+
+    // Print with color if stderr is a terminal and NO_COLOR is not set, or JSON
+    let renderer = Renderer::auto();
+    for d in &errors {
+        if json {
+            info!("{}", renderer.json(d, &src));
+        } else {
+            eprintln!("{}", renderer.render(d, &src));
+        }
+    }
+}
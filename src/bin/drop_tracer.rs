@@ -0,0 +1,272 @@
+#![allow(unused)]
+
+// Drop order tracing
+// - DropTracer hands out Tracked<T> values
+// - Dropping a Tracked<T> appends an event to a shared, thread safe log
+// - Assert drop order instead of reading "Dropping a" from stdout (trait_drop.rs)
+
+use hello_rust::info;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+#[derive(Debug, Clone)]
+struct DropEvent {
+    name: String,
+    // Debug output of thread::ThreadId, name of the thread if it has one
+    thread: String,
+    seq: usize,
+}
+
+#[derive(Debug, Default)]
+struct Log {
+    created: Vec<String>,
+    dropped: Vec<DropEvent>,
+}
+
+#[derive(Clone, Default)]
+struct DropTracer {
+    log: Arc<Mutex<Log>>,
+}
+
+struct Tracked<T> {
+    name: String,
+    value: T,
+    log: Arc<Mutex<Log>>,
+}
+
+impl DropTracer {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn track<T>(&self, name: &str, value: T) -> Tracked<T> {
+        self.log.lock().unwrap().created.push(name.to_string());
+        Tracked {
+            name: name.to_string(),
+            value,
+            log: Arc::clone(&self.log),
+        }
+    }
+
+    fn events(&self) -> Vec<DropEvent> {
+        self.log.lock().unwrap().dropped.clone()
+    }
+
+    fn dropped(&self) -> Vec<String> {
+        self.events().into_iter().map(|e| e.name).collect()
+    }
+
+    fn is_dropped(&self, name: &str) -> bool {
+        self.log
+            .lock()
+            .unwrap()
+            .dropped
+            .iter()
+            .any(|e| e.name == name)
+    }
+
+    fn assert_dropped(&self, name: &str) {
+        assert!(self.is_dropped(name), "expected {name:?} to be dropped");
+    }
+
+    fn assert_not_dropped(&self, name: &str) {
+        assert!(!self.is_dropped(name), "expected {name:?} to be alive");
+    }
+
+    // Names that were tracked but are not dropped (yet)
+    // Tracking the same name twice needs two drops
+    fn alive(&self) -> Vec<String> {
+        let log = self.log.lock().unwrap();
+        let mut alive = log.created.clone();
+        for e in &log.dropped {
+            if let Some(i) = alive.iter().position(|n| *n == e.name) {
+                alive.remove(i);
+            }
+        }
+        alive
+    }
+
+    fn leak_report(&self) -> LeakReport {
+        LeakReport {
+            leaked: self.alive(),
+        }
+    }
+
+    // Leak check at the end of a scope
+    fn scope(&self) -> LeakCheck<'_> {
+        LeakCheck { tracer: self }
+    }
+}
+
+impl<T> Drop for Tracked<T> {
+    fn drop(&mut self) {
+        let current = thread::current();
+        let thread = match current.name() {
+            Some(name) => name.to_string(),
+            None => format!("{:?}", current.id()),
+        };
+        // Don't panic inside drop while already panicking
+        let mut log = match self.log.lock() {
+            Ok(log) => log,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let seq = log.dropped.len();
+        log.dropped.push(DropEvent {
+            name: self.name.clone(),
+            thread,
+            seq,
+        });
+    }
+}
+
+impl<T> Deref for Tracked<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Tracked<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Tracked<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Tracked({:?}, {:?})", self.name, self.value)
+    }
+}
+
+#[derive(Debug)]
+struct LeakReport {
+    leaked: Vec<String>,
+}
+
+impl LeakReport {
+    fn is_clean(&self) -> bool {
+        self.leaked.is_empty()
+    }
+}
+
+impl fmt::Display for LeakReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.leaked.is_empty() {
+            write!(f, "no leaks")
+        } else {
+            write!(
+                f,
+                "{} leaked: {}",
+                self.leaked.len(),
+                self.leaked.join(", ")
+            )
+        }
+    }
+}
+
+// Panics on drop if anything tracked is still alive
+struct LeakCheck<'a> {
+    tracer: &'a DropTracer,
+}
+
+impl Drop for LeakCheck<'_> {
+    fn drop(&mut self) {
+        let report = self.tracer.leak_report();
+        if !report.is_clean() && !thread::panicking() {
+            panic!("leak check failed: {report}");
+        }
+    }
+}
+
+// assert_dropped_in_order!(tracer, "a", "b", "c")
+// The names must be dropped in this order, other drops can be in between
+macro_rules! assert_dropped_in_order {
+    ($tracer:expr, $($name:expr),+ $(,)?) => {{
+        let dropped = $tracer.dropped();
+        let expected: Vec<&str> = vec![$($name),+];
+        let mut it = dropped.iter();
+        for name in &expected {
+            assert!(
+                it.any(|d| d == name),
+                "expected drop order {:?}, got {:?}",
+                expected,
+                dropped
+            );
+        }
+    }};
+}
+
+fn take(s: Tracked<String>) {
+    info!("take {}", *s);
+    // s is dropped
+}
+
+fn main() {
+    // trait_drop.rs
+    let tracer = DropTracer::new();
+    {
+        let _check = tracer.scope();
+        let a = tracer.track("a", ());
+        let b = tracer.track("b", ());
+        let c = tracer.track("c", ());
+
+        std::mem::drop(b);
+        {
+            c;
+        }
+        // a is dropped at the end of the scope
+    }
+    assert_dropped_in_order!(tracer, "b", "c", "a");
+    info!("{:?}", tracer.dropped());
+
+    // Locals are dropped in reverse order of declaration
+    let tracer = DropTracer::new();
+    {
+        let x = tracer.track("x", 1);
+        let y = tracer.track("y", 2);
+    }
+    assert_dropped_in_order!(tracer, "y", "x");
+
+    // ownership.rs - moving into a function drops at the end of the function
+    let tracer = DropTracer::new();
+    let s = tracer.track("s", String::from("cat"));
+    let s1 = s;
+    tracer.assert_not_dropped("s");
+    take(s1);
+    tracer.assert_dropped("s");
+
+    // move.rs - a move closure owns the value, dropped with the closure
+    let tracer = DropTracer::new();
+    let s = tracer.track("s", "hello".to_string());
+    let f = move || info!("move: {}", *s);
+    f();
+    tracer.assert_not_dropped("s");
+    std::mem::drop(f);
+    tracer.assert_dropped("s");
+
+    // Drops from other threads
+    let tracer = DropTracer::new();
+    let v = tracer.track("v", vec![1, 2, 3]);
+    thread::Builder::new()
+        .name("worker".to_string())
+        .spawn(move || info!("sum = {}", v.iter().sum::<i32>()))
+        .unwrap()
+        .join()
+        .unwrap();
+    let local = tracer.track("local", ());
+    std::mem::drop(local);
+    for e in tracer.events() {
+        info!("{e:?}");
+    }
+
+    // Leak report
+    let tracer = DropTracer::new();
+    let leaked = tracer.track("leaked", ());
+    std::mem::forget(leaked);
+    let dropped = tracer.track("dropped", ());
+    std::mem::drop(dropped);
+    info!("{}", tracer.leak_report());
+}
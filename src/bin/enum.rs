@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 #[derive(Debug, PartialEq)]
 enum Color {
     Red,
@@ -16,19 +18,19 @@ fn main() {
     let color = Color::Hex(String::from("ffffff"));
     let color = Color::Hsl { h: 1, s: 2, l: 0 };
     // Debug
-    println!("{:?}", color);
+    info!("{:?}", color);
 
     // PartialEq
-    println!("blue == red ? {}", Color::Blue == Color::Red);
-    println!("green == green ? {}", Color::Green == Color::Green);
+    info!("blue == red ? {}", Color::Blue == Color::Red);
+    info!("green == green ? {}", Color::Green == Color::Green);
 
     // Option<T> = Some(T) | None
     let x: Option<i32> = None;
     let x: Option<i32> = Some(-1);
-    println!("{:?}", x);
+    info!("{:?}", x);
 
     // Result<T, E> = Ok(T) | Error(E)
     let res: Result<i32, &str> = Err("error 💀");
     let res: Result<i32, &str> = Ok(100);
-    println!("{:?}", res);
+    info!("{:?}", res);
 }
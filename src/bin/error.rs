@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 #[derive(Debug)]
 enum MathError {
     DivByZero,
@@ -18,8 +20,8 @@ fn main() {
     let x = v.get(99);
     // Error handling with Option
     match x {
-        Some(i) => println!("{i}"),
-        None => println!("none"),
+        Some(i) => info!("{i}"),
+        None => info!("none"),
     }
 
     let x = 1;
@@ -35,7 +37,7 @@ fn main() {
     };
     // Error handling with Result
     match q {
-        Ok(r) => println!("{x} / {y} = {r}"),
-        Err(err) => println!("{x} / {y} {:?}", err),
+        Ok(r) => info!("{x} / {y} = {r}"),
+        Err(err) => info!("{x} / {y} {:?}", err),
     }
 }
@@ -1,19 +1,21 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 fn main() {
     // Unwrap and expect
     let x: Option<u32> = Some(1);
     // Unwraps the inner value. Panics if None
     let i = x.unwrap();
-    println!("{}", i);
+    info!("{}", i);
 
     let res: Result<u32, String> = Ok(123);
     // Unwraps the inner value. Panics if Err
     let i = res.unwrap();
-    println!("res = {}", i);
+    info!("res = {}", i);
 
     let res: Result<u32, String> = Err("error".to_string());
     // Same as unwrap with custom error message
     let i = res.expect("result not ok");
-    println!("res = {}", i);
+    info!("res = {}", i);
 }
@@ -0,0 +1,625 @@
+#![allow(unused)]
+
+// Open addressing hash map
+// - All entries in one Vec, no pointer per entry
+// - Robin Hood probing: an entry far from its ideal slot takes the slot of
+//   an entry closer to its ideal slot, keeping probe lengths short
+// - Backward shift deletion: no tombstones
+// - Entry API like std HashMap (hash_map.rs)
+// - The map itself only needs core and alloc (Vec), the hasher is the S parameter.
+//   RandomState, new(), Default and FromIterator are the std only parts,
+//   with_hasher works with any BuildHasher, see Fnv below
+//
+// Differential test against std HashMap, seeded so every run is the same:
+// cargo run --bin flat_map            20 seeds, about 1s in debug
+// cargo run --bin flat_map -- 1000    more seeds
+
+use hello_rust::info;
+use std::borrow::Borrow;
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{BuildHasher, BuildHasherDefault, Hash, Hasher};
+use std::mem;
+
+struct Slot<K, V> {
+    hash: u64,
+    key: K,
+    value: V,
+}
+
+struct FlatMap<K, V, S = RandomState> {
+    // Length is 0 or a power of 2
+    slots: Vec<Option<Slot<K, V>>>,
+    len: usize,
+    hasher: S,
+    max_load: f64,
+}
+
+enum Probe {
+    Found(usize),
+    // Key is not in the map, it belongs at this index
+    Vacant(usize),
+}
+
+impl<K: Hash + Eq, V> FlatMap<K, V, RandomState> {
+    fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> FlatMap<K, V, S> {
+    fn with_hasher(hasher: S) -> Self {
+        Self {
+            slots: Vec::new(),
+            len: 0,
+            hasher,
+            max_load: 0.875,
+        }
+    }
+
+    // Max ratio of entries to slots before growing, in (0, 1)
+    fn with_max_load(mut self, max_load: f64) -> Self {
+        assert!(
+            max_load > 0.0 && max_load < 1.0,
+            "max load must be in (0, 1)"
+        );
+        self.max_load = max_load;
+        self
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn capacity(&self) -> usize {
+        (self.slots.len() as f64 * self.max_load) as usize
+    }
+
+    fn mask(&self) -> usize {
+        self.slots.len() - 1
+    }
+
+    fn hash<Q: Hash + ?Sized>(&self, key: &Q) -> u64 {
+        self.hasher.hash_one(key)
+    }
+
+    // Distance of the entry at i from its ideal slot
+    fn dist(&self, i: usize, hash: u64) -> usize {
+        i.wrapping_sub(hash as usize) & self.mask()
+    }
+
+    fn probe<Q>(&self, hash: u64, key: &Q) -> Probe
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        if self.slots.is_empty() {
+            return Probe::Vacant(0);
+        }
+        let mask = self.mask();
+        let mut i = hash as usize & mask;
+        let mut dist = 0;
+        loop {
+            match &self.slots[i] {
+                None => return Probe::Vacant(i),
+                Some(slot) => {
+                    if slot.hash == hash && slot.key.borrow() == key {
+                        return Probe::Found(i);
+                    }
+                    // Our key would have taken this slot, so it is not in the map
+                    if self.dist(i, slot.hash) < dist {
+                        return Probe::Vacant(i);
+                    }
+                }
+            }
+            i = (i + 1) & mask;
+            dist += 1;
+        }
+    }
+
+    // Place a new entry at i, pushing the displaced entries further
+    fn place(&mut self, mut i: usize, slot: Slot<K, V>) {
+        let mask = self.mask();
+        let mut carry = Some(slot);
+        let mut dist = self.dist(i, carry.as_ref().unwrap().hash);
+        while let Some(mut c) = carry.take() {
+            match &mut self.slots[i] {
+                empty @ None => *empty = Some(c),
+                Some(existing) => {
+                    let existing_dist = i.wrapping_sub(existing.hash as usize) & mask;
+                    if existing_dist < dist {
+                        mem::swap(existing, &mut c);
+                        dist = existing_dist;
+                    }
+                    carry = Some(c);
+                    i = (i + 1) & mask;
+                    dist += 1;
+                }
+            }
+        }
+        self.len += 1;
+    }
+
+    // Replace the slots with n empty slots, return the old slots
+    fn take_slots(&mut self, n: usize) -> Vec<Option<Slot<K, V>>> {
+        self.len = 0;
+        mem::replace(&mut self.slots, (0..n).map(|_| None).collect())
+    }
+
+    fn grow(&mut self) {
+        let old = self.take_slots((self.slots.len() * 2).max(8));
+        for slot in old.into_iter().flatten() {
+            let i = slot.hash as usize & self.mask();
+            self.place(i, slot);
+        }
+    }
+
+    // Remove the entry at i and shift the following entries back
+    fn remove_at(&mut self, mut i: usize) -> Slot<K, V> {
+        let mask = self.mask();
+        let removed = self.slots[i].take().unwrap();
+        loop {
+            let next = (i + 1) & mask;
+            match &self.slots[next] {
+                Some(slot) if self.dist(next, slot.hash) > 0 => {
+                    self.slots[i] = self.slots[next].take();
+                    i = next;
+                }
+                _ => break,
+            }
+        }
+        self.len -= 1;
+        removed
+    }
+
+    fn entry(&mut self, key: K) -> Entry<'_, K, V, S> {
+        let hash = self.hash(&key);
+        match self.probe(hash, &key) {
+            Probe::Found(index) => Entry::Occupied(OccupiedEntry { map: self, index }),
+            Probe::Vacant(_) => Entry::Vacant(VacantEntry {
+                map: self,
+                hash,
+                key,
+            }),
+        }
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.entry(key) {
+            Entry::Occupied(mut e) => Some(e.insert(value)),
+            Entry::Vacant(e) => {
+                e.insert(value);
+                None
+            }
+        }
+    }
+
+    fn find<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match self.probe(self.hash(key), key) {
+            Probe::Found(i) => Some(i),
+            Probe::Vacant(_) => None,
+        }
+    }
+
+    fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let i = self.find(key)?;
+        self.slots[i].as_ref().map(|s| &s.value)
+    }
+
+    fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let i = self.find(key)?;
+        self.slots[i].as_mut().map(|s| &mut s.value)
+    }
+
+    fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(key).is_some()
+    }
+
+    fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let i = self.find(key)?;
+        Some(self.remove_at(i).value)
+    }
+
+    // Keep the entries where f returns true
+    // Entries are re-placed so that probe chains stay valid
+    fn retain(&mut self, mut f: impl FnMut(&K, &mut V) -> bool) {
+        let old = self.take_slots(self.slots.len());
+        for mut slot in old.into_iter().flatten() {
+            if f(&slot.key, &mut slot.value) {
+                let i = slot.hash as usize & self.mask();
+                self.place(i, slot);
+            }
+        }
+    }
+
+    // Remove all entries, capacity is kept
+    fn drain(&mut self) -> Drain<K, V> {
+        let old = self.take_slots(self.slots.len());
+        Drain {
+            inner: old.into_iter(),
+        }
+    }
+
+    fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            inner: self.slots.iter(),
+        }
+    }
+
+    fn iter_mut(&mut self) -> impl Iterator<Item = (&K, &mut V)> {
+        self.slots
+            .iter_mut()
+            .flatten()
+            .map(|s| (&s.key, &mut s.value))
+    }
+
+    fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, v)| v)
+    }
+
+    // Longest distance from an ideal slot
+    fn max_probe(&self) -> usize {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter_map(|(i, s)| s.as_ref().map(|s| self.dist(i, s.hash)))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl<K: Hash + Eq, V> Default for FlatMap<K, V, RandomState> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, S> fmt::Debug for FlatMap<K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.slots.iter().flatten().map(|s| (&s.key, &s.value)))
+            .finish()
+    }
+}
+
+struct Iter<'a, K, V> {
+    inner: std::slice::Iter<'a, Option<Slot<K, V>>>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .by_ref()
+            .flatten()
+            .next()
+            .map(|s| (&s.key, &s.value))
+    }
+}
+
+impl<'a, K: Hash + Eq, V, S: BuildHasher> IntoIterator for &'a FlatMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+struct Drain<K, V> {
+    inner: std::vec::IntoIter<Option<Slot<K, V>>>,
+}
+
+impl<K, V> Iterator for Drain<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .by_ref()
+            .flatten()
+            .next()
+            .map(|s| (s.key, s.value))
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> IntoIterator for FlatMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = Drain<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Drain {
+            inner: self.slots.into_iter(),
+        }
+    }
+}
+
+impl<K: Hash + Eq, V> FromIterator<(K, V)> for FlatMap<K, V, RandomState> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (k, v) in iter {
+            map.insert(k, v);
+        }
+        map
+    }
+}
+
+enum Entry<'a, K, V, S> {
+    Occupied(OccupiedEntry<'a, K, V, S>),
+    Vacant(VacantEntry<'a, K, V, S>),
+}
+
+struct OccupiedEntry<'a, K, V, S> {
+    map: &'a mut FlatMap<K, V, S>,
+    index: usize,
+}
+
+struct VacantEntry<'a, K, V, S> {
+    map: &'a mut FlatMap<K, V, S>,
+    hash: u64,
+    key: K,
+}
+
+impl<'a, K: Hash + Eq, V, S: BuildHasher> Entry<'a, K, V, S> {
+    fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    fn or_insert_with(self, default: impl FnOnce() -> V) -> &'a mut V {
+        match self {
+            Entry::Occupied(e) => e.into_mut(),
+            Entry::Vacant(e) => e.insert(default()),
+        }
+    }
+
+    fn or_insert_with_key(self, default: impl FnOnce(&K) -> V) -> &'a mut V {
+        match self {
+            Entry::Occupied(e) => e.into_mut(),
+            Entry::Vacant(e) => {
+                let value = default(&e.key);
+                e.insert(value)
+            }
+        }
+    }
+
+    fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    fn and_modify(mut self, f: impl FnOnce(&mut V)) -> Self {
+        if let Entry::Occupied(e) = &mut self {
+            f(e.get_mut());
+        }
+        self
+    }
+
+    fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(e) => e.key(),
+            Entry::Vacant(e) => e.key(),
+        }
+    }
+}
+
+impl<'a, K: Hash + Eq, V, S: BuildHasher> OccupiedEntry<'a, K, V, S> {
+    fn slot(&self) -> &Slot<K, V> {
+        self.map.slots[self.index].as_ref().unwrap()
+    }
+
+    fn key(&self) -> &K {
+        &self.slot().key
+    }
+
+    fn get(&self) -> &V {
+        &self.slot().value
+    }
+
+    fn get_mut(&mut self) -> &mut V {
+        &mut self.map.slots[self.index].as_mut().unwrap().value
+    }
+
+    fn into_mut(self) -> &'a mut V {
+        &mut self.map.slots[self.index].as_mut().unwrap().value
+    }
+
+    fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    fn remove_entry(self) -> (K, V) {
+        let slot = self.map.remove_at(self.index);
+        (slot.key, slot.value)
+    }
+
+    fn remove(self) -> V {
+        self.remove_entry().1
+    }
+}
+
+impl<'a, K: Hash + Eq, V, S: BuildHasher> VacantEntry<'a, K, V, S> {
+    fn key(&self) -> &K {
+        &self.key
+    }
+
+    fn into_key(self) -> K {
+        self.key
+    }
+
+    fn insert(self, value: V) -> &'a mut V {
+        let map = self.map;
+        if map.len + 1 > map.capacity() {
+            map.grow();
+        }
+        // Probe again, grow may have moved the entries
+        let index = match map.probe(self.hash, &self.key) {
+            Probe::Vacant(i) => i,
+            Probe::Found(_) => unreachable!("vacant entry key is in the map"),
+        };
+        map.place(
+            index,
+            Slot {
+                hash: self.hash,
+                key: self.key,
+                value,
+            },
+        );
+        // The new entry stays at index, only entries after it are moved
+        &mut map.slots[index].as_mut().unwrap().value
+    }
+}
+
+// FNV-1a, core only, no random seed (not HashDoS resistant)
+#[derive(Default)]
+struct Fnv(u64);
+
+impl Hasher for Fnv {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        if self.0 == 0 {
+            self.0 = 0xcbf2_9ce4_8422_2325;
+        }
+        for &b in bytes {
+            self.0 = (self.0 ^ b as u64).wrapping_mul(0x100_0000_01b3);
+        }
+    }
+}
+
+type FnvBuild = BuildHasherDefault<Fnv>;
+
+// xorshift64, deterministic random numbers for the differential test
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+fn same<S: BuildHasher>(a: &FlatMap<u32, u64, S>, b: &HashMap<u32, u64>) -> bool {
+    a.len() == b.len() && a.iter().all(|(k, v)| b.get(k) == Some(v))
+}
+
+// Apply the same random operations to FlatMap and HashMap and compare
+fn differential_test(seed: u64, ops: usize) {
+    let mut rng = Rng(seed);
+    let mut flat = FlatMap::with_hasher(FnvBuild::default()).with_max_load(0.9);
+    let mut std = HashMap::new();
+    for step in 0..ops {
+        // Small key space so that keys repeat
+        let key = (rng.next() % 512) as u32;
+        let value = rng.next();
+        match rng.next() % 8 {
+            0..=2 => assert_eq!(flat.insert(key, value), std.insert(key, value)),
+            3 | 4 => assert_eq!(flat.remove(&key), std.remove(&key)),
+            5 => assert_eq!(flat.get(&key), std.get(&key)),
+            6 => {
+                *flat.entry(key).and_modify(|v| *v += 1).or_insert(value) += 1;
+                *std.entry(key).and_modify(|v| *v += 1).or_insert(value) += 1;
+            }
+            _ => {
+                if step % 64 == 0 {
+                    flat.retain(|k, _| k % 3 != 0);
+                    std.retain(|k, _| k % 3 != 0);
+                }
+            }
+        }
+        assert_eq!(flat.len(), std.len(), "len mismatch at step {step}");
+        // Full compare is O(n), not after every step
+        if step % 64 == 0 {
+            assert!(same(&flat, &std), "mismatch at step {step}");
+        }
+    }
+    assert!(same(&flat, &std));
+    let mut drained: Vec<_> = flat.drain().collect();
+    let mut expected: Vec<_> = std.drain().collect();
+    drained.sort();
+    expected.sort();
+    assert_eq!(drained, expected);
+    assert!(flat.is_empty());
+}
+
+fn main() {
+    let mut scores: FlatMap<String, u32> = FlatMap::new();
+
+    scores.insert(String::from("red"), 100);
+    scores.insert(String::from("blue"), 200);
+
+    // Get
+    info!("red: {:?}", scores.get("red"));
+    info!("green: {:?}", scores.get("green"));
+
+    // Upsert
+    let score: &mut u32 = scores.entry("blue".to_string()).or_insert(0);
+    *score += 200;
+    info!("blue: {:?}", scores.get("blue"));
+
+    *scores.entry("green".to_string()).or_default() += 1;
+    scores
+        .entry("green".to_string())
+        .and_modify(|s| *s *= 10)
+        .or_insert(0);
+    info!("scores: {:?}", scores);
+
+    if let Entry::Occupied(e) = scores.entry("red".to_string()) {
+        info!("removed {:?}", e.remove_entry());
+    }
+
+    scores.retain(|_, s| *s > 100);
+    info!("retain > 100: {:?}", scores);
+
+    // Robin Hood keeps probe lengths short even when full
+    let map: FlatMap<u32, u32> = (0..10_000).map(|i| (i, i * i)).collect();
+    info!(
+        "len = {}, slots = {}, max probe = {}",
+        map.len(),
+        map.slots.len(),
+        map.max_probe()
+    );
+
+    let seeds: u64 = std::env::args()
+        .nth(1)
+        .map(|n| n.parse().expect("number of seeds"))
+        .unwrap_or(20);
+    for seed in 1..=seeds {
+        differential_test(seed, 5_000);
+    }
+    info!("differential test against HashMap passed, {seeds} seeds");
+}
@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 // Function pointer
 fn add(x: u32, y: u32) -> u32 {
     x + y
@@ -21,14 +23,14 @@ fn fn_pointer_mut(f: fn(&mut Vec<u32>, u32), v: &mut Vec<u32>, x: u32) {
 fn main() {
     // Store a function pointer to a variable
     let f: fn(u32, u32) -> u32 = add;
-    println!("f: {}", f(1, 2));
+    info!("f: {}", f(1, 2));
 
     // Function pointer as input to a function
     let z = do_twice(add, 1, 2);
-    println!("fn pointer: {z}");
+    info!("fn pointer: {z}");
 
     // Function pointer that mutates a data as input to a function
     let mut v: Vec<u32> = vec![1, 2, 3];
     fn_pointer_mut(push, &mut v, 4);
-    println!("fn pointer mut: {:?}", v);
+    info!("fn pointer mut: {:?}", v);
 }
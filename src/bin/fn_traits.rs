@@ -1,6 +1,9 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 // Fn, FnMut and FnOnce traits
+// Storing them in a struct: callbacks.rs
 
 fn f_fn<T, F: Fn() -> T>(f: F) {
     // Can call more than once
@@ -33,11 +36,11 @@ fn main() {
 
     // Fn
     let s = "hello".to_string();
-    let f = || println!("fn: {}", s);
+    let f = || info!("fn: {}", s);
     f_fn(f);
     // Can call more than once
     f_fn(f);
-    println!("main: {}", s);
+    info!("main: {}", s);
 
     // FnMut
     let mut v = vec![];
@@ -45,14 +48,14 @@ fn main() {
     f_mut(&mut f);
     // Can call more than once
     f_mut(&mut f);
-    println!("main: {:?}", v);
+    info!("main: {:?}", v);
 
     // FnOnce
     let v = vec![0, 1, 2];
     // Force transfer of v's ownership
-    let f = move || println!("fn once: {:?}", v);
+    let f = move || info!("fn once: {:?}", v);
     f_once(f);
     // Cannot call more than once
     // f_once(f);
-    // println!("main: {:?}", v);
+    // info!("main: {:?}", v);
 }
@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 fn add(x: u32, y: u32) -> u32 {
     // Implicit return, no semicolon
     x + y
@@ -7,7 +9,7 @@ fn add(x: u32, y: u32) -> u32 {
 
 // No output
 fn print_name(name: String) {
-    println!("My name is {}", name);
+    info!("My name is {}", name);
 }
 
 // Diverge - never return
@@ -23,7 +25,7 @@ fn main() {
     let x: u32 = 1;
     let y: u32 = 2;
     let z: u32 = add(x, y);
-    println!("{} + {} = {}", x, y, z);
+    info!("{} + {} = {}", x, y, z);
 
     print_name("Rust".to_string());
 }
@@ -1,5 +1,6 @@
 #![allow(unused)]
 
+use hello_rust::info;
 use std::future::Future;
 use std::pin::Pin;
 use std::task::{Context, Poll};
@@ -7,14 +8,14 @@ use std::time::{Duration, Instant};
 
 // Hello
 async fn hello() {
-    println!("hello");
+    info!("hello");
 }
 
 // Return type of a async function is a compiler-generated anonymous type
 // A type that implements Future<Output = i32>
 fn hello_fut() -> impl Future<Output = ()> {
     async {
-        println!("hello");
+        info!("hello");
     }
 }
 
@@ -24,7 +25,7 @@ impl Future for Hello {
     type Output = ();
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
-        println!("hello");
+        info!("hello");
         Poll::Ready(())
     }
 }
@@ -66,7 +67,7 @@ impl Future for Sleep {
             // Forgetting to do this results in the task hanging indefinitely
 
             // This wakes the executor on every loop which wastes CPU cycles
-            // println!("pending");
+            // info!("pending");
             // cx.waker().wake_by_ref();
 
             let waker = cx.waker().clone();
@@ -91,11 +92,11 @@ async fn main() {
     // Compiler generated anonymous type
     let fut: _ = hello_fut();
     // Need to await for output
-    println!("hello_fut: {:?}", fut.await);
+    info!("hello_fut: {:?}", fut.await);
 
     let fut = hello_impl_fut();
-    println!("hello_impl_fut: {:?}", fut.await);
+    info!("hello_impl_fut: {:?}", fut.await);
 
     sleep_impl_fut(1000).await;
-    println!("⏰");
+    info!("⏰");
 }
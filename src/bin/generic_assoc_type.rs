@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 // Associated type
 // - placeholder type inside trait definition
 // - placeholder is replaced by the implementation
@@ -74,6 +76,6 @@ fn main() {
     };
 
     while let Some(v) = arr_iter.next() {
-        println!("{:?}", v);
+        info!("{:?}", v);
     }
 }
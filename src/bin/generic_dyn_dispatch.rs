@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 // Static and dynamic dispatch
 
 // Static dispatch
@@ -26,13 +28,13 @@ trait F {
 
 impl F for A {
     fn f(&self) {
-        println!("{:?}", self)
+        info!("{:?}", self)
     }
 }
 
 impl F for B {
     fn f(&self) {
-        println!("{:?}", self)
+        info!("{:?}", self)
     }
 }
 
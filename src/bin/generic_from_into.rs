@@ -1,5 +1,6 @@
 #![allow(unused)]
 
+use hello_rust::info;
 use std::convert::{From, Into};
 
 #[derive(Debug)]
@@ -43,6 +44,6 @@ fn main() {
     let p0 = Point::from((x, y));
     let p1: Point = x.into();
 
-    println!("p0 {:?}", p0);
-    println!("p1 {:?}", p1);
+    info!("p0 {:?}", p0);
+    info!("p1 {:?}", p1);
 }
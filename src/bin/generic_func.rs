@@ -1,5 +1,6 @@
 #![allow(unused)]
 
+use hello_rust::info;
 use std::cmp::PartialOrd;
 
 fn max<T: PartialOrd>(s: &[T]) -> Option<&T> {
@@ -26,13 +27,13 @@ fn swap<A, B>(t: (A, B)) -> (B, A) {
 fn main() {
     let nums = vec![33, 1, 22, 54, 25, 99, 10];
     let largest = max(&nums);
-    println!("largest num: {:?}", largest);
+    info!("largest num: {:?}", largest);
 
     let chars = vec!['a', 'c', 'y', 'i', 'm'];
     let largest = max(&chars);
-    println!("largest char: {:?}", largest);
+    info!("largest char: {:?}", largest);
 
     let t: (i32, bool) = (1, true);
     let s = swap(t);
-    println!("swapped: {:?}", s);
+    info!("swapped: {:?}", s);
 }
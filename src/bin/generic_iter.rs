@@ -1,5 +1,6 @@
 #![allow(unused)]
 
+use hello_rust::info;
 use std::collections::{HashMap, HashSet};
 
 /*
@@ -33,13 +34,13 @@ fn main() {
 
     let mut counter = Counter { count: 0 };
     while let Some(c) = counter.next() {
-        println!("count {}", c);
+        info!("count {}", c);
     }
 
     // Default implementation of into_iter is implicitly called
     let counter = Counter { count: 0 };
     for c in counter {
-        println!("count {}", c);
+        info!("count {}", c);
     }
 
     // into_iter
@@ -49,12 +50,12 @@ fn main() {
     let vals: Vec<i32> = vec![1, 2, 3];
     for v in vals {
         // v has type i32
-        println!("into_iter: {}", v);
+        info!("into_iter: {}", v);
     }
 
     /*
     for v in vals {
-        println!("{}", v);
+        info!("{}", v);
     }
     */
 
@@ -63,7 +64,7 @@ fn main() {
     map.insert(1, 'a');
     map.insert(2, 'b');
     for (k, v) in map {
-        println!("{}, {}", k, v);
+        info!("{}, {}", k, v);
     }
 
     // Convert vector into HashSet
@@ -75,10 +76,10 @@ fn main() {
     let vals: Vec<i32> = vec![1, 2, 3];
     for v in vals.iter() {
         // v has type &i32
-        println!("iter: {}", v);
+        info!("iter: {}", v);
     }
     for v in vals.iter() {
-        println!("iter: {}", v);
+        info!("iter: {}", v);
     }
 
     // iter_mut
@@ -86,16 +87,16 @@ fn main() {
     for v in vals.iter_mut() {
         *v += 10;
     }
-    println!("iter_mut: {:?}", vals);
+    info!("iter_mut: {:?}", vals);
 
     // For loop with reference
     let vals: Vec<i32> = vec![1, 2, 3];
     for v in &vals {
         // v has type &i32
-        println!("ref: {}", v);
+        info!("ref: {}", v);
     }
     for v in &vals {
-        println!("ref: {}", v);
+        info!("ref: {}", v);
     }
 
     // For loop with array
@@ -103,9 +104,9 @@ fn main() {
     let arr: [u32; 5] = [1, 2, 3, 4, 5];
     for a in arr {
         // a has type u32
-        println!("array: {}", a);
+        info!("array: {}", a);
     }
     for a in arr {
-        println!("array: {}", a);
+        info!("array: {}", a);
     }
 }
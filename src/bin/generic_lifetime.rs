@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 // Every reference has a lifetime
 
 // Both x and y live at least 'a
@@ -13,7 +15,7 @@ fn longest_str<'a>(x: &'a str, y: &'a str) -> &'a str {
 
 // Multiple lifetime
 fn print_refs<'a, 'b>(x: &'a str, y: &'b str) {
-    println!("{} {}", x, y);
+    info!("{} {}", x, y);
 }
 
 // Must return correct lifetime
@@ -24,7 +26,7 @@ fn f_out<'a, 'b>(x: &'a str, y: &'b str) -> &'a str {
 
 // Elision - Rust figures out the lifetime
 fn no_need_to_declare_lifetime(x: &str) {
-    println!("{}", x);
+    info!("{}", x);
 }
 
 // Struct example
@@ -47,13 +49,13 @@ fn main() {
         let y = "Rust".to_string();
         longest_str(&x, &y)
     };
-    println!("longest {:?}", z);
+    info!("longest {:?}", z);
     */
 
     // This compiles (z lives atleast as long as both x and y)
     let y = "Rust".to_string();
     let z = longest_str(&x, &y);
-    println!("longest {:?}", z);
+    info!("longest {:?}", z);
 
     // Static lifetime
     let s: &'static str = "Hello";
@@ -64,5 +66,5 @@ fn main() {
     // Book
     let mut book = Book { title: "Rust" };
     book.edit("Solidity");
-    println!("book: {:?}", book);
+    info!("book: {:?}", book);
 }
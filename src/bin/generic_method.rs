@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 #[derive(Debug)]
 struct Point<T> {
     x: T,
@@ -22,5 +24,5 @@ impl<T> Point<T> {
 fn main() {
     let mut p: Point<u32> = Point::new(1, 2);
     p.move_to(2, 3);
-    println!("{:?}", p);
+    info!("{:?}", p);
 }
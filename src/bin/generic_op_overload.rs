@@ -1,5 +1,6 @@
 #![allow(unused)]
 
+use hello_rust::info;
 use std::ops::Add;
 
 #[derive(Debug)]
@@ -34,5 +35,5 @@ fn main() {
     let p0: Point<f32> = Point { x: 1.0, y: 2.0 };
     let p1: Point<f32> = Point { x: 1.0, y: 3.0 };
     let p2 = p0 + p1;
-    println!("{:?}", p2);
+    info!("{:?}", p2);
 }
@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 // Generic trait
 trait List<T> {
     fn count(&self) -> usize;
@@ -41,10 +43,10 @@ impl<X, Y> List<(X, Y)> for [(X, Y); 2] {
 
 fn main() {
     let xy: (u32, u32) = (1, 2);
-    println!("count: {}", xy.count());
-    println!("first: {:?}", xy.first());
+    info!("count: {}", xy.count());
+    info!("first: {:?}", xy.first());
 
     let arr: [(u32, &str); 2] = [(1, "a"), (2, "b")];
-    println!("count: {}", arr.count());
-    println!("first: {:?}", arr.first());
+    info!("count: {}", arr.count());
+    info!("first: {:?}", arr.first());
 }
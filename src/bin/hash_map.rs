@@ -1,5 +1,6 @@
 #![allow(unused)]
 
+use hello_rust::info;
 use std::collections::HashMap;
 
 fn main() {
@@ -10,10 +11,10 @@ fn main() {
 
     // Get
     let score: Option<&u32> = scores.get("red");
-    println!("red: {:?}", score);
+    info!("red: {:?}", score);
 
     let score = scores.get("green");
-    println!("green: {:?}", score);
+    info!("green: {:?}", score);
 
     // Insert
     scores.insert("green".to_string(), 300);
@@ -23,7 +24,7 @@ fn main() {
     *score += 200;
 
     let score = scores.get("blue");
-    println!("blue: {:?}", score);
+    info!("blue: {:?}", score);
 
-    println!("scores: {:#?}", scores);
+    info!("scores: {:#?}", scores);
 }
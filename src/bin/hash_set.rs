@@ -1,20 +1,21 @@
 #![allow(unused)]
 
+use hello_rust::info;
 use std::collections::HashSet;
 
 fn main() {
     let mut set: HashSet<u32> = HashSet::new();
 
     let inserted: bool = set.insert(1);
-    println!("inserted: {inserted}");
+    info!("inserted: {inserted}");
     let inserted: bool = set.insert(1);
-    println!("inserted: {inserted}");
+    info!("inserted: {inserted}");
 
     set.insert(2);
     set.insert(3);
 
-    println!("1: {}", set.contains(&1));
-    println!("2: {}", set.contains(&2));
-    println!("3: {}", set.contains(&3));
-    println!("4: {}", set.contains(&4));
+    info!("1: {}", set.contains(&1));
+    info!("2: {}", set.contains(&2));
+    info!("3: {}", set.contains(&3));
+    info!("4: {}", set.contains(&4));
 }
@@ -1,12 +1,14 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 fn main() {
     let x: i32 = 10;
 
     if x % 2 == 0 {
-        println!("{x} is even");
+        info!("{x} is even");
     } else {
-        println!("{x} is odd");
+        info!("{x} is odd");
     }
 
     // Return from conditional statement
@@ -18,5 +20,5 @@ fn main() {
         0
     };
 
-    println!("{}", z);
+    info!("{}", z);
 }
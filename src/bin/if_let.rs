@@ -1,16 +1,18 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 fn main() {
     let x: Option<u32> = Some(1);
 
     match x {
-        Some(i) => println!("match {i}"),
+        Some(i) => info!("match {i}"),
         _ => {}
     }
 
     // if let
     if let Some(i) = x {
-        println!("if let {i}");
+        info!("if let {i}");
     }
 
     // let else
@@ -18,5 +20,5 @@ fn main() {
         // Must "diverge" - return or panic
         panic!("x is None");
     };
-    println!("let else {i}")
+    info!("let else {i}")
 }
@@ -0,0 +1,415 @@
+#![allow(unused)]
+
+// String interner
+// - Each distinct string is stored once, in an arena
+// - intern(&str) returns a Symbol, a Copy u32 handle
+//   comparing or hashing a Symbol is cheaper than a String
+// - resolve(Symbol) returns the &str again
+// - Interner for 1 thread, ShardedInterner shared with Arc between threads
+
+use hello_rust::info;
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::BuildHasher;
+use std::mem::size_of;
+use std::sync::{Arc, RwLock};
+use std::thread;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Symbol(u32);
+
+// Pre-seeded symbols, the index into STATIC_SYMBOLS
+impl Symbol {
+    const HELLO: Symbol = Symbol(0);
+    const WORLD: Symbol = Symbol(1);
+    const RUST: Symbol = Symbol(2);
+    const CRAB: Symbol = Symbol(3);
+}
+
+static STATIC_SYMBOLS: &[&str] = &["hello", "world", "rust", "🦀"];
+
+// Strings are copied into chunks that are never reallocated,
+// so a &str into a chunk stays valid until the arena is dropped
+struct Arena {
+    chunks: Vec<String>,
+}
+
+impl Arena {
+    const FIRST_CHUNK: usize = 1024;
+
+    fn new() -> Self {
+        Self { chunks: Vec::new() }
+    }
+
+    // Copies s into the arena
+    // SAFETY: the returned &str is not really 'static, it lives as long as the arena.
+    // The caller must not use it after self is dropped.
+    unsafe fn alloc(&mut self, s: &str) -> &'static str {
+        // No chunk may exist yet, and "" needs no bytes
+        if s.is_empty() {
+            return "";
+        }
+        let free = self.chunks.last().map_or(0, |c| c.capacity() - c.len());
+        if s.len() > free {
+            let size = self
+                .chunks
+                .last()
+                .map_or(Self::FIRST_CHUNK, |c| c.capacity() * 2)
+                .max(s.len());
+            self.chunks.push(String::with_capacity(size));
+        }
+        let chunk = self.chunks.last_mut().unwrap();
+        let start = chunk.len();
+        // Fits in the capacity, push_str does not reallocate
+        chunk.push_str(s);
+        let s: &str = &chunk[start..];
+        // SAFETY: the bytes of chunk are never moved or freed while the arena lives
+        unsafe { &*(s as *const str) }
+    }
+
+    fn capacity(&self) -> usize {
+        self.chunks.iter().map(|c| c.capacity()).sum()
+    }
+
+    fn used(&self) -> usize {
+        self.chunks.iter().map(|c| c.len()).sum()
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct MemoryUsage {
+    strings: usize,
+    // Bytes of string data in the arena
+    bytes: usize,
+    arena_capacity: usize,
+    // Hash map and Vec of the symbol tables, approximate
+    tables: usize,
+}
+
+impl MemoryUsage {
+    fn total(&self) -> usize {
+        self.arena_capacity + self.tables
+    }
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            strings: self.strings + other.strings,
+            bytes: self.bytes + other.bytes,
+            arena_capacity: self.arena_capacity + other.arena_capacity,
+            tables: self.tables + other.tables,
+        }
+    }
+}
+
+impl fmt::Display for MemoryUsage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} strings, {} bytes of text, arena {} bytes, tables {} bytes, total {} bytes",
+            self.strings,
+            self.bytes,
+            self.arena_capacity,
+            self.tables,
+            self.total()
+        )
+    }
+}
+
+// Approximate heap size of the symbol tables
+fn table_bytes<V>(map: &HashMap<&str, V>, strs: &Vec<&str>) -> usize {
+    // Each bucket of the hash map also has 1 control byte
+    map.capacity() * (size_of::<(&str, V)>() + 1) + strs.capacity() * size_of::<&str>()
+}
+
+struct Interner {
+    map: HashMap<&'static str, Symbol>,
+    // Symbol(i) -> strs[i]
+    strs: Vec<&'static str>,
+    arena: Arena,
+}
+
+impl Interner {
+    fn new() -> Self {
+        Self::with_static(&[])
+    }
+
+    // Symbol(i) = statics[i], these strings are not copied
+    fn with_static(statics: &'static [&'static str]) -> Self {
+        let mut interner = Self {
+            map: HashMap::new(),
+            strs: Vec::new(),
+            arena: Arena::new(),
+        };
+        for s in statics {
+            interner.insert(s);
+        }
+        assert_eq!(
+            interner.strs.len(),
+            statics.len(),
+            "duplicate static symbol"
+        );
+        interner
+    }
+
+    fn insert(&mut self, s: &'static str) -> Symbol {
+        let sym = Symbol(u32::try_from(self.strs.len()).expect("too many symbols"));
+        *self.map.entry(s).or_insert_with(|| {
+            self.strs.push(s);
+            sym
+        })
+    }
+
+    fn intern(&mut self, s: &str) -> Symbol {
+        if let Some(&sym) = self.map.get(s) {
+            return sym;
+        }
+        // SAFETY: s is only stored in self.map and self.strs, which are dropped with
+        // the arena, and resolve() ties the &str it returns to &self
+        let s = unsafe { self.arena.alloc(s) };
+        self.insert(s)
+    }
+
+    // Lookup without interning
+    fn get(&self, s: &str) -> Option<Symbol> {
+        self.map.get(s).copied()
+    }
+
+    // &str lives as long as the interner, not 'static
+    fn resolve(&self, sym: Symbol) -> Option<&str> {
+        self.strs.get(sym.0 as usize).copied()
+    }
+
+    fn len(&self) -> usize {
+        self.strs.len()
+    }
+
+    fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage {
+            strings: self.strs.len(),
+            bytes: self.strs.iter().map(|s| s.len()).sum(),
+            arena_capacity: self.arena.capacity(),
+            tables: table_bytes(&self.map, &self.strs),
+        }
+    }
+}
+
+// Concurrent interner
+// - Strings are split into shards by hash, each shard has its own lock,
+//   so threads interning different strings rarely wait for each other
+// - Static symbols are read without any lock
+// - Symbol = statics + local index * SHARDS + shard
+struct ShardedInterner {
+    statics: &'static [&'static str],
+    static_map: HashMap<&'static str, Symbol>,
+    shards: Vec<RwLock<Shard>>,
+    hasher: RandomState,
+}
+
+struct Shard {
+    map: HashMap<&'static str, u32>,
+    strs: Vec<&'static str>,
+    arena: Arena,
+}
+
+impl ShardedInterner {
+    const SHARDS: usize = 16;
+
+    fn new() -> Self {
+        Self::with_static(&[])
+    }
+
+    fn with_static(statics: &'static [&'static str]) -> Self {
+        let static_map: HashMap<_, _> = statics
+            .iter()
+            .enumerate()
+            .map(|(i, &s)| (s, Symbol(i as u32)))
+            .collect();
+        assert_eq!(static_map.len(), statics.len(), "duplicate static symbol");
+        Self {
+            statics,
+            static_map,
+            shards: (0..Self::SHARDS)
+                .map(|_| {
+                    RwLock::new(Shard {
+                        map: HashMap::new(),
+                        strs: Vec::new(),
+                        arena: Arena::new(),
+                    })
+                })
+                .collect(),
+            hasher: RandomState::new(),
+        }
+    }
+
+    fn shard_of(&self, s: &str) -> usize {
+        self.hasher.hash_one(s) as usize % Self::SHARDS
+    }
+
+    fn symbol(&self, shard: usize, local: u32) -> Symbol {
+        let i = self.statics.len() + local as usize * Self::SHARDS + shard;
+        Symbol(u32::try_from(i).expect("too many symbols"))
+    }
+
+    fn intern(&self, s: &str) -> Symbol {
+        if let Some(&sym) = self.static_map.get(s) {
+            return sym;
+        }
+        let i = self.shard_of(s);
+        // Most strings are already interned, a read lock is enough for them
+        if let Some(&local) = self.shards[i].read().unwrap().map.get(s) {
+            return self.symbol(i, local);
+        }
+        let mut shard = self.shards[i].write().unwrap();
+        // Another thread may have interned s between the 2 locks
+        if let Some(&local) = shard.map.get(s) {
+            return self.symbol(i, local);
+        }
+        let local = u32::try_from(shard.strs.len()).expect("too many symbols");
+        // SAFETY: s is only stored in this shard, next to the arena,
+        // and resolve() ties the &str it returns to &self
+        let s = unsafe { shard.arena.alloc(s) };
+        shard.map.insert(s, local);
+        shard.strs.push(s);
+        self.symbol(i, local)
+    }
+
+    fn get(&self, s: &str) -> Option<Symbol> {
+        if let Some(&sym) = self.static_map.get(s) {
+            return Some(sym);
+        }
+        let i = self.shard_of(s);
+        let local = *self.shards[i].read().unwrap().map.get(s)?;
+        Some(self.symbol(i, local))
+    }
+
+    fn resolve(&self, sym: Symbol) -> Option<&str> {
+        let i = sym.0 as usize;
+        if i < self.statics.len() {
+            return Some(self.statics[i]);
+        }
+        let i = i - self.statics.len();
+        let shard = self.shards[i % Self::SHARDS].read().unwrap();
+        // The arena of the shard lives as long as self,
+        // so the &str can outlive the lock guard
+        shard.strs.get(i / Self::SHARDS).copied()
+    }
+
+    fn len(&self) -> usize {
+        self.statics.len()
+            + self
+                .shards
+                .iter()
+                .map(|s| s.read().unwrap().strs.len())
+                .sum::<usize>()
+    }
+
+    fn memory_usage(&self) -> MemoryUsage {
+        let statics = MemoryUsage {
+            strings: self.statics.len(),
+            bytes: self.statics.iter().map(|s| s.len()).sum(),
+            arena_capacity: 0,
+            tables: self.static_map.capacity() * (size_of::<(&str, Symbol)>() + 1),
+        };
+        self.shards.iter().fold(statics, |total, shard| {
+            let shard = shard.read().unwrap();
+            total.add(MemoryUsage {
+                strings: shard.strs.len(),
+                bytes: shard.arena.used(),
+                arena_capacity: shard.arena.capacity(),
+                tables: table_bytes(&shard.map, &shard.strs),
+            })
+        })
+    }
+}
+
+const TEXT: &str = "hello world hello rust the quick brown fox jumps over the lazy dog \
+    hello 🦀 rust is fast rust is safe the dog sleeps the fox runs hello world";
+
+fn main() {
+    let mut interner = Interner::with_static(STATIC_SYMBOLS);
+    let words: Vec<Symbol> = TEXT
+        .split_whitespace()
+        .map(|w| interner.intern(w))
+        .collect();
+    info!("{} words, {} distinct", words.len(), interner.len());
+    info!("{:?}", &words[..6]);
+
+    // Symbols are Copy and compared as integers
+    let hello = interner.intern("hello");
+    assert_eq!(hello, Symbol::HELLO);
+    assert_eq!(interner.get("🦀"), Some(Symbol::CRAB));
+    // "" on an arena without chunks
+    let mut fresh = Interner::new();
+    let e = fresh.intern("");
+    assert_eq!(fresh.intern(""), e);
+    assert_eq!(fresh.resolve(e), Some(""));
+    let sharded = ShardedInterner::new();
+    let e = sharded.intern("");
+    assert_eq!(sharded.intern(""), e);
+    assert_eq!(sharded.resolve(e), Some(""));
+    info!(
+        "hello = {:?}, resolve = {:?}",
+        hello,
+        interner.resolve(hello)
+    );
+    info!("get(\"missing\") = {:?}", interner.get("missing"));
+    let text: Vec<&str> = words
+        .iter()
+        .map(|&s| interner.resolve(s).unwrap())
+        .collect();
+    assert_eq!(
+        text.join(" "),
+        TEXT.split_whitespace().collect::<Vec<_>>().join(" ")
+    );
+
+    // Memory - interned vs 1 String per word
+    info!("interner: {}", interner.memory_usage());
+    let strings: Vec<String> = TEXT.split_whitespace().map(|w| w.to_string()).collect();
+    let bytes = strings.capacity() * size_of::<String>()
+        + strings.iter().map(|s| s.capacity()).sum::<usize>();
+    info!("Vec<String>: {} strings, {} bytes", strings.len(), bytes);
+
+    let mut empty = Interner::new();
+    info!("empty interner, first symbol = {:?}", empty.intern("hello"));
+
+    // Shared between threads
+    let shared = Arc::new(ShardedInterner::with_static(STATIC_SYMBOLS));
+    let handles: Vec<_> = (0..4)
+        .map(|t| {
+            let shared = Arc::clone(&shared);
+            thread::spawn(move || {
+                // Each thread interns the words in a different order
+                let mut words: Vec<&str> = TEXT.split_whitespace().collect();
+                words.rotate_left(t * 5);
+                let mut pairs: Vec<(String, Symbol)> = words
+                    .iter()
+                    .map(|w| (w.to_string(), shared.intern(w)))
+                    .collect();
+                for i in 0..1000 {
+                    let w = format!("word{}", i % 100);
+                    pairs.push((w.clone(), shared.intern(&w)));
+                }
+                pairs
+            })
+        })
+        .collect();
+
+    let mut seen: HashMap<String, Symbol> = HashMap::new();
+    for h in handles {
+        for (w, sym) in h.join().unwrap() {
+            // Every thread got the same symbol for the same string
+            assert_eq!(*seen.entry(w.clone()).or_insert(sym), sym);
+            assert_eq!(shared.resolve(sym), Some(w.as_str()));
+        }
+    }
+    assert_eq!(shared.intern("rust"), Symbol::RUST);
+    assert_eq!(shared.get("word42"), seen.get("word42").copied());
+    info!(
+        "sharded: {} distinct, world = {:?}, word42 = {:?}",
+        shared.len(),
+        shared.get("world"),
+        shared.get("word42")
+    );
+    info!("sharded: {}", shared.memory_usage());
+}
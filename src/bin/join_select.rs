@@ -1,5 +1,6 @@
 #![allow(unused)]
 
+use hello_rust::info;
 use std::time::Duration;
 use tokio::{join, select};
 
@@ -24,23 +25,23 @@ async fn f(val: &'static str, dt: u64) -> &'static str {
 #[tokio::main]
 async fn main() {
     let (res1, res2, res3) = join!(f("future 1", 10), f("future 2", 20), f("future 3", 30));
-    println!("join: res1 = {:?}", res1);
-    println!("join: res2 = {:?}", res2);
-    println!("join: res3 = {:?}", res3);
+    info!("join: res1 = {:?}", res1);
+    info!("join: res2 = {:?}", res2);
+    info!("join: res3 = {:?}", res3);
 
     let res = select! {
         val = f("future 1", 1) => {
-            println!("future 1 finished first");
+            info!("future 1 finished first");
             val
         }
         val = f("future 2", 1) => {
-            println!("future 2 finished first");
+            info!("future 2 finished first");
             val
         }
         val = f("future 3", 1) => {
-            println!("future 3 finished first");
+            info!("future 3 finished first");
             val
         }
     };
-    println!("select: res = {:?}", res);
+    info!("select: res = {:?}", res);
 }
@@ -0,0 +1,707 @@
+#![allow(unused)]
+
+// JSON parser and serializer (RFC 8259) using only std
+// - Value enum
+// - Errors report line and column
+// - Compact and pretty output
+// - JSON Pointer lookup (RFC 6901)
+// - ToJson / FromJson traits
+
+use hello_rust::info;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    // Vec keeps the key order of the document
+    Object(Vec<(String, Value)>),
+}
+
+#[derive(Debug, PartialEq)]
+struct ParseError {
+    msg: String,
+    line: usize,
+    col: usize,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at line {} column {}", self.msg, self.line, self.col)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Arrays and objects nested deeper are an error instead of a stack overflow
+const MAX_DEPTH: usize = 128;
+
+struct Parser<'a> {
+    src: &'a str,
+    bytes: &'a [u8],
+    pos: usize,
+    // Arrays and objects currently open
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(src: &'a str) -> Self {
+        Self {
+            src,
+            bytes: src.as_bytes(),
+            pos: 0,
+            depth: 0,
+        }
+    }
+
+    // Line and column (in chars) of the byte offset, both start at 1
+    fn error_at(&self, pos: usize, msg: &str) -> ParseError {
+        let before = &self.src[..pos.min(self.src.len())];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let col = before[line_start..].chars().count() + 1;
+        ParseError {
+            msg: msg.to_string(),
+            line,
+            col,
+        }
+    }
+
+    fn error(&self, msg: &str) -> ParseError {
+        self.error_at(self.pos, msg)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    // Whitespace allowed by RFC 8259: space, tab, line feed, carriage return
+    fn skip_ws(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), ParseError> {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", byte as char)))
+        }
+    }
+
+    fn parse(mut self) -> Result<Value, ParseError> {
+        self.skip_ws();
+        let value = self.value()?;
+        self.skip_ws();
+        if self.pos < self.bytes.len() {
+            return Err(self.error("trailing characters"));
+        }
+        Ok(value)
+    }
+
+    fn value(&mut self) -> Result<Value, ParseError> {
+        match self.peek() {
+            None => Err(self.error("unexpected end of input")),
+            Some(b'n') => self.literal("null", Value::Null),
+            Some(b't') => self.literal("true", Value::Bool(true)),
+            Some(b'f') => self.literal("false", Value::Bool(false)),
+            Some(b'"') => Ok(Value::String(self.string()?)),
+            Some(b'[') => self.array(),
+            Some(b'{') => self.object(),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            Some(_) => Err(self.error("unexpected character")),
+        }
+    }
+
+    fn literal(&mut self, word: &str, value: Value) -> Result<Value, ParseError> {
+        if self.bytes[self.pos..].starts_with(word.as_bytes()) {
+            self.pos += word.len();
+            Ok(value)
+        } else {
+            Err(self.error(&format!("expected '{word}'")))
+        }
+    }
+
+    fn digits(&mut self) -> usize {
+        let start = self.pos;
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+
+    // number = [ minus ] int [ frac ] [ exp ]
+    fn number(&mut self) -> Result<Value, ParseError> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        match self.peek() {
+            Some(b'0') => {
+                self.pos += 1;
+                if let Some(b'0'..=b'9') = self.peek() {
+                    return Err(self.error("leading zeros are not allowed"));
+                }
+            }
+            Some(b'1'..=b'9') => {
+                self.digits();
+            }
+            _ => return Err(self.error("expected digit")),
+        }
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            if self.digits() == 0 {
+                return Err(self.error("expected digit after '.'"));
+            }
+        }
+        if let Some(b'e' | b'E') = self.peek() {
+            self.pos += 1;
+            if let Some(b'+' | b'-') = self.peek() {
+                self.pos += 1;
+            }
+            if self.digits() == 0 {
+                return Err(self.error("expected digit in exponent"));
+            }
+        }
+        let n: f64 = self.src[start..self.pos]
+            .parse()
+            .map_err(|_| self.error_at(start, "invalid number"))?;
+        if !n.is_finite() {
+            return Err(self.error_at(start, "number out of range"));
+        }
+        Ok(Value::Number(n))
+    }
+
+    fn hex4(&mut self) -> Result<u16, ParseError> {
+        let hex = self
+            .src
+            .get(self.pos..self.pos + 4)
+            .filter(|s| s.bytes().all(|b| b.is_ascii_hexdigit()))
+            .ok_or_else(|| self.error("expected 4 hex digits"))?;
+        self.pos += 4;
+        Ok(u16::from_str_radix(hex, 16).unwrap())
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        self.expect(b'"')?;
+        let mut s = String::new();
+        loop {
+            let start = self.pos;
+            // Copy runs of plain characters at once
+            while let Some(b) = self.peek() {
+                if b == b'"' || b == b'\\' || b < 0x20 {
+                    break;
+                }
+                self.pos += 1;
+            }
+            s.push_str(&self.src[start..self.pos]);
+
+            match self.peek() {
+                None => return Err(self.error("unterminated string")),
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(s);
+                }
+                Some(b'\\') => {
+                    let esc_pos = self.pos;
+                    self.pos += 1;
+                    let c = match self.peek() {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => {
+                            self.pos += 1;
+                            s.push(self.unicode_escape(esc_pos)?);
+                            continue;
+                        }
+                        _ => return Err(self.error("invalid escape")),
+                    };
+                    self.pos += 1;
+                    s.push(c);
+                }
+                Some(_) => return Err(self.error("control character in string")),
+            }
+        }
+    }
+
+    // \uXXXX, with UTF-16 surrogate pairs for chars outside the BMP
+    fn unicode_escape(&mut self, esc_pos: usize) -> Result<char, ParseError> {
+        let hi = self.hex4()?;
+        let code = match hi {
+            0xd800..=0xdbff => {
+                if !self.bytes[self.pos..].starts_with(b"\\u") {
+                    return Err(self.error_at(esc_pos, "unpaired high surrogate"));
+                }
+                self.pos += 2;
+                let lo = self.hex4()?;
+                if !(0xdc00..=0xdfff).contains(&lo) {
+                    return Err(self.error_at(esc_pos, "invalid low surrogate"));
+                }
+                0x10000 + ((hi as u32 - 0xd800) << 10) + (lo as u32 - 0xdc00)
+            }
+            0xdc00..=0xdfff => return Err(self.error_at(esc_pos, "unpaired low surrogate")),
+            _ => hi as u32,
+        };
+        Ok(char::from_u32(code).unwrap())
+    }
+
+    // Called at the opening bracket, the caller decrements depth when closed
+    fn enter(&mut self) -> Result<(), ParseError> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error(&format!("nesting deeper than {MAX_DEPTH}")));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn array(&mut self) -> Result<Value, ParseError> {
+        self.enter()?;
+        let items = self.array_items()?;
+        self.depth -= 1;
+        Ok(items)
+    }
+
+    fn array_items(&mut self) -> Result<Value, ParseError> {
+        self.expect(b'[')?;
+        let mut items = Vec::new();
+        self.skip_ws();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(Value::Array(items));
+        }
+        loop {
+            self.skip_ws();
+            items.push(self.value()?);
+            self.skip_ws();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(Value::Array(items));
+                }
+                _ => return Err(self.error("expected ',' or ']'")),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, ParseError> {
+        self.enter()?;
+        let fields = self.object_fields()?;
+        self.depth -= 1;
+        Ok(fields)
+    }
+
+    fn object_fields(&mut self) -> Result<Value, ParseError> {
+        self.expect(b'{')?;
+        let mut fields = Vec::new();
+        self.skip_ws();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(Value::Object(fields));
+        }
+        loop {
+            self.skip_ws();
+            if self.peek() != Some(b'"') {
+                return Err(self.error("expected string key"));
+            }
+            let key = self.string()?;
+            self.skip_ws();
+            self.expect(b':')?;
+            self.skip_ws();
+            let value = self.value()?;
+            fields.push((key, value));
+            self.skip_ws();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(Value::Object(fields));
+                }
+                _ => return Err(self.error("expected ',' or '}'")),
+            }
+        }
+    }
+}
+
+fn parse(src: &str) -> Result<Value, ParseError> {
+    Parser::new(src).parse()
+}
+
+fn write_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn write_number(out: &mut String, n: f64) {
+    // JSON has no NaN or infinity
+    if !n.is_finite() {
+        out.push_str("null");
+    } else if n.fract() == 0.0 && n.abs() < 1e15 {
+        out.push_str(&format!("{}", n as i64));
+    } else {
+        out.push_str(&format!("{n}"));
+    }
+}
+
+impl Value {
+    fn to_compact(&self) -> String {
+        let mut out = String::new();
+        self.write(&mut out, None, 0);
+        out
+    }
+
+    fn to_pretty(&self) -> String {
+        let mut out = String::new();
+        self.write(&mut out, Some(2), 0);
+        out
+    }
+
+    // indent = None for compact output
+    fn write(&self, out: &mut String, indent: Option<usize>, depth: usize) {
+        let newline = |out: &mut String, depth: usize| {
+            if let Some(n) = indent {
+                out.push('\n');
+                out.push_str(&" ".repeat(n * depth));
+            }
+        };
+        match self {
+            Value::Null => out.push_str("null"),
+            Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+            Value::Number(n) => write_number(out, *n),
+            Value::String(s) => write_string(out, s),
+            Value::Array(items) => {
+                out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    newline(out, depth + 1);
+                    item.write(out, indent, depth + 1);
+                }
+                if !items.is_empty() {
+                    newline(out, depth);
+                }
+                out.push(']');
+            }
+            Value::Object(fields) => {
+                out.push('{');
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    newline(out, depth + 1);
+                    write_string(out, key);
+                    out.push(':');
+                    if indent.is_some() {
+                        out.push(' ');
+                    }
+                    value.write(out, indent, depth + 1);
+                }
+                if !fields.is_empty() {
+                    newline(out, depth);
+                }
+                out.push('}');
+            }
+        }
+    }
+
+    fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    // JSON Pointer, for example "/b/c" or "/items/0"
+    // ~1 = '/' and ~0 = '~'
+    fn pointer(&self, pointer: &str) -> Option<&Value> {
+        if pointer.is_empty() {
+            return Some(self);
+        }
+        let rest = pointer.strip_prefix('/')?;
+        rest.split('/').try_fold(self, |value, token| {
+            let token = token.replace("~1", "/").replace("~0", "~");
+            match value {
+                Value::Object(_) => value.get(&token),
+                Value::Array(items) => {
+                    // No leading zeros or signs
+                    if token != "0" && token.starts_with('0') {
+                        return None;
+                    }
+                    items.get(token.parse::<usize>().ok()?)
+                }
+                _ => None,
+            }
+        })
+    }
+
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // {:#} for pretty output
+        if f.alternate() {
+            write!(f, "{}", self.to_pretty())
+        } else {
+            write!(f, "{}", self.to_compact())
+        }
+    }
+}
+
+trait ToJson {
+    fn to_json(&self) -> Value;
+}
+
+trait FromJson: Sized {
+    fn from_json(value: &Value) -> Result<Self, String>;
+}
+
+fn field<'a>(value: &'a Value, key: &str) -> Result<&'a Value, String> {
+    value
+        .get(key)
+        .ok_or_else(|| format!("missing field '{key}'"))
+}
+
+fn number(value: &Value, key: &str) -> Result<f64, String> {
+    field(value, key)?
+        .as_f64()
+        .ok_or_else(|| format!("field '{key}' is not a number"))
+}
+
+fn integer<T: TryFrom<i64>>(value: &Value, key: &str) -> Result<T, String> {
+    let n = number(value, key)?;
+    if n.fract() != 0.0 {
+        return Err(format!("field '{key}' is not an integer"));
+    }
+    T::try_from(n as i64).map_err(|_| format!("field '{key}' is out of range"))
+}
+
+// Types from struct.rs and enum.rs
+#[derive(Debug, PartialEq)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Debug, PartialEq)]
+struct Circle {
+    center: Point,
+    radius: u32,
+}
+
+#[derive(Debug, PartialEq)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+    Rgba(u8, u8, u8, f32),
+    Hex(String),
+    Hsl { h: u8, s: u8, l: u8 },
+}
+
+impl ToJson for Point {
+    fn to_json(&self) -> Value {
+        Value::Object(vec![
+            ("x".to_string(), Value::Number(self.x as f64)),
+            ("y".to_string(), Value::Number(self.y as f64)),
+        ])
+    }
+}
+
+impl FromJson for Point {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        Ok(Point {
+            x: number(value, "x")? as f32,
+            y: number(value, "y")? as f32,
+        })
+    }
+}
+
+impl ToJson for Circle {
+    fn to_json(&self) -> Value {
+        Value::Object(vec![
+            ("center".to_string(), self.center.to_json()),
+            ("radius".to_string(), Value::Number(self.radius as f64)),
+        ])
+    }
+}
+
+impl FromJson for Circle {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        Ok(Circle {
+            center: Point::from_json(field(value, "center")?)?,
+            radius: integer(value, "radius")?,
+        })
+    }
+}
+
+// Unit variants = "Red"
+// Other variants = { "Rgba": [r, g, b, a] }, { "Hex": "..." }, { "Hsl": { "h": .. } }
+impl ToJson for Color {
+    fn to_json(&self) -> Value {
+        let tagged = |tag: &str, value: Value| Value::Object(vec![(tag.to_string(), value)]);
+        let n = |v: u8| Value::Number(v as f64);
+        match self {
+            Color::Red => Value::String("Red".to_string()),
+            Color::Green => Value::String("Green".to_string()),
+            Color::Blue => Value::String("Blue".to_string()),
+            Color::Rgba(r, g, b, a) => tagged(
+                "Rgba",
+                Value::Array(vec![n(*r), n(*g), n(*b), Value::Number(*a as f64)]),
+            ),
+            Color::Hex(s) => tagged("Hex", Value::String(s.clone())),
+            Color::Hsl { h, s, l } => tagged(
+                "Hsl",
+                Value::Object(vec![
+                    ("h".to_string(), n(*h)),
+                    ("s".to_string(), n(*s)),
+                    ("l".to_string(), n(*l)),
+                ]),
+            ),
+        }
+    }
+}
+
+impl FromJson for Color {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        match value {
+            Value::String(s) => match s.as_str() {
+                "Red" => Ok(Color::Red),
+                "Green" => Ok(Color::Green),
+                "Blue" => Ok(Color::Blue),
+                _ => Err(format!("unknown color '{s}'")),
+            },
+            Value::Object(fields) if fields.len() == 1 => {
+                let (tag, inner) = &fields[0];
+                match tag.as_str() {
+                    "Rgba" => match inner {
+                        Value::Array(items) if items.len() == 4 => {
+                            let byte = |v: &Value| {
+                                v.as_f64()
+                                    .filter(|n| n.fract() == 0.0 && (0.0..=255.0).contains(n))
+                                    .map(|n| n as u8)
+                                    .ok_or("Rgba component is not a u8".to_string())
+                            };
+                            let a = items[3].as_f64().ok_or("Rgba alpha is not a number")?;
+                            Ok(Color::Rgba(
+                                byte(&items[0])?,
+                                byte(&items[1])?,
+                                byte(&items[2])?,
+                                a as f32,
+                            ))
+                        }
+                        _ => Err("Rgba expects an array of 4 numbers".to_string()),
+                    },
+                    "Hex" => inner
+                        .as_str()
+                        .map(|s| Color::Hex(s.to_string()))
+                        .ok_or("Hex expects a string".to_string()),
+                    "Hsl" => Ok(Color::Hsl {
+                        h: integer(inner, "h")?,
+                        s: integer(inner, "s")?,
+                        l: integer(inner, "l")?,
+                    }),
+                    _ => Err(format!("unknown color '{tag}'")),
+                }
+            }
+            _ => Err("expected a color".to_string()),
+        }
+    }
+}
+
+fn main() {
+    // Raw JSON from string.rs
+    let s: &str = r#"
+        { "a": 1,
+          "b": { "c": 2 },
+          "d": 3
+        }
+    "#;
+    let value = parse(s).unwrap();
+    info!("{value}");
+    info!("{value:#}");
+    info!("/b/c = {:?}", value.pointer("/b/c"));
+    info!("/x = {:?}", value.pointer("/x"));
+
+    // Escapes, including a surrogate pair for 🦀
+    let value = parse(r#"["Hello\nRust \ud83e\udd80", "\u00e9", -1.5e3]"#).unwrap();
+    info!("{value}");
+
+    // Errors report line and column
+    for bad in [
+        "{\n  \"a\": 1,\n  \"b\": tru\n}",
+        "[1, 2,]",
+        "01",
+        r#""\ud83e""#,
+        "{\"a\" 1}",
+    ] {
+        info!("{:?} -> {}", bad, parse(bad).unwrap_err());
+    }
+
+    // Nesting is limited, deep input is an error, not a stack overflow
+    let deep = "[".repeat(MAX_DEPTH) + &"]".repeat(MAX_DEPTH);
+    assert!(parse(&deep).is_ok());
+    let deep = format!("[{deep}]");
+    let err = parse(&deep).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "nesting deeper than 128 at line 1 column 129"
+    );
+    let err = parse(&"[{\"a\":".repeat(100_000)).unwrap_err();
+    assert_eq!((err.line, err.col), (1, 385));
+
+    // ToJson and FromJson
+    let circle = Circle {
+        center: Point { x: 1.0, y: 2.5 },
+        radius: 3,
+    };
+    let json = circle.to_json().to_compact();
+    info!("{json}");
+    let back = Circle::from_json(&parse(&json).unwrap()).unwrap();
+    info!("{:?} == {:?} ? {}", circle, back, circle == back);
+
+    for color in [
+        Color::Red,
+        Color::Rgba(100, 200, 0, 0.5),
+        Color::Hex(String::from("ffffff")),
+        Color::Hsl { h: 1, s: 2, l: 0 },
+    ] {
+        let json = color.to_json().to_compact();
+        let back = Color::from_json(&parse(&json).unwrap()).unwrap();
+        info!("{json} -> {back:?}");
+    }
+}
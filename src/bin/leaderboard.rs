@@ -0,0 +1,350 @@
+#![allow(unused)]
+
+// Leaderboard
+// - scores: HashMap<String, u32> from hash_map.rs, name -> score
+// - Order statistics tree (treap with subtree sizes), (score, name) in rank order
+// - O(log n) update_score, rank_of, top_n, around, range_by_score
+// - Ties: same score is ranked by name, a before b
+// - Concurrent wrapper with Arc<RwLock>
+
+use hello_rust::info;
+use std::cmp::{Ordering, Reverse};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::thread;
+
+// Sorts by score descending, then name ascending
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Key {
+    score: Reverse<u32>,
+    name: String,
+}
+
+type Tree = Option<Box<Node>>;
+
+#[derive(Debug)]
+struct Node {
+    key: Key,
+    // Heap order on priority keeps the tree balanced (expected O(log n) depth)
+    priority: u64,
+    // Number of nodes in this subtree
+    size: usize,
+    left: Tree,
+    right: Tree,
+}
+
+fn size(t: &Tree) -> usize {
+    t.as_ref().map_or(0, |n| n.size)
+}
+
+fn height(t: &Tree) -> usize {
+    t.as_ref()
+        .map_or(0, |n| 1 + height(&n.left).max(height(&n.right)))
+}
+
+fn update(node: &mut Node) {
+    node.size = 1 + size(&node.left) + size(&node.right);
+}
+
+// Split into (keys where go_left is true, the rest)
+// go_left must be true for a prefix of the keys in order
+fn split(t: Tree, go_left: &impl Fn(&Key) -> bool) -> (Tree, Tree) {
+    match t {
+        None => (None, None),
+        Some(mut node) => {
+            if go_left(&node.key) {
+                let (l, r) = split(node.right.take(), go_left);
+                node.right = l;
+                update(&mut node);
+                (Some(node), r)
+            } else {
+                let (l, r) = split(node.left.take(), go_left);
+                node.left = r;
+                update(&mut node);
+                (l, Some(node))
+            }
+        }
+    }
+}
+
+// All keys in a must be less than all keys in b
+fn merge(a: Tree, b: Tree) -> Tree {
+    match (a, b) {
+        (None, t) | (t, None) => t,
+        (Some(mut a), Some(mut b)) => {
+            if a.priority > b.priority {
+                a.right = merge(a.right.take(), Some(b));
+                update(&mut a);
+                Some(a)
+            } else {
+                b.left = merge(Some(a), b.left.take());
+                update(&mut b);
+                Some(b)
+            }
+        }
+    }
+}
+
+// Number of keys less than key
+fn count_less(t: &Tree, key: &Key) -> usize {
+    let mut t = t;
+    let mut count = 0;
+    while let Some(node) = t {
+        match key.cmp(&node.key) {
+            Ordering::Greater => {
+                count += size(&node.left) + 1;
+                t = &node.right;
+            }
+            _ => t = &node.left,
+        }
+    }
+    count
+}
+
+// Keys with index in start..end, in order
+fn collect(t: &Tree, start: usize, end: usize, out: &mut Vec<Key>) {
+    let Some(node) = t else {
+        return;
+    };
+    if start >= end {
+        return;
+    }
+    let left = size(&node.left);
+    if start < left {
+        collect(&node.left, start, end.min(left), out);
+    }
+    if start <= left && left < end {
+        out.push(node.key.clone());
+    }
+    if end > left + 1 {
+        collect(
+            &node.right,
+            start.saturating_sub(left + 1),
+            end - left - 1,
+            out,
+        );
+    }
+}
+
+#[derive(Debug)]
+struct Leaderboard {
+    scores: HashMap<String, u32>,
+    tree: Tree,
+    // xorshift state for priorities, never 0 (xorshift stays at 0)
+    seed: u64,
+}
+
+// Not derived, a derived seed of 0 would give every node priority 0
+impl Default for Leaderboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Leaderboard {
+    fn new() -> Self {
+        Self {
+            scores: HashMap::new(),
+            tree: None,
+            seed: 0x9e37_79b9_7f4a_7c15,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.scores.len()
+    }
+
+    fn next_priority(&mut self) -> u64 {
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+        self.seed
+    }
+
+    fn key(name: &str, score: u32) -> Key {
+        Key {
+            score: Reverse(score),
+            name: name.to_string(),
+        }
+    }
+
+    fn insert_key(&mut self, key: Key) {
+        let node = Box::new(Node {
+            key,
+            priority: self.next_priority(),
+            size: 1,
+            left: None,
+            right: None,
+        });
+        let (l, r) = split(self.tree.take(), &|k| *k < node.key);
+        self.tree = merge(merge(l, Some(node)), r);
+    }
+
+    fn remove_key(&mut self, key: &Key) {
+        let (l, r) = split(self.tree.take(), &|k| k < key);
+        let (_, r) = split(r, &|k| k == key);
+        self.tree = merge(l, r);
+    }
+
+    // Insert or update, returns the old score
+    fn update_score(&mut self, name: &str, score: u32) -> Option<u32> {
+        let old = self.scores.insert(name.to_string(), score);
+        if let Some(old) = old {
+            self.remove_key(&Self::key(name, old));
+        }
+        self.insert_key(Self::key(name, score));
+        old
+    }
+
+    // Add to the current score, starting from 0
+    fn add_score(&mut self, name: &str, delta: u32) -> u32 {
+        let score = self.score(name).unwrap_or(0).saturating_add(delta);
+        self.update_score(name, score);
+        score
+    }
+
+    fn remove(&mut self, name: &str) -> Option<u32> {
+        let score = self.scores.remove(name)?;
+        self.remove_key(&Self::key(name, score));
+        Some(score)
+    }
+
+    fn score(&self, name: &str) -> Option<u32> {
+        self.scores.get(name).copied()
+    }
+
+    // 1 = top
+    fn rank_of(&self, name: &str) -> Option<usize> {
+        let score = self.score(name)?;
+        Some(count_less(&self.tree, &Self::key(name, score)) + 1)
+    }
+
+    // Entries with rank in start..=end, both 1 based
+    fn by_rank(&self, start: usize, end: usize) -> Vec<(usize, String, u32)> {
+        let mut keys = Vec::new();
+        let start = start.max(1);
+        collect(&self.tree, start - 1, end.min(self.len()), &mut keys);
+        keys.into_iter()
+            .enumerate()
+            .map(|(i, k)| (start + i, k.name, k.score.0))
+            .collect()
+    }
+
+    fn top_n(&self, n: usize) -> Vec<(usize, String, u32)> {
+        self.by_rank(1, n)
+    }
+
+    // k players above and k players below
+    fn around(&self, name: &str, k: usize) -> Vec<(usize, String, u32)> {
+        match self.rank_of(name) {
+            Some(rank) => self.by_rank(rank.saturating_sub(k), rank + k),
+            None => Vec::new(),
+        }
+    }
+
+    // Players with min <= score <= max, highest first
+    fn range_by_score(&self, min: u32, max: u32) -> Vec<(usize, String, u32)> {
+        if min > max {
+            return Vec::new();
+        }
+        // First key with score <= max is (max, "")
+        let start = count_less(&self.tree, &Self::key("", max));
+        // Number of keys with score >= min
+        let end = match min.checked_sub(1) {
+            Some(below) => count_less(&self.tree, &Self::key("", below)),
+            None => self.len(),
+        };
+        self.by_rank(start + 1, end)
+    }
+}
+
+// Many readers or one writer at a time
+#[derive(Clone)]
+struct SharedLeaderboard {
+    inner: Arc<RwLock<Leaderboard>>,
+}
+
+impl Default for SharedLeaderboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SharedLeaderboard {
+    fn new() -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(Leaderboard::new())),
+        }
+    }
+
+    fn update_score(&self, name: &str, score: u32) -> Option<u32> {
+        self.inner.write().unwrap().update_score(name, score)
+    }
+
+    // Read and write under one lock, so concurrent adds are not lost
+    fn add_score(&self, name: &str, delta: u32) -> u32 {
+        self.inner.write().unwrap().add_score(name, delta)
+    }
+
+    fn rank_of(&self, name: &str) -> Option<usize> {
+        self.inner.read().unwrap().rank_of(name)
+    }
+
+    fn top_n(&self, n: usize) -> Vec<(usize, String, u32)> {
+        self.inner.read().unwrap().top_n(n)
+    }
+}
+
+fn main() {
+    let mut board = Leaderboard::new();
+
+    board.update_score("red", 100);
+    board.update_score("blue", 200);
+    board.update_score("green", 300);
+    board.update_score("yellow", 200);
+    board.add_score("blue", 200);
+
+    info!("top 3: {:?}", board.top_n(3));
+    info!("rank of blue: {:?}", board.rank_of("blue"));
+    // Tie at 200 - blue was updated to 400, so only yellow has 200
+    info!("rank of yellow: {:?}", board.rank_of("yellow"));
+    info!("rank of pink: {:?}", board.rank_of("pink"));
+
+    for i in 0..20 {
+        board.update_score(&format!("player{i:02}"), i * 10);
+    }
+    info!("around player10: {:?}", board.around("player10", 2));
+    info!("100..=150: {:?}", board.range_by_score(100, 150));
+
+    // Same score, ranked by name
+    board.update_score("aaa", 300);
+    info!("top 3: {:?}", board.top_n(3));
+
+    // Sorted inserts stay balanced, a list would be 10000 deep
+    let mut big = Leaderboard::default();
+    for i in 0..10_000 {
+        big.update_score(&format!("p{i:05}"), i);
+    }
+    let h = height(&big.tree);
+    info!("10000 players, tree height = {h}");
+    assert!(h < 50);
+    assert_eq!(big.rank_of("p09999"), Some(1));
+
+    // Concurrent updates
+    let shared = SharedLeaderboard::default();
+    let handles: Vec<_> = (0..4)
+        .map(|t| {
+            let shared = shared.clone();
+            thread::spawn(move || {
+                for i in 0..100 {
+                    shared.add_score(&format!("team{}", i % 5), t + 1);
+                }
+            })
+        })
+        .collect();
+    for h in handles {
+        h.join().unwrap();
+    }
+    // Each team gets 20 * (1 + 2 + 3 + 4) = 200
+    info!("teams: {:?}", shared.top_n(5));
+}
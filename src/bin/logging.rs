@@ -0,0 +1,164 @@
+#![allow(unused)]
+
+// Leveled logging with hello_rust::log (src/log.rs)
+// cargo run --bin logging
+// HELLO_LOG=debug cargo run --bin logging
+// HELLO_LOG=logging::worker=trace,warn HELLO_LOG_FORMAT=text cargo run --bin logging
+// HELLO_LOG_FORMAT=json cargo run --bin logging
+
+use hello_rust::log::{Filter, JsonSink, Level, Record, Sink, TextSink};
+use hello_rust::{debug, error, info, trace, warn};
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+// Shared buffer to read back what a sink wrote
+#[derive(Clone, Default)]
+struct Buf(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Buf {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Buf {
+    fn take(&self) -> String {
+        String::from_utf8(std::mem::take(&mut *self.0.lock().unwrap())).unwrap()
+    }
+}
+
+mod worker {
+    use hello_rust::{debug, trace};
+
+    // Target is logging::worker
+    pub fn work(id: u32) -> u32 {
+        trace!("start"; id = id);
+        let result = id * id;
+        debug!("done in {}ms", id * 10; id = id, result = result);
+        result
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    info!("hello {}", "🦀");
+    warn!("disk almost full"; used = "93%");
+    error!("failed to connect"; host = "localhost", port = 8080);
+    // Hidden unless HELLO_LOG enables debug or trace
+    debug!("debug message");
+    trace!("trace message");
+
+    // Threads
+    thread::scope(|s| {
+        for id in 1..=3 {
+            s.spawn(move || worker::work(id));
+        }
+    });
+
+    // Tokio tasks
+    let handles: Vec<_> = (1..=3)
+        .map(|id| {
+            tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(10 * id)).await;
+                info!("task {id} finished"; task = id);
+            })
+        })
+        .collect();
+    for h in handles {
+        h.await.unwrap();
+    }
+
+    // Filters
+    let f = Filter::parse("channel=debug,channel::io=off,warn").unwrap();
+    assert!(f.enabled(Level::Debug, "channel"));
+    assert!(f.enabled(Level::Debug, "channel::worker"));
+    assert!(!f.enabled(Level::Error, "channel::io"));
+    assert!(!f.enabled(Level::Info, "thread"));
+    assert!(f.enabled(Level::Warn, "thread"));
+    // channel_2 is a different target, not a child of channel
+    assert!(!f.enabled(Level::Debug, "channel_2"));
+    assert!(Filter::parse("channel=loud").is_err());
+    // Longest matching target wins, whatever the order of the directives
+    for spec in [
+        "a=warn,a::b=trace,a::b::c=error",
+        "a::b::c=error,a::b=trace,a=warn",
+    ] {
+        let f = Filter::parse(spec).unwrap();
+        assert!(f.enabled(Level::Warn, "a::x"));
+        assert!(!f.enabled(Level::Info, "a::x"));
+        assert!(f.enabled(Level::Trace, "a::b::x"));
+        assert!(f.enabled(Level::Error, "a::b::c::d"));
+        assert!(!f.enabled(Level::Warn, "a::b::c::d"));
+        // Default for other targets
+        assert!(f.enabled(Level::Info, "b"));
+    }
+    // off
+    for spec in ["off", "OFF", "info,off"] {
+        let f = Filter::parse(spec).unwrap();
+        assert!(!f.enabled(Level::Error, "logging"));
+    }
+    let f = Filter::parse("off,channel=info").unwrap();
+    assert!(f.enabled(Level::Info, "channel"));
+    assert!(!f.enabled(Level::Error, "thread"));
+    // A bare target enables every level for it, others keep the default
+    let f = Filter::parse("channel").unwrap();
+    assert_eq!(f, Filter::default().target("channel", Some(Level::Trace)));
+    assert!(f.enabled(Level::Trace, "channel::worker"));
+    assert!(!f.enabled(Level::Debug, "thread"));
+    // Empty directives are ignored
+    assert_eq!(Filter::parse("").unwrap(), Filter::default());
+    assert_eq!(
+        Filter::parse(" warn , ,").unwrap(),
+        Filter::new(Some(Level::Warn))
+    );
+    info!("filters ok");
+
+    // Exact sink output, JSON strings are escaped
+    let buf = Buf::default();
+    let text = TextSink::new(buf.clone());
+    let json = JsonSink::new(buf.clone());
+    let fields: &[(&str, &dyn std::fmt::Display)] = &[("path", &"C:\\tmp"), ("n", &-1)];
+    let record = Record {
+        level: Level::Warn,
+        target: "logging",
+        args: format_args!("say \"hi\"\n\tbye\u{1} 🦀"),
+        fields,
+    };
+    let thread = thread::current().name().unwrap().to_string();
+    text.write(&record);
+    assert_eq!(
+        buf.take(),
+        "WARN  logging: say \"hi\"\n\tbye\u{1} 🦀 path=C:\\tmp n=-1\n"
+    );
+    json.write(&record);
+    assert_eq!(
+        buf.take(),
+        format!(
+            "{{\"level\":\"WARN\",\"target\":\"logging\",\"thread\":\"{thread}\",\
+             \"msg\":\"say \\\"hi\\\"\\n\\tbye\\u0001 🦀\",\
+             \"path\":\"C:\\\\tmp\",\"n\":\"-1\"}}\n"
+        )
+    );
+    info!("sinks ok");
+
+    // Sinks can also be used directly
+    let fields: &[(&str, &dyn std::fmt::Display)] = &[("id", &1), ("name", &"a \"quoted\" name")];
+    for sink in [
+        Box::new(TextSink::new(io::stdout())) as Box<dyn Sink>,
+        Box::new(JsonSink::new(io::stdout())),
+    ] {
+        sink.write(&Record {
+            level: Level::Info,
+            target: "logging",
+            args: format_args!("sink {}", "demo"),
+            fields,
+        });
+    }
+}
@@ -1,10 +1,12 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 fn main() {
     // Loop
     let mut i: u32 = 0;
     loop {
-        println!("loop {}", i);
+        info!("loop {}", i);
         i += 1;
 
         if i > 5 {
@@ -15,13 +17,13 @@ fn main() {
     // While loop
     let mut i: u32 = 0;
     while i <= 5 {
-        println!("while loop {}", i);
+        info!("while loop {}", i);
         i += 1;
     }
 
     // For loop
     for i in 0..5 {
-        println!("for loop {}", i);
+        info!("for loop {}", i);
     }
 
     // Loop array
@@ -33,12 +35,12 @@ fn main() {
         // This will not compile
         // i is usize
         // let k = i + 1u32;
-        println!("{}", xs[i]);
+        info!("{}", xs[i]);
     }
 
     // Loop using iterator
     for x in xs.iter() {
-        println!("for loop iter {}", x);
+        info!("for loop iter {}", x);
     }
 
     // Return value from loop
@@ -49,13 +51,13 @@ fn main() {
             break "i > 3";
         }
     };
-    println!("return value from loop {}", v);
+    info!("return value from loop {}", v);
 
     // Labels
     let mut i = 0;
     'outer: for i in 0..3 {
         'inner: for j in 0..3 {
-            println!("{i}, {j}");
+            info!("{i}, {j}");
             if i == 1 && j == 1 {
                 break 'outer;
             }
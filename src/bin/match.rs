@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 enum Animal {
     Cat,
     Dog,
@@ -11,28 +13,28 @@ fn main() {
     // Match
     let x = 1;
     match x {
-        1 => println!("one"),
-        2 => println!("two"),
-        3 => println!("three"),
-        _ => println!("other"),
+        1 => info!("one"),
+        2 => info!("two"),
+        3 => info!("three"),
+        _ => info!("other"),
     }
 
     // Multiple
     match x {
-        1 | 2 | 3 => println!("1 or 2 or 3"),
-        _ => println!("other"),
+        1 | 2 | 3 => info!("1 or 2 or 3"),
+        _ => info!("other"),
     }
 
     // Range
     match x {
-        1..=10 => println!("between 1 to 10"),
-        _ => println!("other"),
+        1..=10 => info!("between 1 to 10"),
+        _ => info!("other"),
     }
 
     // @
     match x {
-        i @ 1..=10 => println!("@ {i}"),
-        _ => println!("other"),
+        i @ 1..=10 => info!("@ {i}"),
+        _ => info!("other"),
     }
 
     // Assigning value from match
@@ -43,20 +45,20 @@ fn main() {
         Animal::Duck => "quack",
         _ => "?",
     };
-    println!("{}", animal_sound);
+    info!("{}", animal_sound);
 
     // Option
     let x: Option<u32> = Some(3);
     match x {
-        Some(x) => println!("{x}"),
-        None => println!("none"),
+        Some(x) => info!("{x}"),
+        None => info!("none"),
     }
 
     // Result
     let res: Result<u32, String> = Ok(1);
     match res {
-        Ok(i) => println!("ok {i}"),
+        Ok(i) => info!("ok {i}"),
         // Ignore error message
-        Err(_) => println!("err"),
+        Err(_) => info!("err"),
     }
 }
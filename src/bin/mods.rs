@@ -2,24 +2,24 @@
 
 mod foo {
     pub fn print() {
-        println!("foo");
+        hello_rust::info!("foo");
     }
 }
 
 mod my {
     pub fn print() {
-        println!("my");
+        hello_rust::info!("my");
     }
 
     // Private - cannot be called by main
     fn f() {
-        println!("private");
+        hello_rust::info!("private");
     }
 
     // Nest modules
     pub mod a {
         pub fn print() {
-            println!("a");
+            hello_rust::info!("a");
         }
 
         // Public struct
@@ -38,7 +38,7 @@ mod my {
     // Cannot be called outside of this module
     mod b {
         pub fn print() {
-            println!("b");
+            hello_rust::info!("b");
         }
     }
 
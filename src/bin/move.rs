@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 fn main() {
     // Closures can capture variables by
     // - Borrow immutable reference &T
@@ -8,23 +10,23 @@ fn main() {
 
     // Borrow immutable reference
     let s = "hello".to_string();
-    let f = || println!("borrow: {}", s);
+    let f = || info!("borrow: {}", s);
 
     f();
-    println!("main: {}", s);
+    info!("main: {}", s);
 
     // Borrow mutable
     let mut s = "hello".to_string();
     let mut f = || s += " world";
 
     f();
-    println!("main: mut {}", s);
+    info!("main: mut {}", s);
 
     // Taking ownership
     // Using `move` forces closure to take ownership of captured variables
     let s = "hello".to_string();
     let f = move || {
-        println!("move: {}", s);
+        info!("move: {}", s);
         // Force drop on s
         std::mem::drop(s);
     };
@@ -32,5 +34,5 @@ fn main() {
     // Cannot call twice - ownership moved into f
     f();
     // Cannot call print - ownership moved into f
-    // println!("main {}", s);
+    // info!("main {}", s);
 }
@@ -1,5 +1,6 @@
 #![allow(unused)]
 
+use hello_rust::info;
 use std::sync::{Mutex, MutexGuard};
 use std::thread;
 use std::time::Duration;
@@ -13,7 +14,7 @@ fn main() {
 
     {
         let mut val: MutexGuard<'_, i32> = m.lock().unwrap();
-        println!("{:?}", m);
+        info!("{:?}", m);
 
         // Trying to acquire the second lock will block this thread
         // let mut val = m.lock().unwrap();
@@ -25,7 +26,7 @@ fn main() {
         *val += 1;
         // mutex guard is dropped
     }
-    println!("{:?}", m);
+    info!("{:?}", m);
 
     // Example of Mutex with scoped threads
     thread::scope(|scope| {
@@ -39,5 +40,5 @@ fn main() {
         });
     });
 
-    println!("{:?}", m);
+    info!("{:?}", m);
 }
@@ -0,0 +1,591 @@
+#![allow(unused)]
+
+// What Vec does under the hood (vec.rs, ownership.rs)
+// - Stack: pointer, capacity and length
+// - Heap: elements, allocated with std::alloc
+// - RawVec owns the allocation, MyVec owns the elements
+// - Zero sized types never allocate
+// - Panic safe: a panic in Drop, Clone or an iterator never drops an element twice
+// - Check for undefined behavior with Miri, main runs every check
+//   rustup +nightly component add miri
+//   cargo +nightly miri run --bin my_vec
+
+use hello_rust::info;
+use std::alloc::{self, Layout};
+use std::cell::{Cell, RefCell};
+use std::collections::HashSet;
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::{Bound, Deref, DerefMut, RangeBounds};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr::{self, NonNull};
+use std::rc::Rc;
+
+struct RawVec<T> {
+    ptr: NonNull<T>,
+    cap: usize,
+}
+
+impl<T> RawVec<T> {
+    const IS_ZST: bool = mem::size_of::<T>() == 0;
+
+    fn new() -> Self {
+        Self {
+            // Never dereferenced while cap = 0
+            ptr: NonNull::dangling(),
+            // Zero sized types never need to allocate
+            cap: if Self::IS_ZST { usize::MAX } else { 0 },
+        }
+    }
+
+    // Double the capacity, start with 4
+    fn grow(&mut self) {
+        self.grow_to(self.cap + 1);
+    }
+
+    // At least min_cap, at least double
+    fn grow_to(&mut self, min_cap: usize) {
+        // cap is usize::MAX for zero sized types, so this means overflow
+        assert!(!Self::IS_ZST, "capacity overflow");
+        let new_cap = min_cap.max(self.cap * 2).max(4);
+        let new_layout = Layout::array::<T>(new_cap).expect("capacity overflow");
+        assert!(
+            new_layout.size() <= isize::MAX as usize,
+            "allocation too large"
+        );
+
+        let new_ptr = if self.cap == 0 {
+            unsafe { alloc::alloc(new_layout) }
+        } else {
+            let old_layout = Layout::array::<T>(self.cap).unwrap();
+            unsafe { alloc::realloc(self.ptr.as_ptr() as *mut u8, old_layout, new_layout.size()) }
+        };
+        self.ptr = match NonNull::new(new_ptr as *mut T) {
+            Some(p) => p,
+            None => alloc::handle_alloc_error(new_layout),
+        };
+        self.cap = new_cap;
+    }
+}
+
+impl<T> Drop for RawVec<T> {
+    // Frees memory only, elements are dropped by MyVec
+    fn drop(&mut self) {
+        if self.cap != 0 && !Self::IS_ZST {
+            unsafe {
+                alloc::dealloc(
+                    self.ptr.as_ptr() as *mut u8,
+                    Layout::array::<T>(self.cap).unwrap(),
+                );
+            }
+        }
+    }
+}
+
+struct MyVec<T> {
+    buf: RawVec<T>,
+    len: usize,
+    // MyVec owns values of T (for the drop checker)
+    _marker: PhantomData<T>,
+}
+
+// Same rules as Vec
+unsafe impl<T: Send> Send for MyVec<T> {}
+unsafe impl<T: Sync> Sync for MyVec<T> {}
+
+impl<T> MyVec<T> {
+    fn new() -> Self {
+        Self {
+            buf: RawVec::new(),
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    fn ptr(&self) -> *mut T {
+        self.buf.ptr.as_ptr()
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn capacity(&self) -> usize {
+        self.buf.cap
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        let needed = self.len.checked_add(additional).expect("capacity overflow");
+        if needed > self.capacity() {
+            self.buf.grow_to(needed);
+        }
+    }
+
+    // len is increased after each clone, if a clone panics the vec keeps
+    // the elements cloned so far
+    fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.reserve(other.len());
+        for v in other {
+            unsafe { ptr::write(self.ptr().add(self.len), v.clone()) };
+            self.len += 1;
+        }
+    }
+
+    fn push(&mut self, value: T) {
+        if self.len == self.capacity() {
+            self.buf.grow();
+        }
+        unsafe { ptr::write(self.ptr().add(self.len), value) };
+        self.len += 1;
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(unsafe { ptr::read(self.ptr().add(self.len)) })
+    }
+
+    // Shift elements after index to the right
+    fn insert(&mut self, index: usize, value: T) {
+        assert!(index <= self.len, "index out of bounds");
+        if self.len == self.capacity() {
+            self.buf.grow();
+        }
+        unsafe {
+            let p = self.ptr().add(index);
+            ptr::copy(p, p.add(1), self.len - index);
+            ptr::write(p, value);
+        }
+        self.len += 1;
+    }
+
+    // Shift elements after index to the left
+    fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len, "index out of bounds");
+        unsafe {
+            self.len -= 1;
+            let p = self.ptr().add(index);
+            let value = ptr::read(p);
+            ptr::copy(p.add(1), p, self.len - index);
+            value
+        }
+    }
+
+    // O(1), last element is moved into index
+    fn swap_remove(&mut self, index: usize) -> T {
+        assert!(index < self.len, "index out of bounds");
+        unsafe {
+            self.len -= 1;
+            let p = self.ptr().add(index);
+            let value = ptr::read(p);
+            ptr::copy(self.ptr().add(self.len), p, 1);
+            value
+        }
+    }
+
+    fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        let tail = ptr::slice_from_raw_parts_mut(unsafe { self.ptr().add(len) }, self.len - len);
+        // Set len first, if a drop panics the remaining elements are leaked, not dropped twice
+        self.len = len;
+        unsafe { ptr::drop_in_place(tail) };
+    }
+
+    fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    // Remove the range, the removed elements are returned by the iterator
+    fn drain(&mut self, range: impl RangeBounds<usize>) -> Drain<'_, T> {
+        let start = match range.start_bound() {
+            Bound::Included(&i) => i,
+            Bound::Excluded(&i) => i + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&i) => i + 1,
+            Bound::Excluded(&i) => i,
+            Bound::Unbounded => self.len,
+        };
+        assert!(start <= end && end <= self.len, "drain range out of bounds");
+        let tail_len = self.len - end;
+        // If Drain is leaked (mem::forget) the vec only keeps 0..start
+        self.len = start;
+        Drain {
+            vec: self,
+            start,
+            next: start,
+            end,
+            tail_len,
+        }
+    }
+}
+
+impl<T> Drop for MyVec<T> {
+    fn drop(&mut self) {
+        // Drop the elements, RawVec frees the memory
+        self.clear();
+    }
+}
+
+impl<T> Default for MyVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Deref for MyVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.ptr(), self.len) }
+    }
+}
+
+impl<T> DerefMut for MyVec<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        unsafe { std::slice::from_raw_parts_mut(self.ptr(), self.len) }
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for MyVec<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone> Clone for MyVec<T> {
+    fn clone(&self) -> Self {
+        let mut out = MyVec::new();
+        for v in self.iter() {
+            out.push(v.clone());
+        }
+        out
+    }
+}
+
+// push keeps len right, if the iterator panics the vec keeps what it got
+impl<T> Extend<T> for MyVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for v in iter {
+            self.push(v);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for MyVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut out = MyVec::new();
+        for v in iter {
+            out.push(v);
+        }
+        out
+    }
+}
+
+struct Drain<'a, T> {
+    vec: &'a mut MyVec<T>,
+    // Drained range
+    start: usize,
+    // Next element to return
+    next: usize,
+    end: usize,
+    // Elements after the range
+    tail_len: usize,
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.next == self.end {
+            return None;
+        }
+        let value = unsafe { ptr::read(self.vec.ptr().add(self.next)) };
+        self.next += 1;
+        Some(value)
+    }
+}
+
+impl<T> Drop for Drain<'_, T> {
+    fn drop(&mut self) {
+        // Move the tail back even if dropping an element panics
+        struct MoveTail<'r, 'a, T>(&'r mut Drain<'a, T>);
+
+        impl<T> Drop for MoveTail<'_, '_, T> {
+            fn drop(&mut self) {
+                let d = &mut *self.0;
+                unsafe {
+                    let base = d.vec.ptr();
+                    ptr::copy(base.add(d.end), base.add(d.start), d.tail_len);
+                }
+                d.vec.len = d.start + d.tail_len;
+            }
+        }
+
+        let guard = MoveTail(self);
+        // Drop the elements that were not taken, as a slice so that the
+        // others are still dropped if one of them panics
+        let d = &mut *guard.0;
+        let rest =
+            ptr::slice_from_raw_parts_mut(unsafe { d.vec.ptr().add(d.next) }, d.end - d.next);
+        d.next = d.end;
+        unsafe { ptr::drop_in_place(rest) };
+    }
+}
+
+struct IntoIter<T> {
+    // Keeps the allocation alive, freed on drop
+    buf: RawVec<T>,
+    start: usize,
+    end: usize,
+}
+
+impl<T> IntoIterator for MyVec<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        let vec = mem::ManuallyDrop::new(self);
+        // Move the RawVec out, MyVec::drop must not run
+        let buf = unsafe { ptr::read(&vec.buf) };
+        IntoIter {
+            buf,
+            start: 0,
+            end: vec.len,
+        }
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.start == self.end {
+            return None;
+        }
+        let value = unsafe { ptr::read(self.buf.ptr.as_ptr().add(self.start)) };
+        self.start += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.end - self.start;
+        (n, Some(n))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.start == self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(unsafe { ptr::read(self.buf.ptr.as_ptr().add(self.end)) })
+    }
+}
+
+impl<T> Drop for IntoIter<T> {
+    fn drop(&mut self) {
+        // Drop the remaining elements
+        for _ in self.by_ref() {}
+    }
+}
+
+// Ids of the Tracked values alive, like Tracked in drop_tracer.rs
+#[derive(Default)]
+struct Tracker {
+    next_id: Cell<u32>,
+    live: RefCell<HashSet<u32>>,
+}
+
+impl Tracker {
+    fn new_id(&self) -> u32 {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        self.live.borrow_mut().insert(id);
+        id
+    }
+
+    fn item(self: &Rc<Self>, panic_on_drop: bool, panic_on_clone: bool) -> Tracked {
+        Tracked {
+            id: self.new_id(),
+            tracker: Rc::clone(self),
+            panic_on_drop,
+            panic_on_clone,
+        }
+    }
+
+    fn live(&self) -> usize {
+        self.live.borrow().len()
+    }
+}
+
+struct Tracked {
+    id: u32,
+    tracker: Rc<Tracker>,
+    panic_on_drop: bool,
+    panic_on_clone: bool,
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        let was_live = self.tracker.live.borrow_mut().remove(&self.id);
+        assert!(was_live, "{} dropped twice", self.id);
+        if self.panic_on_drop {
+            panic!("drop {} panicked", self.id);
+        }
+    }
+}
+
+impl Clone for Tracked {
+    fn clone(&self) -> Self {
+        if self.panic_on_clone {
+            panic!("clone {} panicked", self.id);
+        }
+        self.tracker.item(self.panic_on_drop, false)
+    }
+}
+
+fn panics(f: impl FnOnce()) -> bool {
+    panic::catch_unwind(AssertUnwindSafe(f)).is_err()
+}
+
+// Every value is dropped exactly once, also when Drop, Clone or an iterator panics
+fn check_drops() {
+    let t = Rc::new(Tracker::default());
+    let item = || t.item(false, false);
+    {
+        let mut v: MyVec<Tracked> = (0..10).map(|_| item()).collect();
+        v.remove(0);
+        v.swap_remove(0);
+        v.truncate(7);
+        // Drop a partially consumed Drain
+        let mut d = v.drain(1..4);
+        d.next();
+        drop(d);
+        assert_eq!(v.len(), 4);
+        let mut it = v.into_iter();
+        it.next();
+        // Remaining elements dropped with the iterator
+    }
+    assert_eq!(t.live(), 0);
+
+    panic::set_hook(Box::new(|_| {}));
+
+    // truncate: the elements after the panicking one are still dropped
+    let mut v: MyVec<Tracked> = (0..6).map(|i| t.item(i == 2, false)).collect();
+    assert!(panics(|| v.truncate(1)));
+    assert_eq!((v.len(), t.live()), (1, 1));
+    drop(v);
+    assert_eq!(t.live(), 0);
+
+    // Dropping the whole vec
+    let v: MyVec<Tracked> = (0..4).map(|i| t.item(i == 0, false)).collect();
+    assert!(panics(|| drop(v)));
+    assert_eq!(t.live(), 0);
+
+    // drain: the tail is moved back and the other drained elements dropped
+    let mut v: MyVec<Tracked> = (0..8).map(|i| t.item(i == 3, false)).collect();
+    let ids: Vec<u32> = v.iter().map(|x| x.id).collect();
+    assert!(panics(|| {
+        let mut d = v.drain(2..6);
+        d.next();
+    }));
+    let left: Vec<u32> = v.iter().map(|x| x.id).collect();
+    assert_eq!(left, [ids[0], ids[1], ids[6], ids[7]]);
+    assert_eq!(t.live(), 4);
+    v.clear();
+    assert_eq!(t.live(), 0);
+
+    // extend_from_slice: clone of the 3rd element panics, 2 clones are kept
+    let src: Vec<Tracked> = (0..5).map(|i| t.item(false, i == 2)).collect();
+    let mut v: MyVec<Tracked> = MyVec::new();
+    v.push(item());
+    assert!(panics(|| v.extend_from_slice(&src)));
+    assert_eq!((v.len(), t.live()), (3, 5 + 3));
+    drop(v);
+    drop(src);
+    assert_eq!(t.live(), 0);
+
+    // extend: the iterator panics after 3 items
+    let mut v: MyVec<Tracked> = MyVec::new();
+    assert!(panics(|| {
+        v.extend((0..10).map(|i| {
+            assert!(i < 3, "iterator panicked");
+            item()
+        }))
+    }));
+    assert_eq!((v.len(), t.live()), (3, 3));
+    drop(v);
+
+    // clone: a panicking Clone leaves no half built vec behind
+    let v: MyVec<Tracked> = (0..4).map(|i| t.item(false, i == 2)).collect();
+    assert!(panics(|| drop(v.clone())));
+    assert_eq!(t.live(), 4);
+    drop(v);
+    assert_eq!(t.live(), 0);
+
+    let _ = panic::take_hook();
+}
+
+fn main() {
+    let mut v: MyVec<i32> = MyVec::new();
+    let mut line = Vec::new();
+    for i in 0..10 {
+        v.push(i);
+        line.push(format!("(len {}, cap {})", v.len(), v.capacity()));
+    }
+    info!("{}", line.join(" "));
+
+    v.insert(0, -1);
+    let x = v.remove(5);
+    let y = v.swap_remove(0);
+    info!("removed {x}, swap removed {y}: {:?}", v);
+    info!("pop = {:?}", v.pop());
+
+    // Deref to slice - all slice methods work
+    v.sort();
+    info!("sorted {:?}, sum = {}", v, v.iter().sum::<i32>());
+
+    let drained: Vec<i32> = v.drain(2..5).collect();
+    info!("drained {:?}, left {:?}", drained, v);
+
+    v.truncate(2);
+    info!("truncate {:?}", v);
+
+    let strings: MyVec<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+    let back: Vec<String> = strings.into_iter().rev().collect();
+    info!("{:?}", back);
+
+    // Zero sized type - no allocation
+    let mut units: MyVec<()> = MyVec::new();
+    for _ in 0..1000 {
+        units.push(());
+    }
+    info!("ZST len = {}, cap = {}", units.len(), units.capacity());
+
+    let mut v: MyVec<i32> = MyVec::new();
+    v.reserve(10);
+    let cap = v.capacity();
+    v.extend(0..10);
+    v.extend_from_slice(&[10, 11]);
+    assert_eq!(cap, 10);
+    assert_eq!(&v[..], (0..12).collect::<Vec<_>>().as_slice());
+
+    check_drops();
+    info!("drop checks passed");
+}
@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 // Memory
 // Stack
 // - Stores data of fixed size at compile time
@@ -16,12 +18,12 @@
 // 3. When the owner goes out of scope, the value will be dropped
 
 fn take(s: String) {
-    println!("take {s}");
+    info!("take {s}");
     // s is dropped
 }
 
 fn copy(v: i32) {
-    println!("copy {v}");
+    info!("copy {v}");
     // v is dropped here
 }
 
@@ -39,8 +41,8 @@ fn main() {
     // Owner of s is s2
     let s2 = s1;
     // This will not compile
-    // println!("{s}");
-    println!("{s2}");
+    // info!("{s}");
+    info!("{s2}");
 
     // Ownnership doesn't move for types that implement the Copy trait.
     // Values are copied, separate owners for i, i1 an i2
@@ -50,7 +52,7 @@ fn main() {
     let i1 = i;
     // Owner of i2 is i2
     let i2 = i1;
-    println!("i = {i}, i1 = {i1}, i2 = {i2}");
+    info!("i = {i}, i1 = {i1}, i2 = {i2}");
 
     // 3. When the owner goes out of scope, the value will be dropped
     let s = String::from("cat");
@@ -58,26 +60,26 @@ fn main() {
         s;
     }
     // This will not compile
-    // println!("{s}");
+    // info!("{s}");
 
     let s = String::from("cat");
     {
         // Owner of s is s1
         let s1 = s;
-        println!("{s1}");
+        info!("{s1}");
         // s1 is dropped
     }
     // This will not compile
-    // println!("{s}");
+    // info!("{s}");
 
     let s = String::from("cat");
     take(s);
     // This will not compile
-    // println!("{s}");
+    // info!("{s}");
 
     let i = 1;
     // i is copied as function input
     copy(i);
     // i is not dropped so this compiles
-    println!("i = {i}");
+    info!("i = {i}");
 }
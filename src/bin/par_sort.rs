@@ -0,0 +1,359 @@
+#![allow(unused)]
+
+// Parallel divide and conquer with scoped threads (scoped_thread.rs)
+// - split_at_mut gives each thread its own half of the slice
+// - thread::scope lets the threads borrow the slice, no Arc or 'static needed
+// - Stop splitting at a cutoff size or when the thread budget is used up
+// - Panics in a worker are re-raised on the calling thread
+// - No Clone bound: merge moves elements, and if the comparator panics
+//   the slice still holds every element exactly once
+//
+// The benchmark only runs when a size is given
+// cargo run --release --bin par_sort -- 10000000
+
+use hello_rust::info;
+use std::cmp::Ordering;
+use std::env;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::sync::atomic::{self, AtomicUsize};
+use std::thread;
+use std::time::Instant;
+
+#[derive(Debug, Clone, Copy)]
+struct Config {
+    // Max number of threads working at the same time, including the caller
+    threads: usize,
+    // Slices shorter than this are processed sequentially
+    cutoff: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            threads: thread::available_parallelism().map_or(1, |n| n.get()),
+            cutoff: 1 << 14,
+        }
+    }
+}
+
+// Run left on a new scoped thread and right on this thread
+// Re-raises a panic from the left side with its original payload
+fn join<A: Send, B>(left: impl FnOnce() -> A + Send, right: impl FnOnce() -> B) -> (A, B) {
+    thread::scope(|s| {
+        let h = s.spawn(left);
+        let b = right();
+        match h.join() {
+            Ok(a) => (a, b),
+            Err(payload) => panic::resume_unwind(payload),
+        }
+    })
+}
+
+// Left elements not merged yet, buf[start..end].
+// On drop (merge done or the comparator panicked) they are moved to dest,
+// the gap between the merged elements and the rest of the right half.
+struct MergeHole<T> {
+    start: *mut T,
+    end: *mut T,
+    dest: *mut T,
+}
+
+impl<T> Drop for MergeHole<T> {
+    fn drop(&mut self) {
+        unsafe {
+            let n = self.end.offset_from(self.start) as usize;
+            ptr::copy_nonoverlapping(self.start, self.dest, n);
+        }
+    }
+}
+
+// Stable merge of sorted v[..mid] and v[mid..]
+// The left half is moved into a buffer, then merged back into v
+fn merge<T, F>(v: &mut [T], mid: usize, cmp: &F)
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    // Zero sized elements are all the same, and offset_from panics on them
+    if size_of::<T>() == 0 {
+        return;
+    }
+    let len = v.len();
+    // Only used for its memory, len stays 0 so it never drops an element
+    let mut buf: Vec<T> = Vec::with_capacity(mid);
+    let v = v.as_mut_ptr();
+    unsafe {
+        ptr::copy_nonoverlapping(v, buf.as_mut_ptr(), mid);
+        let mut hole = MergeHole {
+            start: buf.as_mut_ptr(),
+            end: buf.as_mut_ptr().add(mid),
+            dest: v,
+        };
+        let (mut right, right_end) = (v.add(mid), v.add(len));
+        // dest + (end - start) == right, the hole is exactly the left elements left
+        while hole.start < hole.end && right < right_end {
+            // Take from the right only if strictly less, so equal elements keep their order
+            if cmp(&*right, &*hole.start) == Ordering::Less {
+                ptr::copy_nonoverlapping(right, hole.dest, 1);
+                right = right.add(1);
+            } else {
+                ptr::copy_nonoverlapping(hole.start, hole.dest, 1);
+                hole.start = hole.start.add(1);
+            }
+            hole.dest = hole.dest.add(1);
+        }
+        // Dropping the hole moves the remaining left elements,
+        // remaining right elements are already in place
+    }
+}
+
+fn par_sort_by_with<T, F>(v: &mut [T], cmp: &F, config: Config)
+where
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    // Every order of zero sized elements is sorted
+    if size_of::<T>() == 0 {
+        return;
+    }
+    if config.threads <= 1 || v.len() <= config.cutoff {
+        v.sort_by(cmp);
+        return;
+    }
+    let mid = v.len() / 2;
+    let (left, right) = v.split_at_mut(mid);
+    let left_threads = config.threads / 2;
+    let left_config = Config {
+        threads: left_threads,
+        ..config
+    };
+    let right_config = Config {
+        threads: config.threads - left_threads,
+        ..config
+    };
+    join(
+        || par_sort_by_with(left, cmp, left_config),
+        || par_sort_by_with(right, cmp, right_config),
+    );
+    merge(v, mid, cmp);
+}
+
+// Same result as slice::sort_by (stable)
+fn par_sort_by<T, F>(v: &mut [T], cmp: F)
+where
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    par_sort_by_with(v, &cmp, Config::default());
+}
+
+fn par_sort<T: Ord + Send>(v: &mut [T]) {
+    par_sort_by(v, T::cmp);
+}
+
+// fold each chunk sequentially, then combine the results
+// Same result as the sequential fold when combine is associative
+// and identity is its identity element
+fn par_reduce_with<T, R>(
+    v: &[T],
+    identity: &R,
+    fold: &(impl Fn(R, &T) -> R + Sync),
+    combine: &(impl Fn(R, R) -> R + Sync),
+    config: Config,
+) -> R
+where
+    T: Sync,
+    R: Send + Sync + Clone,
+{
+    if config.threads <= 1 || v.len() <= config.cutoff {
+        return v.iter().fold(identity.clone(), fold);
+    }
+    let (left, right) = v.split_at(v.len() / 2);
+    let left_threads = config.threads / 2;
+    let (a, b) = join(
+        || {
+            let config = Config {
+                threads: left_threads,
+                ..config
+            };
+            par_reduce_with(left, identity, fold, combine, config)
+        },
+        || {
+            let config = Config {
+                threads: config.threads - left_threads,
+                ..config
+            };
+            par_reduce_with(right, identity, fold, combine, config)
+        },
+    );
+    combine(a, b)
+}
+
+fn par_reduce<T, R>(
+    v: &[T],
+    identity: R,
+    fold: impl Fn(R, &T) -> R + Sync,
+    combine: impl Fn(R, R) -> R + Sync,
+) -> R
+where
+    T: Sync,
+    R: Send + Sync + Clone,
+{
+    par_reduce_with(v, &identity, &fold, &combine, Config::default())
+}
+
+// xorshift64 for test data
+fn random_vec(n: usize, mut seed: u64) -> Vec<u64> {
+    (0..n)
+        .map(|_| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        })
+        .collect()
+}
+
+fn main() {
+    let mut v = vec![5, 3, 8, 1, 9, 2, 7];
+    par_sort(&mut v);
+    info!("{:?}", v);
+
+    // Same result as the sequential stable sort, for any thread budget
+    let data: Vec<(u64, usize)> = random_vec(100_000, 1)
+        .into_iter()
+        .enumerate()
+        .map(|(i, x)| (x % 100, i))
+        .collect();
+    let mut expected = data.clone();
+    expected.sort_by_key(|e| e.0);
+    for threads in [1, 2, 3, 8] {
+        let mut v = data.clone();
+        let config = Config {
+            threads,
+            cutoff: 1000,
+        };
+        par_sort_by_with(
+            &mut v,
+            &|a: &(u64, usize), b: &(u64, usize)| a.0.cmp(&b.0),
+            config,
+        );
+        assert_eq!(v, expected, "threads = {threads}");
+    }
+    info!("par_sort_by matches sort_by");
+
+    // Zero sized elements, nothing to move
+    let mut units = vec![(); 100_000];
+    par_sort(&mut units);
+    par_sort_by_with(
+        &mut units,
+        &|a, b| a.cmp(b),
+        Config {
+            threads: 4,
+            cutoff: 1000,
+        },
+    );
+    assert_eq!(units.len(), 100_000);
+
+    let nums: Vec<u64> = (1..=1_000_000).collect();
+    let sum = par_reduce(&nums, 0u64, |acc, x| acc + x, |a, b| a + b);
+    let max = par_reduce(&nums, 0u64, |acc, &x| acc.max(x), u64::max);
+    info!("sum = {sum}, max = {max}");
+    assert_eq!(sum, nums.iter().sum::<u64>());
+
+    // Panics are propagated to the caller
+    panic::set_hook(Box::new(|_| {}));
+    let res = panic::catch_unwind(|| {
+        // Only the left half contains the bad value, so the panic happens on a spawned thread
+        let mut v: Vec<u64> = (0..100_000).rev().collect();
+        v[0] = u64::MAX;
+        par_sort_by_with(
+            &mut v,
+            &|a: &u64, b: &u64| {
+                if *a == u64::MAX || *b == u64::MAX {
+                    panic!("comparator panicked");
+                }
+                a.cmp(b)
+            },
+            Config {
+                threads: 4,
+                cutoff: 1000,
+            },
+        );
+    });
+    let msg = res
+        .unwrap_err()
+        .downcast_ref::<&str>()
+        .copied()
+        .unwrap_or("?");
+    info!("panic propagated: {msg:?}");
+
+    // A panic at any point, also during a merge, keeps every element exactly once.
+    // Strings so that a duplicated element would be a double free.
+    let config = Config {
+        threads: 4,
+        cutoff: 64,
+    };
+    let data: Vec<String> = random_vec(2_000, 5)
+        .iter()
+        .map(|x| (x % 500).to_string())
+        .collect();
+    let mut sorted = data.clone();
+    sorted.sort();
+    let calls = AtomicUsize::new(0);
+    let counting = |a: &String, b: &String| {
+        calls.fetch_add(1, atomic::Ordering::Relaxed);
+        a.cmp(b)
+    };
+    par_sort_by_with(&mut data.clone(), &counting, config);
+    let total = calls.load(atomic::Ordering::Relaxed);
+    // The last comparisons are in the final merge
+    for limit in [1, total / 2, total - 100, total - 1] {
+        let calls = AtomicUsize::new(0);
+        let mut v = data.clone();
+        let res = panic::catch_unwind(AssertUnwindSafe(|| {
+            par_sort_by_with(
+                &mut v,
+                &|a: &String, b: &String| {
+                    if calls.fetch_add(1, atomic::Ordering::Relaxed) + 1 == limit {
+                        panic!("comparator panicked");
+                    }
+                    a.cmp(b)
+                },
+                config,
+            )
+        }));
+        assert!(res.is_err());
+        v.sort();
+        assert_eq!(v, sorted, "panic after {limit} comparisons");
+    }
+    let _ = panic::take_hook();
+    info!("comparator panics keep every element, {total} comparisons");
+
+    // Benchmark, 10^7 elements take about 25s in a debug build
+    let Some(n) = env::args().nth(1) else {
+        info!("benchmark: cargo run --release --bin par_sort -- 10000000");
+        return;
+    };
+    let n: usize = n.parse().expect("number of elements");
+    let data = random_vec(n, 3);
+    let mut seq = data.clone();
+    let start = Instant::now();
+    seq.sort();
+    let seq_time = start.elapsed();
+
+    let config = Config::default();
+    let mut par = data.clone();
+    let start = Instant::now();
+    par_sort_by_with(&mut par, &u64::cmp, config);
+    let par_time = start.elapsed();
+    assert_eq!(seq, par);
+
+    info!("n = {n}, threads = {}", config.threads);
+    info!("sequential {:?}", seq_time);
+    info!("parallel   {:?}", par_time);
+    info!(
+        "speed up   {:.2}x",
+        seq_time.as_secs_f64() / par_time.as_secs_f64()
+    );
+}
@@ -0,0 +1,300 @@
+#![allow(unused)]
+
+// Object pool
+// - Pool<T> hands out Pooled<T> guards
+// - Pooled<T> returns the object to the pool on Drop
+// - Max size, reset hook
+// - Blocking, timed and non blocking checkout
+// - Shared across threads with Arc<Mutex> (arc.rs)
+// - A panic in create or reset gives the slot back (scope_guard.rs)
+
+use hello_rust::info;
+use std::ops::{Deref, DerefMut};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+
+struct State<T> {
+    idle: Vec<T>,
+    // Number of objects created, idle + checked out
+    created: usize,
+}
+
+struct Inner<T> {
+    state: Mutex<State<T>>,
+    // Signaled when an object is returned
+    available: Condvar,
+    max_size: usize,
+    create: Box<dyn Fn() -> T + Send + Sync>,
+    reset: Box<dyn Fn(&mut T) + Send + Sync>,
+}
+
+impl<T> Inner<T> {
+    // Ignore poisoning, the pool state is always consistent
+    fn lock(&self) -> MutexGuard<'_, State<T>> {
+        match self.state.lock() {
+            Ok(state) => state,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    // The object is gone, a new one can be created
+    fn release_slot(&self) {
+        self.lock().created -= 1;
+        self.available.notify_one();
+    }
+}
+
+// Releases a counted slot if create or reset unwinds
+struct SlotGuard<'a, T> {
+    pool: &'a Inner<T>,
+}
+
+impl<T> SlotGuard<'_, T> {
+    fn dismiss(self) {
+        std::mem::forget(self);
+    }
+}
+
+impl<T> Drop for SlotGuard<'_, T> {
+    fn drop(&mut self) {
+        self.pool.release_slot();
+    }
+}
+
+// Cheap to clone, all clones share the same objects
+struct Pool<T> {
+    inner: Arc<Inner<T>>,
+}
+
+impl<T> Clone for Pool<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+struct Pooled<T> {
+    // None only while returning to the pool
+    value: Option<T>,
+    pool: Arc<Inner<T>>,
+}
+
+impl<T> Pool<T> {
+    // Objects are created lazily with create, up to max_size
+    fn new(max_size: usize, create: impl Fn() -> T + Send + Sync + 'static) -> Self {
+        assert!(max_size > 0, "max_size must be > 0");
+        Self {
+            inner: Arc::new(Inner {
+                state: Mutex::new(State {
+                    idle: Vec::new(),
+                    created: 0,
+                }),
+                available: Condvar::new(),
+                max_size,
+                create: Box::new(create),
+                reset: Box::new(|_| {}),
+            }),
+        }
+    }
+
+    // Called on every object before it goes back into the pool
+    // Must be set before the pool is shared
+    fn with_reset(mut self, reset: impl Fn(&mut T) + Send + Sync + 'static) -> Self {
+        Arc::get_mut(&mut self.inner)
+            .expect("with_reset must be called before the pool is cloned")
+            .reset = Box::new(reset);
+        self
+    }
+
+    // Blocks until an object is available
+    fn get(&self) -> Pooled<T> {
+        self.checkout(None).unwrap()
+    }
+
+    // None if no object became available in time
+    fn get_timeout(&self, timeout: Duration) -> Option<Pooled<T>> {
+        self.checkout(Some(Instant::now() + timeout))
+    }
+
+    // Doesn't block
+    fn try_get(&self) -> Option<Pooled<T>> {
+        self.checkout(Some(Instant::now()))
+    }
+
+    fn checkout(&self, deadline: Option<Instant>) -> Option<Pooled<T>> {
+        let mut state = self.inner.lock();
+        loop {
+            if let Some(value) = state.idle.pop() {
+                return Some(self.wrap(value));
+            }
+            if state.created < self.inner.max_size {
+                state.created += 1;
+                // Don't hold the lock while creating
+                drop(state);
+                let guard = SlotGuard { pool: &self.inner };
+                let value = (self.inner.create)();
+                guard.dismiss();
+                return Some(self.wrap(value));
+            }
+            state = match deadline {
+                None => self.inner.available.wait(state).unwrap(),
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return None;
+                    }
+                    self.inner
+                        .available
+                        .wait_timeout(state, deadline - now)
+                        .unwrap()
+                        .0
+                }
+            };
+        }
+    }
+
+    fn wrap(&self, value: T) -> Pooled<T> {
+        Pooled {
+            value: Some(value),
+            pool: Arc::clone(&self.inner),
+        }
+    }
+
+    fn idle(&self) -> usize {
+        self.inner.lock().idle.len()
+    }
+
+    fn created(&self) -> usize {
+        self.inner.lock().created
+    }
+}
+
+impl<T> Pooled<T> {
+    // Take the object out of the pool for good
+    // Frees a slot so the pool can create a new one
+    fn detach(mut self) -> T {
+        let value = self.value.take().unwrap();
+        self.pool.release_slot();
+        value
+    }
+}
+
+impl<T> Deref for Pooled<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.value.as_ref().unwrap()
+    }
+}
+
+impl<T> DerefMut for Pooled<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.value.as_mut().unwrap()
+    }
+}
+
+impl<T> Drop for Pooled<T> {
+    fn drop(&mut self) {
+        let Some(mut value) = self.value.take() else {
+            return;
+        };
+        // If reset panics the object is dropped and its slot released
+        let guard = SlotGuard { pool: &self.pool };
+        (self.pool.reset)(&mut value);
+        guard.dismiss();
+        let mut state = self.pool.lock();
+        state.idle.push(value);
+        drop(state);
+        self.pool.available.notify_one();
+    }
+}
+
+fn main() {
+    // Pool of buffers, cleared before reuse
+    let pool: Pool<Vec<u8>> = Pool::new(2, || {
+        info!("create buffer");
+        Vec::with_capacity(1024)
+    })
+    .with_reset(|buf| buf.clear());
+
+    {
+        let mut b1 = pool.get();
+        b1.extend_from_slice(b"hello");
+        let b2 = pool.get();
+        // Pool is empty
+        info!("try_get = {:?}", pool.try_get().is_some());
+        info!(
+            "get_timeout = {:?}",
+            pool.get_timeout(Duration::from_millis(10)).is_some()
+        );
+        // b1 and b2 are returned here
+    }
+    info!("idle = {}, created = {}", pool.idle(), pool.created());
+
+    // Reused and reset
+    let b = pool.get();
+    info!("reused len = {}, capacity = {}", b.len(), b.capacity());
+    drop(b);
+
+    // Share between threads
+    let counter = Arc::new(Mutex::new(0));
+    let mut handles = Vec::new();
+    for i in 0..8 {
+        let pool = pool.clone();
+        let counter = Arc::clone(&counter);
+        handles.push(thread::spawn(move || {
+            // Blocks until one of the 2 buffers is free
+            let mut buf = pool.get();
+            buf.push(i);
+            thread::sleep(Duration::from_millis(5));
+            *counter.lock().unwrap() += 1;
+        }));
+    }
+    for h in handles {
+        h.join().unwrap();
+    }
+    info!("jobs = {}", counter.lock().unwrap());
+    info!("idle = {}, created = {}", pool.idle(), pool.created());
+
+    // Detach
+    let b = pool.get().detach();
+    info!("detached, created = {}", pool.created());
+
+    // Panics in create and reset don't leak the only slot
+    panic::set_hook(Box::new(|_| {}));
+    let calls = Arc::new(Mutex::new(0));
+    let c = Arc::clone(&calls);
+    let pool: Pool<u32> = Pool::new(1, move || {
+        // Unlock before panicking, a panic with the guard held poisons calls
+        let n = {
+            let mut calls = c.lock().unwrap();
+            *calls += 1;
+            *calls
+        };
+        if n == 1 {
+            panic!("create failed");
+        }
+        n
+    })
+    .with_reset(|n| {
+        if *n == 2 {
+            panic!("reset failed");
+        }
+    });
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| pool.get())).is_err());
+    assert_eq!(pool.created(), 0);
+    // Second create returns 2, its reset panics on drop
+    let n = pool.get();
+    assert_eq!(*n, 2);
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| drop(n))).is_err());
+    assert_eq!((pool.created(), pool.idle()), (0, 0));
+    // max_size = 1, would block forever if a slot had leaked
+    let n = pool.get_timeout(Duration::from_millis(100)).unwrap();
+    assert_eq!(*n, 3);
+    drop(n);
+    assert_eq!((pool.created(), pool.idle()), (1, 1));
+    let _ = panic::take_hook();
+}
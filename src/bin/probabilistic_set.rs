@@ -0,0 +1,548 @@
+#![allow(unused)]
+
+// Probabilistic data structures
+// - Use much less memory than HashSet (hash_set.rs), answers are approximate
+// - BloomFilter: "maybe in the set" or "definitely not in the set"
+// - CountingBloomFilter: Bloom filter that supports remove
+// - HyperLogLog: estimate the number of distinct items
+// - CountMinSketch: estimate how often an item was seen (never too low)
+//
+// check_error_bounds() compares the measured error with the theoretical bound.
+// The hash has no random seed, so every run gives the same numbers:
+// cargo run --bin probabilistic_set    under 1s in debug
+
+use hello_rust::info;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+// FNV-1a, same output on every run and platform so serialized filters stay valid
+struct Fnv(u64);
+
+impl Hasher for Fnv {
+    fn finish(&self) -> u64 {
+        splitmix(self.0)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 ^= b as u64;
+            self.0 = self.0.wrapping_mul(0x100_0000_01b3);
+        }
+    }
+}
+
+fn splitmix(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+fn hash<T: Hash + ?Sized>(item: &T) -> u64 {
+    let mut h = Fnv(0xcbf2_9ce4_8422_2325);
+    item.hash(&mut h);
+    h.finish()
+}
+
+// k indexes in 0..m from 2 hashes (Kirsch-Mitzenmacher double hashing)
+fn indexes<T: Hash + ?Sized>(item: &T, k: u32, m: usize) -> impl Iterator<Item = usize> {
+    let h1 = hash(item);
+    let h2 = splitmix(h1) | 1;
+    (0..k as u64).map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % m as u64) as usize)
+}
+
+// Optimal number of bits and hashes for n items and false positive rate p
+fn bloom_params(n: usize, p: f64) -> (usize, u32) {
+    assert!(n > 0, "n must be > 0");
+    assert!(p > 0.0 && p < 1.0, "p must be in (0, 1)");
+    let ln2 = std::f64::consts::LN_2;
+    let m = (-(n as f64) * p.ln() / (ln2 * ln2)).ceil() as usize;
+    let k = ((m as f64 / n as f64) * ln2).round().max(1.0) as u32;
+    (m.max(1), k)
+}
+
+// Serialization helpers, little endian
+struct ByteReader<'a>(&'a [u8]);
+
+impl ByteReader<'_> {
+    fn take(&mut self, n: usize) -> Result<&[u8], String> {
+        if self.0.len() < n {
+            return Err("unexpected end of bytes".to_string());
+        }
+        let (head, tail) = self.0.split_at(n);
+        self.0 = tail;
+        Ok(head)
+    }
+
+    fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, String> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn tag(&mut self, tag: &[u8; 4]) -> Result<(), String> {
+        if self.take(4)? != tag {
+            return Err(format!("expected {:?}", String::from_utf8_lossy(tag)));
+        }
+        Ok(())
+    }
+
+    fn end(&self) -> Result<(), String> {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            Err("trailing bytes".to_string())
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct BloomFilter {
+    bits: Vec<u64>,
+    // Number of bits
+    m: usize,
+    // Number of hashes
+    k: u32,
+}
+
+impl BloomFilter {
+    // Sized for n items with false positive rate p
+    fn new(n: usize, p: f64) -> Self {
+        let (m, k) = bloom_params(n, p);
+        Self {
+            bits: vec![0; m.div_ceil(64)],
+            m,
+            k,
+        }
+    }
+
+    fn insert<T: Hash + ?Sized>(&mut self, item: &T) {
+        for i in indexes(item, self.k, self.m) {
+            self.bits[i / 64] |= 1 << (i % 64);
+        }
+    }
+
+    fn contains<T: Hash + ?Sized>(&self, item: &T) -> bool {
+        indexes(item, self.k, self.m).all(|i| self.bits[i / 64] & (1 << (i % 64)) != 0)
+    }
+
+    // Same m and k required
+    fn union(&mut self, other: &BloomFilter) {
+        assert!(
+            self.m == other.m && self.k == other.k,
+            "incompatible filters"
+        );
+        for (a, b) in self.bits.iter_mut().zip(&other.bits) {
+            *a |= b;
+        }
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut out = b"BLOM".to_vec();
+        out.extend_from_slice(&(self.m as u64).to_le_bytes());
+        out.extend_from_slice(&self.k.to_le_bytes());
+        for word in &self.bits {
+            out.extend_from_slice(&word.to_le_bytes());
+        }
+        out
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let mut r = ByteReader(bytes);
+        r.tag(b"BLOM")?;
+        let m = r.u64()? as usize;
+        let k = r.u32()?;
+        if m == 0 || k == 0 {
+            return Err("invalid parameters".to_string());
+        }
+        // Check the size before allocating
+        let words = m.div_ceil(64);
+        if r.0.len() != words * 8 {
+            return Err("wrong number of bytes".to_string());
+        }
+        let bits = (0..words).map(|_| r.u64()).collect::<Result<_, _>>()?;
+        Ok(Self { bits, m, k })
+    }
+}
+
+// 4 bit counters would be enough in practice, u8 keeps it simple
+#[derive(Debug, Clone, PartialEq)]
+struct CountingBloomFilter {
+    counters: Vec<u8>,
+    k: u32,
+}
+
+impl CountingBloomFilter {
+    fn new(n: usize, p: f64) -> Self {
+        let (m, k) = bloom_params(n, p);
+        Self {
+            counters: vec![0; m],
+            k,
+        }
+    }
+
+    fn insert<T: Hash + ?Sized>(&mut self, item: &T) {
+        for i in indexes(item, self.k, self.counters.len()) {
+            // Saturated counters are never decremented
+            self.counters[i] = self.counters[i].saturating_add(1);
+        }
+    }
+
+    // Only remove items that were inserted, otherwise other items may be lost
+    fn remove<T: Hash + ?Sized>(&mut self, item: &T) -> bool {
+        if !self.contains(item) {
+            return false;
+        }
+        for i in indexes(item, self.k, self.counters.len()) {
+            if self.counters[i] != u8::MAX {
+                self.counters[i] -= 1;
+            }
+        }
+        true
+    }
+
+    fn contains<T: Hash + ?Sized>(&self, item: &T) -> bool {
+        indexes(item, self.k, self.counters.len()).all(|i| self.counters[i] > 0)
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut out = b"CBLM".to_vec();
+        out.extend_from_slice(&(self.counters.len() as u64).to_le_bytes());
+        out.extend_from_slice(&self.k.to_le_bytes());
+        out.extend_from_slice(&self.counters);
+        out
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let mut r = ByteReader(bytes);
+        r.tag(b"CBLM")?;
+        let m = r.u64()? as usize;
+        let k = r.u32()?;
+        if m == 0 || k == 0 || r.0.len() != m {
+            return Err("invalid parameters".to_string());
+        }
+        let counters = r.take(m)?.to_vec();
+        Ok(Self { counters, k })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct HyperLogLog {
+    // m = 2^p registers
+    p: u8,
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    // Standard error = 1.04 / sqrt(2^p)
+    fn new(p: u8) -> Self {
+        assert!((4..=16).contains(&p), "p must be in 4..=16");
+        Self {
+            p,
+            registers: vec![0; 1 << p],
+        }
+    }
+
+    fn insert<T: Hash + ?Sized>(&mut self, item: &T) {
+        let h = hash(item);
+        // First p bits pick the register
+        let i = (h >> (64 - self.p)) as usize;
+        // Position of the first 1 bit in the rest
+        let rest = h << self.p;
+        let rank = (rest.leading_zeros() + 1).min(64 - self.p as u32 + 1) as u8;
+        self.registers[i] = self.registers[i].max(rank);
+    }
+
+    fn estimate(&self) -> f64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+        let sum: f64 = self.registers.iter().map(|&r| 2f64.powi(-(r as i32))).sum();
+        let estimate = alpha * m * m / sum;
+        let zeros = self.registers.iter().filter(|&&r| r == 0).count();
+        // Small range correction - linear counting
+        if estimate <= 2.5 * m && zeros > 0 {
+            m * (m / zeros as f64).ln()
+        } else {
+            estimate
+        }
+    }
+
+    fn std_error(&self) -> f64 {
+        1.04 / (self.registers.len() as f64).sqrt()
+    }
+
+    // Estimate of the union of both sets
+    fn merge(&mut self, other: &HyperLogLog) {
+        assert_eq!(self.p, other.p, "incompatible precision");
+        for (a, b) in self.registers.iter_mut().zip(&other.registers) {
+            *a = (*a).max(*b);
+        }
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut out = b"HLL1".to_vec();
+        out.push(self.p);
+        out.extend_from_slice(&self.registers);
+        out
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let mut r = ByteReader(bytes);
+        r.tag(b"HLL1")?;
+        let p = r.u8()?;
+        if !(4..=16).contains(&p) {
+            return Err("invalid precision".to_string());
+        }
+        let registers = r.take(1 << p)?.to_vec();
+        r.end()?;
+        Ok(Self { p, registers })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct CountMinSketch {
+    width: usize,
+    depth: usize,
+    // depth rows of width counters
+    counts: Vec<u64>,
+    total: u64,
+}
+
+impl CountMinSketch {
+    // Estimate <= true count + eps * total, with probability 1 - delta
+    fn new(eps: f64, delta: f64) -> Self {
+        assert!(eps > 0.0 && delta > 0.0 && delta < 1.0);
+        let width = (std::f64::consts::E / eps).ceil() as usize;
+        let depth = (1.0 / delta).ln().ceil().max(1.0) as usize;
+        Self {
+            width,
+            depth,
+            counts: vec![0; width * depth],
+            total: 0,
+        }
+    }
+
+    fn cells<T: Hash + ?Sized>(&self, item: &T) -> impl Iterator<Item = usize> {
+        let width = self.width;
+        // One index per row
+        indexes(item, self.depth as u32, width)
+            .enumerate()
+            .map(move |(row, col)| row * width + col)
+    }
+
+    fn add<T: Hash + ?Sized>(&mut self, item: &T, count: u64) {
+        for i in self.cells(item).collect::<Vec<_>>() {
+            self.counts[i] = self.counts[i].saturating_add(count);
+        }
+        self.total = self.total.saturating_add(count);
+    }
+
+    fn estimate<T: Hash + ?Sized>(&self, item: &T) -> u64 {
+        self.cells(item).map(|i| self.counts[i]).min().unwrap_or(0)
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut out = b"CMS1".to_vec();
+        out.extend_from_slice(&(self.width as u32).to_le_bytes());
+        out.extend_from_slice(&(self.depth as u32).to_le_bytes());
+        out.extend_from_slice(&self.total.to_le_bytes());
+        for c in &self.counts {
+            out.extend_from_slice(&c.to_le_bytes());
+        }
+        out
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let mut r = ByteReader(bytes);
+        r.tag(b"CMS1")?;
+        let width = r.u32()? as usize;
+        let depth = r.u32()? as usize;
+        let total = r.u64()?;
+        // width * depth * 8 can overflow u64 with a bad header
+        let size = (width as u64)
+            .checked_mul(depth as u64)
+            .and_then(|n| n.checked_mul(8));
+        if width == 0 || depth == 0 || size != Some(r.0.len() as u64) {
+            return Err("invalid parameters".to_string());
+        }
+        let counts = (0..width * depth)
+            .map(|_| r.u64())
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            width,
+            depth,
+            counts,
+            total,
+        })
+    }
+}
+
+// Measured error of each structure against its theoretical bound
+fn check_error_bounds() {
+    // Bloom: no false negatives, false positive rate near p
+    for (n, p) in [(1_000u32, 0.1), (10_000, 0.01), (20_000, 0.001)] {
+        let mut bloom = BloomFilter::new(n as usize, p);
+        for i in 0..n {
+            bloom.insert(&i);
+        }
+        assert!((0..n).all(|i| bloom.contains(&i)));
+        let trials = 100_000u32;
+        let fp = (n..n + trials).filter(|i| bloom.contains(i)).count();
+        let rate = fp as f64 / trials as f64;
+        assert!(rate < 1.5 * p, "bloom n = {n}, p = {p}: rate {rate}");
+    }
+
+    // Counting Bloom: removing every item empties the filter
+    let mut counting = CountingBloomFilter::new(1_000, 0.01);
+    for i in 0..1_000u32 {
+        counting.insert(&i);
+    }
+    for i in 0..1_000u32 {
+        counting.remove(&i);
+    }
+    assert!((0..1_000u32).all(|i| !counting.contains(&i)));
+
+    // HyperLogLog: relative error within 3 standard errors (1.04 / sqrt(m))
+    let err = |est: f64, actual: f64| (est - actual).abs() / actual;
+    for precision in [8, 10, 12, 14] {
+        for n in [100u32, 1_000, 10_000, 100_000] {
+            let mut hll = HyperLogLog::new(precision);
+            for i in 0..n {
+                hll.insert(&(precision, i));
+            }
+            let e = err(hll.estimate(), n as f64);
+            assert!(
+                e < 3.0 * hll.std_error(),
+                "hll precision {precision}, n = {n}: error {e}"
+            );
+        }
+    }
+
+    // Count-Min: never too low, over by more than eps * total with probability < delta
+    for (eps, delta) in [(0.01, 0.05), (0.001, 0.01)] {
+        let mut cms = CountMinSketch::new(eps, delta);
+        let n = 10_000u64;
+        for i in 0..n {
+            cms.add(&i, i % 10 + 1);
+        }
+        let bound = (eps * cms.total as f64) as u64;
+        assert!((0..n).all(|i| cms.estimate(&i) > i % 10));
+        let over = (0..n)
+            .filter(|&i| cms.estimate(&i) > i % 10 + 1 + bound)
+            .count();
+        assert!((over as f64 / n as f64) < delta, "cms eps = {eps}");
+    }
+}
+
+fn main() {
+    // Compare with HashSet
+    let mut set: HashSet<u32> = HashSet::new();
+    let mut bloom = BloomFilter::new(10_000, 0.01);
+    for i in 0..10_000u32 {
+        set.insert(i);
+        bloom.insert(&i);
+    }
+    info!("bloom bits = {}, hashes = {}", bloom.m, bloom.k);
+    info!("1: {}", bloom.contains(&1u32));
+    // No false negatives
+    assert!((0..10_000u32).all(|i| bloom.contains(&i)));
+
+    // False positive rate close to 1%
+    let trials = 100_000u32;
+    let fp = (10_000..10_000 + trials)
+        .filter(|i| bloom.contains(i))
+        .count();
+    let rate = fp as f64 / trials as f64;
+    info!("bloom false positive rate = {:.4} (target 0.01)", rate);
+    assert!(rate < 0.015);
+
+    let bytes = bloom.to_bytes();
+    assert_eq!(BloomFilter::from_bytes(&bytes), Ok(bloom.clone()));
+    info!(
+        "bloom bytes = {} (HashSet ~ {})",
+        bytes.len(),
+        set.capacity() * 4
+    );
+
+    // Counting Bloom filter
+    let mut counting = CountingBloomFilter::new(1_000, 0.01);
+    counting.insert("red");
+    counting.insert("blue");
+    counting.remove("red");
+    info!(
+        "red: {}, blue: {}",
+        counting.contains("red"),
+        counting.contains("blue")
+    );
+    let bytes = counting.to_bytes();
+    assert_eq!(CountingBloomFilter::from_bytes(&bytes), Ok(counting));
+
+    // HyperLogLog
+    let mut a = HyperLogLog::new(12);
+    let mut b = HyperLogLog::new(12);
+    for i in 0..60_000u32 {
+        a.insert(&i);
+    }
+    for i in 40_000..100_000u32 {
+        b.insert(&i);
+    }
+    let err = |est: f64, actual: f64| (est - actual).abs() / actual;
+    info!(
+        "hll a = {:.0} (actual 60000), error = {:.4}, std error = {:.4}",
+        a.estimate(),
+        err(a.estimate(), 60_000.0),
+        a.std_error()
+    );
+    a.merge(&b);
+    info!("hll a + b = {:.0} (actual 100000)", a.estimate());
+    // Within 3 standard errors
+    assert!(err(a.estimate(), 100_000.0) < 3.0 * a.std_error());
+    assert_eq!(HyperLogLog::from_bytes(&a.to_bytes()), Ok(a));
+
+    // Count-Min sketch
+    let (eps, delta) = (0.001, 0.01);
+    let mut cms = CountMinSketch::new(eps, delta);
+    // Item i is seen i % 100 + 1 times
+    let n = 20_000u64;
+    for i in 0..n {
+        cms.add(&i, i % 100 + 1);
+    }
+    let bound = (eps * cms.total as f64) as u64;
+    let over = (0..n)
+        .filter(|&i| cms.estimate(&i) > i % 100 + 1 + bound)
+        .count();
+    info!(
+        "cms width = {}, depth = {}, over bound = {:.4} (delta {})",
+        cms.width,
+        cms.depth,
+        over as f64 / n as f64,
+        delta
+    );
+    assert!((0..n).all(|i| cms.estimate(&i) > i % 100));
+    assert!((over as f64 / n as f64) < delta);
+    assert_eq!(CountMinSketch::from_bytes(&cms.to_bytes()), Ok(cms));
+    // Header with width = depth = u32::MAX
+    let mut bad = b"CMS1".to_vec();
+    bad.extend_from_slice(&[0xff; 8]);
+    bad.extend_from_slice(&0u64.to_le_bytes());
+    assert_eq!(
+        CountMinSketch::from_bytes(&bad),
+        Err("invalid parameters".to_string())
+    );
+
+    info!("{:?}", BloomFilter::from_bytes(b"BLOM"));
+
+    check_error_bounds();
+    info!("error bounds ok");
+}
@@ -1,16 +1,21 @@
 #![allow(unused)]
 
-fn f1() -> Result<u32, String> {
-    println!("f1");
+use hello_rust::any_error::{AnyError, Context, Result};
+use hello_rust::errors::{MathError, ParseError};
+use hello_rust::info;
+
+fn f1() -> Result<u32, MathError> {
+    info!("f1");
     Ok(1)
 }
 
-fn f2() -> Result<u32, String> {
-    println!("f2");
+fn f2() -> Result<u32, ParseError> {
+    info!("f2");
     Ok(2)
 }
 
-fn f1_f2_match() -> Result<u32, String> {
+// Errors keep the original error, with a message as context
+fn f1_f2_match() -> Result<u32> {
     // Panic on error
     // f1().unwrap();
     // f2().unwrap();
@@ -18,25 +23,26 @@ fn f1_f2_match() -> Result<u32, String> {
     let res_1 = f1();
     let out_1 = match res_1 {
         Ok(num) => num,
-        Err(_) => {
-            return Err("error from f1".to_string());
+        Err(e) => {
+            return Err(AnyError::new(e).context("error from f1"));
         }
     };
 
     let res_2 = f2();
     let out_2 = match res_2 {
         Ok(num) => num,
-        Err(_) => {
-            return Err("error from f2".to_string());
+        Err(e) => {
+            return Err(AnyError::new(e).context("error from f2"));
         }
     };
 
     Ok(out_1 + out_2)
 }
 
-// Return type must be same (or can be casted) for f1 and f2
-fn f1_f2_question() -> Result<u32, String> {
-    let out_1 = f1()?;
+// ? converts the error with From, MathError and ParseError both convert into AnyError
+// .context() does the same as the match above
+fn f1_f2_question() -> Result<u32> {
+    let out_1 = f1().context("error from f1")?;
     let out_2 = f2()?;
     Ok(out_1 + out_2)
 }
@@ -44,8 +50,8 @@ fn f1_f2_question() -> Result<u32, String> {
 fn main() {
     // Match
     let res = f1_f2_match();
-    println!("{:?}", res);
+    info!("{:?}", res);
     // Question operator
     let res = f1_f2_question();
-    println!("{:?}", res);
+    info!("{:?}", res);
 }
@@ -1,5 +1,6 @@
 #![allow(unused)]
 
+use hello_rust::info;
 use std::rc::Rc;
 
 // Rc - reference counting
@@ -46,32 +47,33 @@ fn main() {
 
     // 2 -> 1 -> Nil
     let a = Rc::new(Cons(2, Rc::new(Cons(1, Rc::new(Nil)))));
-    println!("a: {}", Rc::strong_count(&a));
+    info!("a: {}", Rc::strong_count(&a));
 
     // 3 -> a
     let b = Cons(3, Rc::clone(&a));
     // strong_count increases by 1
-    println!("b: {}", Rc::strong_count(&a));
+    info!("b: {}", Rc::strong_count(&a));
 
     {
         // 4 -> a
         let c = Cons(4, Rc::clone(&a));
         // strong_count increases by 1
-        println!("c: {}", Rc::strong_count(&a));
+        info!("c: {}", Rc::strong_count(&a));
     }
 
     // strong_count decreases by 1
-    println!("drop c: {}", Rc::strong_count(&a));
+    info!("drop c: {}", Rc::strong_count(&a));
 
     // Example - print all values in List
+    let mut line = String::new();
     let mut curr = Rc::clone(&a);
     while let Cons(v, ref tail) = *curr {
-        print!("{v} -> ");
+        line += &format!("{v} -> ");
         // tail = &Rc<List>
         if let Nil = **tail {
             break;
         }
         curr = Rc::clone(tail);
     }
-    println!("Nil");
+    info!("{line}Nil");
 }
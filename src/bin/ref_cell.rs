@@ -1,5 +1,6 @@
 #![allow(unused)]
 
+use hello_rust::info;
 use std::cell::{RefCell, RefMut};
 use std::rc::Rc;
 
@@ -39,7 +40,7 @@ fn main() {
         let mut r1 = r.borrow_mut();
         *r1 += "🦀";
         // r is borrowed until r1 is dropped
-        println!("{:#?}", r);
+        info!("{:#?}", r);
 
         // Run time error - already borrowed
         // let mut r2 = r.borrow_mut();
@@ -48,7 +49,7 @@ fn main() {
     }
 
     // s is owned by r
-    println!("{:#?}", r);
+    info!("{:#?}", r);
 
     // Example - List
     // 1 -> Nil
@@ -59,9 +60,10 @@ fn main() {
     let c = Cons(Rc::new(RefCell::new(4)), Rc::clone(&a));
 
     // Example - traverse to update last element
+    let mut line = String::new();
     let mut curr = Rc::clone(&a);
     while let Cons(v, tail) = &*curr {
-        print!("{} -> ", *(v.borrow()));
+        line += &format!("{} -> ", *(v.borrow()));
         if let Nil = **tail {
             let mut x = v.borrow_mut();
             *x += 100;
@@ -69,11 +71,11 @@ fn main() {
         }
         curr = Rc::clone(tail);
     }
-    println!("Nil");
+    info!("{line}Nil");
 
-    println!("a: {a:?}");
-    println!("b: {b:?}");
-    println!("c: {c:?}");
+    info!("a: {a:?}");
+    info!("b: {b:?}");
+    info!("c: {c:?}");
 
     // Example - update last element without traversing
     let v = Rc::new(RefCell::new(1));
@@ -91,9 +93,9 @@ fn main() {
     // Same as
     // *(v.borrow_mut()) += 100;
 
-    println!("a: {a:?}");
-    println!("b: {b:?}");
-    println!("c: {c:?}");
+    info!("a: {a:?}");
+    info!("b: {b:?}");
+    info!("c: {c:?}");
 
     // Example - Node
     /*
@@ -138,5 +140,5 @@ fn main() {
     }
 
     // Infinite loop - panics
-    println!("{:?}", node0);
+    info!("{:?}", node0);
 }
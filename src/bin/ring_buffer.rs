@@ -0,0 +1,225 @@
+#![allow(unused)]
+
+// Fixed capacity ring buffer
+// - Array of N slots (array.rs), no heap allocation
+// - head = index of the first element, elements wrap around the end
+// - Push to a full buffer either rejects the value or overwrites the oldest
+
+use hello_rust::alloc_count::{count_allocations, CountingAlloc};
+use hello_rust::info;
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::ops::{Index, IndexMut};
+
+// Count heap allocations (src/alloc_count.rs)
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+struct RingBuffer<T, const N: usize> {
+    // Slots head..head + len (mod N) are initialized
+    buf: [MaybeUninit<T>; N],
+    head: usize,
+    len: usize,
+}
+
+impl<T, const N: usize> RingBuffer<T, N> {
+    fn new() -> Self {
+        assert!(N > 0, "capacity must be > 0");
+        Self {
+            buf: [const { MaybeUninit::uninit() }; N],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    // Slot index of the i-th element
+    fn slot(&self, i: usize) -> usize {
+        (self.head + i) % N
+    }
+
+    // Err(value) if full
+    fn push_back(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+        let i = self.slot(self.len);
+        self.buf[i].write(value);
+        self.len += 1;
+        Ok(())
+    }
+
+    fn push_front(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+        self.head = (self.head + N - 1) % N;
+        self.buf[self.head].write(value);
+        self.len += 1;
+        Ok(())
+    }
+
+    // Overwrites the oldest element if full, returns it
+    fn push_overwrite(&mut self, value: T) -> Option<T> {
+        let old = if self.is_full() {
+            self.pop_front()
+        } else {
+            None
+        };
+        // Cannot fail, there is room now
+        let _ = self.push_back(value);
+        old
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let value = unsafe { self.buf[self.head].assume_init_read() };
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+        Some(value)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.len -= 1;
+        let i = self.slot(self.len);
+        Some(unsafe { self.buf[i].assume_init_read() })
+    }
+
+    fn get(&self, i: usize) -> Option<&T> {
+        if i >= self.len {
+            return None;
+        }
+        Some(unsafe { self.buf[self.slot(i)].assume_init_ref() })
+    }
+
+    fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        if i >= self.len {
+            return None;
+        }
+        let slot = self.slot(i);
+        Some(unsafe { self.buf[slot].assume_init_mut() })
+    }
+
+    // Elements in order as 2 contiguous slices
+    // The second slice is empty unless the elements wrap around
+    fn as_slices(&self) -> (&[T], &[T]) {
+        let first_len = self.len.min(N - self.head);
+        let first = &self.buf[self.head..self.head + first_len];
+        let second = &self.buf[..self.len - first_len];
+        // MaybeUninit<T> has the same layout as T, and these slots are initialized
+        unsafe {
+            (
+                &*(first as *const [MaybeUninit<T>] as *const [T]),
+                &*(second as *const [MaybeUninit<T>] as *const [T]),
+            )
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &T> {
+        let (a, b) = self.as_slices();
+        a.iter().chain(b)
+    }
+
+    fn clear(&mut self) {
+        while self.pop_back().is_some() {}
+    }
+}
+
+impl<T, const N: usize> Drop for RingBuffer<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> Index<usize> for RingBuffer<T, N> {
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        self.get(i).expect("index out of bounds")
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for RingBuffer<T, N> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        self.get_mut(i).expect("index out of bounds")
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for RingBuffer<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+fn main() {
+    // Every operation below is checked to make 0 heap allocations
+    let (mut rb, n) = count_allocations(|| {
+        let mut rb: RingBuffer<i32, 4> = RingBuffer::new();
+        for i in 1..=4 {
+            rb.push_back(i).unwrap();
+        }
+        rb
+    });
+    assert_eq!(n, 0);
+
+    let (res, n) = count_allocations(|| {
+        // Full - rejected
+        let rejected = rb.push_back(5);
+        // Full - oldest is overwritten
+        let removed = rb.push_overwrite(5);
+        (rejected, removed)
+    });
+    assert_eq!((res, n), ((Err(5), Some(1)), 0));
+    info!("push 5 = {:?}, overwrite removed {:?}", res.0, res.1);
+    info!("{:?} as_slices = {:?}", rb, rb.as_slices());
+    assert_eq!(rb.as_slices(), (&[2, 3, 4][..], &[5][..]));
+
+    let (res, n) = count_allocations(|| {
+        rb[0] = 20;
+        let popped = (rb.pop_front(), rb.pop_back());
+        rb.push_front(0).unwrap();
+        let sum: i32 = rb.iter().sum();
+        (popped, sum)
+    });
+    assert_eq!((res, n), (((Some(20), Some(5)), 7), 0));
+    info!(
+        "pop_front, pop_back = {:?}, {:?}, sum = {}",
+        rb, res.0, res.1
+    );
+
+    // Strings are created first, moving them in and out does not allocate
+    let mut names: [Option<String>; 5] = ["a", "b", "c", "d", "e"].map(|s| Some(s.to_string()));
+    let mut dropped = Vec::with_capacity(2);
+    let ((), n) = count_allocations(|| {
+        let mut rb: RingBuffer<String, 3> = RingBuffer::new();
+        for name in &mut names {
+            if let Some(old) = rb.push_overwrite(name.take().unwrap()) {
+                dropped.push(old);
+            }
+        }
+        // Drops the remaining elements
+    });
+    assert_eq!(n, 0);
+    assert_eq!(dropped, ["a", "b"]);
+    info!("overwritten {:?}", dropped);
+}
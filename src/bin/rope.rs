@@ -0,0 +1,619 @@
+#![allow(unused)]
+
+// Rope - text stored as a balanced binary tree of small UTF-8 chunks
+// - Leaves hold chunks of text, branches hold the char, byte and line counts
+//   of their subtree so an index can be found in O(log n)
+// - Insert and remove split the tree and join the pieces back, O(log n)
+// - Nodes are shared with Rc (rc.rs) and never mutated,
+//   an edit copies only the path from the root, so clone is O(1)
+// - Balanced like an AVL tree, heights of 2 children differ by at most 1
+
+use hello_rust::info;
+use std::fmt;
+use std::ops::Range;
+use std::rc::Rc;
+
+// Max bytes in a leaf
+const MAX_LEAF: usize = 64;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct Info {
+    bytes: usize,
+    chars: usize,
+    // Number of '\n'
+    newlines: usize,
+}
+
+impl Info {
+    fn of(s: &str) -> Self {
+        Self {
+            bytes: s.len(),
+            chars: s.chars().count(),
+            newlines: s.bytes().filter(|&b| b == b'\n').count(),
+        }
+    }
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            bytes: self.bytes + other.bytes,
+            chars: self.chars + other.chars,
+            newlines: self.newlines + other.newlines,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Node {
+    Leaf(String),
+    Branch {
+        left: Rc<Node>,
+        right: Rc<Node>,
+        info: Info,
+        height: usize,
+    },
+}
+
+impl Node {
+    fn info(&self) -> Info {
+        match self {
+            Node::Leaf(s) => Info::of(s),
+            Node::Branch { info, .. } => *info,
+        }
+    }
+
+    fn height(&self) -> usize {
+        match self {
+            Node::Leaf(_) => 0,
+            Node::Branch { height, .. } => *height,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.info().bytes == 0
+    }
+}
+
+fn leaf(s: &str) -> Rc<Node> {
+    Rc::new(Node::Leaf(s.to_string()))
+}
+
+fn branch(left: Rc<Node>, right: Rc<Node>) -> Rc<Node> {
+    Rc::new(Node::Branch {
+        info: left.info().add(right.info()),
+        height: 1 + left.height().max(right.height()),
+        left,
+        right,
+    })
+}
+
+// Children of a branch, cloning the Rc is cheap
+fn children(node: &Rc<Node>) -> (Rc<Node>, Rc<Node>) {
+    match &**node {
+        Node::Branch { left, right, .. } => (left.clone(), right.clone()),
+        Node::Leaf(_) => unreachable!("leaf has no children"),
+    }
+}
+
+//     n           r
+//   l   r   ->  n   rr
+//     rl rr    l rl
+fn rotate_left(node: Rc<Node>) -> Rc<Node> {
+    let (l, r) = children(&node);
+    let (rl, rr) = children(&r);
+    branch(branch(l, rl), rr)
+}
+
+fn rotate_right(node: Rc<Node>) -> Rc<Node> {
+    let (l, r) = children(&node);
+    let (ll, lr) = children(&l);
+    branch(ll, branch(lr, r))
+}
+
+// Join when left is more than 1 level taller than right
+// Walk down the right spine of left to a subtree of about the same height
+fn join_right(left: Rc<Node>, right: Rc<Node>) -> Rc<Node> {
+    let (l, r) = children(&left);
+    let t = if r.height() <= right.height() + 1 {
+        branch(r, right)
+    } else {
+        join_right(r, right)
+    };
+    if t.height() <= l.height() + 1 {
+        branch(l, t)
+    } else if t.height() == l.height() + 2 {
+        // Only reachable when t came from branch(r, right) one level up
+        let t = if children(&t).0.height() > children(&t).1.height() {
+            rotate_right(t)
+        } else {
+            t
+        };
+        rotate_left(branch(l, t))
+    } else {
+        unreachable!()
+    }
+}
+
+fn join_left(left: Rc<Node>, right: Rc<Node>) -> Rc<Node> {
+    let (l, r) = children(&right);
+    let t = if l.height() <= left.height() + 1 {
+        branch(left, l)
+    } else {
+        join_left(left, l)
+    };
+    if t.height() <= r.height() + 1 {
+        branch(t, r)
+    } else {
+        let t = if children(&t).1.height() > children(&t).0.height() {
+            rotate_left(t)
+        } else {
+            t
+        };
+        rotate_right(branch(t, r))
+    }
+}
+
+// Concatenate 2 balanced trees into a balanced tree
+fn join(left: Rc<Node>, right: Rc<Node>) -> Rc<Node> {
+    if left.is_empty() {
+        return right;
+    }
+    if right.is_empty() {
+        return left;
+    }
+    if let (Node::Leaf(a), Node::Leaf(b)) = (&*left, &*right) {
+        if a.len() + b.len() <= MAX_LEAF {
+            return Rc::new(Node::Leaf(format!("{a}{b}")));
+        }
+    }
+    let (hl, hr) = (left.height(), right.height());
+    if hl > hr + 1 {
+        join_right(left, right)
+    } else if hr > hl + 1 {
+        join_left(left, right)
+    } else {
+        branch(left, right)
+    }
+}
+
+// Split into chars [0, at) and [at, len)
+fn split(node: &Rc<Node>, at: usize) -> (Rc<Node>, Rc<Node>) {
+    if at == 0 {
+        return (leaf(""), node.clone());
+    }
+    if at >= node.info().chars {
+        return (node.clone(), leaf(""));
+    }
+    match &**node {
+        Node::Leaf(s) => {
+            let (a, b) = s.split_at(char_to_byte(s, at));
+            (leaf(a), leaf(b))
+        }
+        Node::Branch { left, right, .. } => {
+            let n = left.info().chars;
+            if at <= n {
+                let (a, b) = split(left, at);
+                (a, join(b, right.clone()))
+            } else {
+                let (a, b) = split(right, at - n);
+                (join(left.clone(), a), b)
+            }
+        }
+    }
+}
+
+// Balanced tree from a string cut into chunks of at most MAX_LEAF bytes
+fn build(s: &str) -> Rc<Node> {
+    let mut chunks = Vec::new();
+    let mut rest = s;
+    while rest.len() > MAX_LEAF {
+        let mut end = MAX_LEAF;
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        let (chunk, tail) = rest.split_at(end);
+        chunks.push(leaf(chunk));
+        rest = tail;
+    }
+    chunks.push(leaf(rest));
+    build_from(&chunks)
+}
+
+fn build_from(chunks: &[Rc<Node>]) -> Rc<Node> {
+    if chunks.len() == 1 {
+        return chunks[0].clone();
+    }
+    let (a, b) = chunks.split_at(chunks.len() / 2);
+    branch(build_from(a), build_from(b))
+}
+
+fn char_to_byte(s: &str, i: usize) -> usize {
+    s.char_indices().nth(i).map_or(s.len(), |(b, _)| b)
+}
+
+#[derive(Clone)]
+struct Rope {
+    root: Rc<Node>,
+}
+
+impl Rope {
+    fn new() -> Self {
+        Self { root: leaf("") }
+    }
+
+    fn len_chars(&self) -> usize {
+        self.root.info().chars
+    }
+
+    fn len_bytes(&self) -> usize {
+        self.root.info().bytes
+    }
+
+    // Number of lines, 1 + number of '\n'
+    fn len_lines(&self) -> usize {
+        self.root.info().newlines + 1
+    }
+
+    fn is_empty(&self) -> bool {
+        self.root.is_empty()
+    }
+
+    fn height(&self) -> usize {
+        self.root.height()
+    }
+
+    fn insert(&mut self, char_idx: usize, text: &str) {
+        assert!(char_idx <= self.len_chars(), "index out of bounds");
+        let (a, b) = split(&self.root, char_idx);
+        self.root = join(join(a, build(text)), b);
+    }
+
+    fn remove(&mut self, range: Range<usize>) {
+        assert!(
+            range.start <= range.end && range.end <= self.len_chars(),
+            "range out of bounds"
+        );
+        let (a, rest) = split(&self.root, range.start);
+        let (_, b) = split(&rest, range.end - range.start);
+        self.root = join(a, b);
+    }
+
+    // Shares the nodes of self
+    fn slice(&self, range: Range<usize>) -> Rope {
+        assert!(
+            range.start <= range.end && range.end <= self.len_chars(),
+            "range out of bounds"
+        );
+        let (_, rest) = split(&self.root, range.start);
+        let (mid, _) = split(&rest, range.end - range.start);
+        Rope { root: mid }
+    }
+
+    fn char(&self, mut char_idx: usize) -> Option<char> {
+        let mut node = &self.root;
+        loop {
+            match &**node {
+                Node::Leaf(s) => return s.chars().nth(char_idx),
+                Node::Branch { left, right, .. } => {
+                    let n = left.info().chars;
+                    if char_idx < n {
+                        node = left;
+                    } else {
+                        char_idx -= n;
+                        node = right;
+                    }
+                }
+            }
+        }
+    }
+
+    // Char index -> (line, column), both 0 based, column counted in chars
+    fn char_to_line_col(&self, char_idx: usize) -> (usize, usize) {
+        assert!(char_idx <= self.len_chars(), "index out of bounds");
+        let line = self.newlines_before(char_idx);
+        (line, char_idx - self.line_to_char(line))
+    }
+
+    // (line, column) -> char index
+    // None if the line does not exist or the column is past the end of the line
+    fn line_col_to_char(&self, line: usize, col: usize) -> Option<usize> {
+        if line >= self.len_lines() {
+            return None;
+        }
+        let start = self.line_to_char(line);
+        let end = if line + 1 < self.len_lines() {
+            // Before the '\n'
+            self.line_to_char(line + 1) - 1
+        } else {
+            self.len_chars()
+        };
+        (start + col <= end).then_some(start + col)
+    }
+
+    fn newlines_before(&self, mut char_idx: usize) -> usize {
+        let mut node = &self.root;
+        let mut count = 0;
+        loop {
+            match &**node {
+                Node::Leaf(s) => {
+                    return count + s.chars().take(char_idx).filter(|&c| c == '\n').count();
+                }
+                Node::Branch { left, right, .. } => {
+                    let info = left.info();
+                    if char_idx <= info.chars {
+                        node = left;
+                    } else {
+                        char_idx -= info.chars;
+                        count += info.newlines;
+                        node = right;
+                    }
+                }
+            }
+        }
+    }
+
+    // Char index of the start of a line
+    fn line_to_char(&self, mut line: usize) -> usize {
+        if line == 0 {
+            return 0;
+        }
+        let mut node = &self.root;
+        let mut chars = 0;
+        loop {
+            match &**node {
+                Node::Leaf(s) => {
+                    // Position after the line-th '\n' of this leaf
+                    let (i, _) = s
+                        .chars()
+                        .enumerate()
+                        .filter(|&(_, c)| c == '\n')
+                        .nth(line - 1)
+                        .expect("line out of bounds");
+                    return chars + i + 1;
+                }
+                Node::Branch { left, right, .. } => {
+                    let info = left.info();
+                    if line <= info.newlines {
+                        node = left;
+                    } else {
+                        line -= info.newlines;
+                        chars += info.chars;
+                        node = right;
+                    }
+                }
+            }
+        }
+    }
+
+    fn chunks(&self) -> Chunks<'_> {
+        Chunks {
+            stack: vec![&self.root],
+        }
+    }
+
+    fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.chunks().flat_map(str::chars)
+    }
+
+    // Lines without the '\n', len_lines() items
+    fn lines(&self) -> Lines<'_> {
+        Lines {
+            chars: Box::new(self.chars()),
+            done: false,
+        }
+    }
+
+    // Check the counts and the balance of every node
+    fn check(&self) {
+        fn check(node: &Node) -> (Info, usize) {
+            match node {
+                Node::Leaf(s) => (Info::of(s), 0),
+                Node::Branch {
+                    left,
+                    right,
+                    info,
+                    height,
+                } => {
+                    let (li, lh) = check(left);
+                    let (ri, rh) = check(right);
+                    assert_eq!(*info, li.add(ri));
+                    assert_eq!(*height, 1 + lh.max(rh));
+                    assert!(lh.abs_diff(rh) <= 1, "unbalanced {lh} {rh}");
+                    (*info, *height)
+                }
+            }
+        }
+        check(&self.root);
+    }
+}
+
+impl From<&str> for Rope {
+    fn from(s: &str) -> Self {
+        Self { root: build(s) }
+    }
+}
+
+impl fmt::Display for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for chunk in self.chunks() {
+            f.write_str(chunk)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Rope({:?})", self.to_string())
+    }
+}
+
+// Leaves from left to right, depth first with a stack
+struct Chunks<'a> {
+    stack: Vec<&'a Rc<Node>>,
+}
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        while let Some(node) = self.stack.pop() {
+            match &**node {
+                Node::Leaf(s) if s.is_empty() => {}
+                Node::Leaf(s) => return Some(s),
+                Node::Branch { left, right, .. } => {
+                    self.stack.push(right);
+                    self.stack.push(left);
+                }
+            }
+        }
+        None
+    }
+}
+
+struct Lines<'a> {
+    chars: Box<dyn Iterator<Item = char> + 'a>,
+    done: bool,
+}
+
+impl Iterator for Lines<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        if self.done {
+            return None;
+        }
+        let mut line = String::new();
+        for c in self.chars.by_ref() {
+            if c == '\n' {
+                return Some(line);
+            }
+            line.push(c);
+        }
+        // Last line, after the last '\n'
+        self.done = true;
+        Some(line)
+    }
+}
+
+// xorshift64
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    // 0..n
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+// Random edits on a Rope and a String, both must always be equal
+fn fuzz(seed: u64, steps: usize) {
+    const PIECES: [&str; 6] = ["a", "hello ", "é", "🦀", "\n", "line\nbreak "];
+    let mut rng = Rng(seed);
+    let mut rope = Rope::new();
+    let mut s = String::new();
+    // Old versions must not change
+    let mut snapshots: Vec<(Rope, String)> = Vec::new();
+
+    for step in 0..steps {
+        let len = s.chars().count();
+        match rng.below(10) {
+            0..=5 => {
+                let text: String = (0..rng.below(40))
+                    .map(|_| PIECES[rng.below(PIECES.len())])
+                    .collect();
+                let at = rng.below(len + 1);
+                rope.insert(at, &text);
+                s.insert_str(char_to_byte(&s, at), &text);
+            }
+            6..=8 => {
+                let start = rng.below(len + 1);
+                let end = start + rng.below(len - start + 1).min(30);
+                rope.remove(start..end);
+                s.replace_range(char_to_byte(&s, start)..char_to_byte(&s, end), "");
+            }
+            _ => snapshots.push((rope.clone(), s.clone())),
+        }
+        rope.check();
+        assert_eq!(rope.to_string(), s, "step {step}");
+        assert_eq!(rope.len_chars(), s.chars().count());
+        assert_eq!(rope.len_bytes(), s.len());
+
+        let len = s.chars().count();
+        let (a, b) = (rng.below(len + 1), rng.below(len + 1));
+        let (a, b) = (a.min(b), a.max(b));
+        let slice = rope.slice(a..b);
+        slice.check();
+        assert_eq!(
+            slice.to_string(),
+            &s[char_to_byte(&s, a)..char_to_byte(&s, b)]
+        );
+        assert_eq!(rope.char(a), s.chars().nth(a));
+
+        let lines: Vec<&str> = s.split('\n').collect();
+        assert_eq!(rope.lines().collect::<Vec<_>>(), lines);
+        let (line, col) = rope.char_to_line_col(a);
+        let prefix: String = s.chars().take(a).collect();
+        assert_eq!(line, prefix.matches('\n').count());
+        assert_eq!(rope.line_col_to_char(line, col), Some(a));
+        assert_eq!(rope.line_col_to_char(lines.len(), 0), None);
+    }
+
+    for (rope, s) in &snapshots {
+        assert_eq!(&rope.to_string(), s);
+    }
+}
+
+fn main() {
+    let mut rope = Rope::from("Hello Rust 🦀\nsecond line\nthird");
+    info!("{:?}", rope);
+    info!(
+        "chars = {}, bytes = {}, lines = {}",
+        rope.len_chars(),
+        rope.len_bytes(),
+        rope.len_lines()
+    );
+
+    rope.insert(6, "wonderful ");
+    rope.remove(0..6);
+    info!("{}", rope);
+    info!("slice = {:?}", rope.slice(10..16));
+    info!("char 15 = {:?}", rope.char(15));
+
+    for (i, line) in rope.lines().enumerate() {
+        info!("line {i}: {line}");
+    }
+    let idx = rope.line_col_to_char(1, 7).unwrap();
+    info!("line 1 col 7 = char {idx} {:?}", rope.char(idx));
+    info!("char {idx} = line, col {:?}", rope.char_to_line_col(idx));
+
+    // Clone shares every node, an edit copies only the path to the changed leaf
+    let text = "The quick brown fox jumps over the lazy dog. ".repeat(10_000);
+    let big = Rope::from(text.as_str());
+    let mut copy = big.clone();
+    info!(
+        "shared root: {}, height = {}, chunks = {}",
+        Rc::ptr_eq(&big.root, &copy.root),
+        big.height(),
+        big.chunks().count()
+    );
+    copy.insert(200_000, "EDIT");
+    info!(
+        "after insert: big {} chars, copy {} chars, height = {}",
+        big.len_chars(),
+        copy.len_chars(),
+        copy.height()
+    );
+    info!("{:?}", copy.slice(199_990..200_010));
+
+    for seed in 1..=20 {
+        fuzz(seed, 500);
+    }
+    info!("fuzz ok");
+}
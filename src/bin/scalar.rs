@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 // Scalar types represent a single value
 fn main() {
     // Signed integers
@@ -46,7 +48,8 @@ fn main() {
     // Type conversion
     let i: i32 = -1;
     let u: u32 = i as u32;
-    println!("i32: {} to u32: {}", i, u);
+    info!("i32: {} to u32: {}", i, u);
+    // convert.rs reports what a cast changed
 
     // Min and max
     let max = i32::MAX;
@@ -56,11 +59,11 @@ fn main() {
     let mut u: u32 = u32::MAX;
     u += 1;
     // Overflow doesn't panic when compiled with --release
-    println!("u32 silent overflow: {}", u);
+    info!("u32 silent overflow: {}", u);
 
     // Return None on overflow
-    println!("u32 check overflow: {:?}", u32::checked_add(u32::MAX, 1));
+    info!("u32 check overflow: {:?}", u32::checked_add(u32::MAX, 1));
 
     // Explicitly allow overflow
-    println!("u32 allow overflow: {}", u32::wrapping_add(u32::MAX, 1));
+    info!("u32 allow overflow: {}", u32::wrapping_add(u32::MAX, 1));
 }
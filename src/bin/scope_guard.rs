@@ -0,0 +1,125 @@
+#![allow(unused)]
+
+// Scope guard
+// - Run a closure when the guard goes out of scope (RAII, see trait_drop.rs)
+// - defer! macro
+// - dismiss to cancel
+// - Run only on unwind (panic) or only on success
+
+use hello_rust::info;
+use std::panic;
+use std::thread;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Strategy {
+    Always,
+    OnUnwind,
+    OnSuccess,
+}
+
+struct ScopeGuard<F: FnOnce()> {
+    // None after dismiss
+    f: Option<F>,
+    strategy: Strategy,
+}
+
+impl<F: FnOnce()> ScopeGuard<F> {
+    fn new(f: F) -> Self {
+        Self::with_strategy(f, Strategy::Always)
+    }
+
+    fn on_unwind(f: F) -> Self {
+        Self::with_strategy(f, Strategy::OnUnwind)
+    }
+
+    fn on_success(f: F) -> Self {
+        Self::with_strategy(f, Strategy::OnSuccess)
+    }
+
+    fn with_strategy(f: F, strategy: Strategy) -> Self {
+        Self {
+            f: Some(f),
+            strategy,
+        }
+    }
+
+    // Cancel the guard, the closure is returned without being called
+    fn dismiss(mut self) -> F {
+        self.f.take().unwrap()
+    }
+}
+
+impl<F: FnOnce()> Drop for ScopeGuard<F> {
+    fn drop(&mut self) {
+        let run = match self.strategy {
+            Strategy::Always => true,
+            Strategy::OnUnwind => thread::panicking(),
+            Strategy::OnSuccess => !thread::panicking(),
+        };
+        if let Some(f) = self.f.take() {
+            if run {
+                f();
+            }
+        }
+    }
+}
+
+// defer!(info!("done"));
+// Guards run in reverse order, like local variables are dropped
+macro_rules! defer {
+    ($($body:tt)*) => {
+        let _guard = ScopeGuard::new(|| { $($body)*; });
+    };
+}
+
+fn transfer(balance: &mut Vec<i32>, fail: bool) {
+    let before = balance.clone();
+    balance.push(-10);
+    // Roll back if anything below panics
+    let rollback = ScopeGuard::on_unwind(|| info!("rollback to {:?}", before));
+    if fail {
+        panic!("transfer failed");
+    }
+    balance.push(10);
+}
+
+fn main() {
+    {
+        defer!(info!("defer 1"));
+        defer!(info!("defer 2"));
+        info!("scope body");
+        // Prints "defer 2" then "defer 1"
+    }
+
+    // Dismiss
+    {
+        let guard = ScopeGuard::new(|| info!("never printed"));
+        guard.dismiss();
+    }
+
+    // Guard can capture mutable state
+    let mut log = Vec::new();
+    {
+        let _guard = ScopeGuard::new(|| log.push("cleanup"));
+    }
+    info!("{:?}", log);
+
+    // Only on success
+    {
+        let _guard = ScopeGuard::on_success(|| info!("committed"));
+    }
+
+    // Only on unwind
+    let mut balance = vec![100];
+    transfer(&mut balance, false);
+    info!("balance = {:?}", balance);
+
+    // Hide the panic message
+    panic::set_hook(Box::new(|_| {}));
+    let res = panic::catch_unwind(|| {
+        let mut balance = vec![100];
+        transfer(&mut balance, true);
+    });
+    let _ = panic::take_hook();
+    info!("panicked = {}", res.is_err());
+}
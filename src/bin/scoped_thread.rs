@@ -1,5 +1,6 @@
 #![allow(unused)]
 
+use hello_rust::info;
 use std::thread;
 
 // Scoped thread
@@ -12,28 +13,28 @@ fn main() {
 
     // Must move ownership of msg into thread
     thread::spawn(move || {
-        println!("thread: {:?}", msg);
+        info!("thread: {:?}", msg);
     });
 
     // This doesn't compile - ownership of msg transferred to thread above
-    // println!("main thread: {:?}", msg);
+    // info!("main thread: {:?}", msg);
 
     // Scoped threads can borrow
     let msg = "hello".to_string();
 
     thread::scope(|scope| {
-        println!("scored thread: {:?}", msg);
+        info!("scored thread: {:?}", msg);
     });
 
-    println!("main thread: {:?}", msg);
+    info!("main thread: {:?}", msg);
 
     // Auto join
     let t1 = thread::spawn(|| {
-        println!("thread 1");
+        info!("thread 1");
     });
 
     let t2 = thread::spawn(|| {
-        println!("thread 2");
+        info!("thread 2");
     });
 
     t1.join().unwrap();
@@ -41,11 +42,11 @@ fn main() {
 
     thread::scope(|scope| {
         scope.spawn(|| {
-            println!("scoped thread 1");
+            info!("scoped thread 1");
         });
 
         scope.spawn(|| {
-            println!("scoped thread 2");
+            info!("scoped thread 2");
         });
     });
 
@@ -58,5 +59,5 @@ fn main() {
         (t1.join().unwrap(), t2.join().unwrap())
     });
 
-    println!("{} {}", v1, v2);
+    info!("{} {}", v1, v2);
 }
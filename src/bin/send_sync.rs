@@ -1,5 +1,6 @@
 #![allow(unused)]
 
+use hello_rust::info;
 use std::cell::RefCell;
 use std::rc::Rc;
 use std::sync::{Arc, Mutex};
@@ -24,7 +25,7 @@ fn main() {
     // Send - String
     let s = "🦀".to_string();
     thread::spawn(move || {
-        println!("Send: {}", s);
+        info!("Send: {}", s);
     })
     .join()
     .unwrap();
@@ -36,7 +37,7 @@ fn main() {
     /*
     let rc = Rc::new(1);
     thread::spawn(move || {
-        println!("Not Send: {:?}", rc);
+        info!("Not Send: {:?}", rc);
     });
     */
 
@@ -45,7 +46,7 @@ fn main() {
     // It uses atomic operations
     let arc = Arc::new(1);
     thread::spawn(move || {
-        println!("Sync : {:?}", arc);
+        info!("Sync : {:?}", arc);
     })
     .join()
     .unwrap();
@@ -60,7 +61,7 @@ fn main() {
     let ref_cell = RefCell::new(1);
     let r = &ref_cell;
     thread::spawn(move || {
-        println!("Not Sync : {:?}", r);
+        info!("Not Sync : {:?}", r);
     });
     */
 }
@@ -0,0 +1,290 @@
+#![allow(unused)]
+
+// SmallVec
+// - Up to N elements are stored inline (array.rs), no heap allocation
+// - More than N elements spill to a Vec on the heap (vec.rs)
+// - Deref to a slice, so it can be used like a Vec
+
+use hello_rust::alloc_count::{count_allocations, CountingAlloc};
+use hello_rust::info;
+use std::fmt;
+use std::mem::{self, MaybeUninit};
+use std::ops::{Deref, DerefMut};
+use std::ptr;
+
+// Count heap allocations (src/alloc_count.rs)
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+enum Data<T, const N: usize> {
+    // First len slots are initialized
+    Inline {
+        buf: [MaybeUninit<T>; N],
+        len: usize,
+    },
+    Heap(Vec<T>),
+}
+
+struct SmallVec<T, const N: usize> {
+    data: Data<T, N>,
+}
+
+impl<T, const N: usize> SmallVec<T, N> {
+    fn new() -> Self {
+        Self {
+            data: Data::Inline {
+                buf: [const { MaybeUninit::uninit() }; N],
+                len: 0,
+            },
+        }
+    }
+
+    fn spilled(&self) -> bool {
+        matches!(self.data, Data::Heap(_))
+    }
+
+    fn capacity(&self) -> usize {
+        match &self.data {
+            Data::Inline { .. } => N,
+            Data::Heap(v) => v.capacity(),
+        }
+    }
+
+    // Move the inline elements to the heap
+    fn spill(&mut self, additional: usize) {
+        if let Data::Inline { buf, len } = &mut self.data {
+            let mut v = Vec::with_capacity((*len + additional).max(N * 2));
+            // Move out of buf, set len to 0 first so nothing is dropped twice
+            let n = mem::replace(len, 0);
+            for slot in &buf[..n] {
+                v.push(unsafe { slot.assume_init_read() });
+            }
+            self.data = Data::Heap(v);
+        }
+    }
+
+    // Room for additional more elements, spills if they don't fit inline
+    fn reserve(&mut self, additional: usize) {
+        match &mut self.data {
+            Data::Inline { len, .. } if *len + additional <= N => {}
+            Data::Inline { .. } => self.spill(additional),
+            Data::Heap(v) => v.reserve(additional),
+        }
+    }
+
+    fn push(&mut self, value: T) {
+        match &mut self.data {
+            Data::Inline { buf, len } if *len < N => {
+                buf[*len].write(value);
+                *len += 1;
+            }
+            Data::Inline { .. } => {
+                self.spill(1);
+                self.push(value);
+            }
+            Data::Heap(v) => v.push(value),
+        }
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        match &mut self.data {
+            Data::Inline { buf, len } => {
+                if *len == 0 {
+                    return None;
+                }
+                *len -= 1;
+                Some(unsafe { buf[*len].assume_init_read() })
+            }
+            Data::Heap(v) => v.pop(),
+        }
+    }
+
+    fn insert(&mut self, index: usize, value: T) {
+        assert!(index <= self.len(), "index out of bounds");
+        match &mut self.data {
+            Data::Inline { buf, len } if *len < N => unsafe {
+                let p = buf.as_mut_ptr().add(index);
+                ptr::copy(p, p.add(1), *len - index);
+                (*p).write(value);
+                *len += 1;
+            },
+            Data::Inline { .. } => {
+                self.spill(1);
+                self.insert(index, value);
+            }
+            Data::Heap(v) => v.insert(index, value),
+        }
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len(), "index out of bounds");
+        match &mut self.data {
+            Data::Inline { buf, len } => unsafe {
+                let p = buf.as_mut_ptr().add(index);
+                let value = (*p).assume_init_read();
+                *len -= 1;
+                ptr::copy(p.add(1), p, *len - index);
+                value
+            },
+            Data::Heap(v) => v.remove(index),
+        }
+    }
+
+    fn truncate(&mut self, new_len: usize) {
+        while self.len() > new_len {
+            self.pop();
+        }
+    }
+
+    fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    fn into_vec(mut self) -> Vec<T> {
+        self.spill(0);
+        match mem::replace(&mut self.data, Data::Heap(Vec::new())) {
+            Data::Heap(v) => v,
+            Data::Inline { .. } => unreachable!(),
+        }
+    }
+}
+
+impl<T, const N: usize> Drop for SmallVec<T, N> {
+    fn drop(&mut self) {
+        // Vec drops itself
+        if let Data::Inline { .. } = self.data {
+            self.clear();
+        }
+    }
+}
+
+impl<T, const N: usize> Deref for SmallVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        match &self.data {
+            Data::Inline { buf, len } => unsafe {
+                std::slice::from_raw_parts(buf.as_ptr() as *const T, *len)
+            },
+            Data::Heap(v) => v,
+        }
+    }
+}
+
+impl<T, const N: usize> DerefMut for SmallVec<T, N> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        match &mut self.data {
+            Data::Inline { buf, len } => unsafe {
+                std::slice::from_raw_parts_mut(buf.as_mut_ptr() as *mut T, *len)
+            },
+            Data::Heap(v) => v,
+        }
+    }
+}
+
+impl<T, const N: usize> Default for SmallVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for SmallVec<T, N> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for SmallVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const N: usize> Extend<T> for SmallVec<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for v in iter {
+            self.push(v);
+        }
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for SmallVec<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut out = Self::new();
+        out.extend(iter);
+        out
+    }
+}
+
+fn main() {
+    // Inline - no allocation
+    let (mut v, n) = count_allocations(|| {
+        let mut v: SmallVec<i32, 4> = SmallVec::new();
+        for i in 1..=3 {
+            v.push(i);
+        }
+        v.insert(0, 0);
+        v.remove(0);
+        v.push(4);
+        v
+    });
+    assert_eq!((n, v.spilled()), (0, false));
+    info!("{:?} spilled = {}", v, v.spilled());
+
+    // 5th element spills to the heap, 1 allocation
+    let ((), n) = count_allocations(|| v.push(5));
+    assert_eq!((n, v.spilled(), v.capacity()), (1, true, 8));
+    info!(
+        "{:?} spilled = {}, capacity = {}",
+        v,
+        v.spilled(),
+        v.capacity()
+    );
+
+    // After reserve, pushes up to the capacity don't allocate
+    let ((), n) = count_allocations(|| v.reserve(100));
+    assert_eq!(n, 1);
+    let cap = v.capacity();
+    let ((), n) = count_allocations(|| v.extend(6..=cap as i32));
+    assert_eq!((n, v.len(), v.capacity()), (0, cap, cap));
+
+    // reserve that fits inline does nothing, more spills once
+    let mut small: SmallVec<u64, 8> = SmallVec::new();
+    let ((), n) = count_allocations(|| {
+        small.reserve(8);
+        small.extend(0..8);
+    });
+    assert_eq!((n, small.spilled()), (0, false));
+    let ((), n) = count_allocations(|| {
+        small.reserve(24);
+        small.extend(8..32);
+    });
+    assert_eq!((n, small.capacity()), (1, 32));
+
+    // Slice methods through Deref
+    v.truncate(5);
+    v.reverse();
+    info!("reversed {:?}, contains 3: {}", v, v.contains(&3));
+    assert_eq!(&v[..], [5, 4, 3, 2, 1]);
+
+    // Drops inline Strings, moving them in does not allocate
+    let (a, b) = ("a".to_string(), "b".to_string());
+    let ((last, names), n) = count_allocations(|| {
+        let mut names: SmallVec<String, 2> = SmallVec::new();
+        names.push(a);
+        names.push(b);
+        (names.pop(), names)
+    });
+    assert_eq!(n, 0);
+    info!("pop = {:?}, left {:?}", last, names);
+
+    // Cloning inline u8s does not allocate, into_vec does
+    let nums: SmallVec<u8, 8> = (0..8).collect();
+    let (copy, n) = count_allocations(|| nums.clone());
+    assert_eq!((n, copy.spilled()), (0, false));
+    let (vec, n) = count_allocations(|| copy.into_vec());
+    assert_eq!(n, 1);
+    info!("into_vec = {:?}", vec);
+}
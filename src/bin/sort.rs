@@ -0,0 +1,439 @@
+#![allow(unused)]
+
+// Sorting and searching slices
+// - Every function takes &mut [T] and a comparator FnMut(&T, &T) -> Ordering
+// - split_at / split_at_mut (borrow_slice.rs) to work on each half
+// - Only safe code: elements are moved with swap and rotate, so if the
+//   comparator panics the slice is still a permutation of the input
+//   (nothing lost, nothing dropped twice)
+//
+// Property tests against slice::sort and panic safety tests, fixed seed so
+// every run checks the same inputs:
+// cargo run --bin sort             300 rounds, under 1s in debug
+// cargo run --bin sort -- 10000    more rounds
+
+use hello_rust::info;
+use std::cmp::Ordering;
+use std::panic::{self, AssertUnwindSafe};
+
+fn insertion_sort<T>(v: &mut [T], cmp: &mut impl FnMut(&T, &T) -> Ordering) {
+    for i in 1..v.len() {
+        let mut j = i;
+        // Strictly greater, so equal elements keep their order (stable)
+        while j > 0 && cmp(&v[j - 1], &v[j]) == Ordering::Greater {
+            v.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+// First index where v[i] >= x
+fn lower_bound_by<T>(v: &[T], mut f: impl FnMut(&T) -> Ordering) -> usize {
+    let (mut lo, mut hi) = (0, v.len());
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if f(&v[mid]) == Ordering::Less {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+// First index where v[i] > x
+fn upper_bound_by<T>(v: &[T], mut f: impl FnMut(&T) -> Ordering) -> usize {
+    let (mut lo, mut hi) = (0, v.len());
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if f(&v[mid]) == Ordering::Greater {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    lo
+}
+
+fn lower_bound<T: Ord>(v: &[T], x: &T) -> usize {
+    lower_bound_by(v, |e| e.cmp(x))
+}
+
+fn upper_bound<T: Ord>(v: &[T], x: &T) -> usize {
+    upper_bound_by(v, |e| e.cmp(x))
+}
+
+fn is_sorted_by<T>(v: &[T], mut cmp: impl FnMut(&T, &T) -> Ordering) -> bool {
+    v.windows(2).all(|w| cmp(&w[0], &w[1]) != Ordering::Greater)
+}
+
+// Merge sorted v[..mid] and v[mid..] in place, stable
+// Split both halves, rotate the middle, then merge each side
+fn merge<T>(v: &mut [T], mid: usize, cmp: &mut impl FnMut(&T, &T) -> Ordering) {
+    let len = v.len();
+    if mid == 0 || mid == len {
+        return;
+    }
+    if len == 2 {
+        if cmp(&v[0], &v[1]) == Ordering::Greater {
+            v.swap(0, 1);
+        }
+        return;
+    }
+    let (cut1, cut2) = if mid > len - mid {
+        let cut1 = mid / 2;
+        // Right elements equal to v[cut1] stay after it
+        let cut2 = mid + lower_bound_by(&v[mid..], |e| cmp(e, &v[cut1]));
+        (cut1, cut2)
+    } else {
+        let cut2 = mid + (len - mid) / 2;
+        // Left elements equal to v[cut2] stay before it
+        let cut1 = upper_bound_by(&v[..mid], |e| cmp(e, &v[cut2]));
+        (cut1, cut2)
+    };
+    v[cut1..cut2].rotate_left(mid - cut1);
+    let new_mid = cut1 + (cut2 - mid);
+    let (left, right) = v.split_at_mut(new_mid);
+    merge(left, cut1, cmp);
+    merge(right, cut2 - new_mid, cmp);
+}
+
+// Stable, O(n log^2 n) because the merge is in place
+fn merge_sort_by<T>(v: &mut [T], mut cmp: impl FnMut(&T, &T) -> Ordering) {
+    fn sort<T>(v: &mut [T], cmp: &mut impl FnMut(&T, &T) -> Ordering) {
+        if v.len() <= 16 {
+            insertion_sort(v, cmp);
+            return;
+        }
+        let mid = v.len() / 2;
+        let (left, right) = v.split_at_mut(mid);
+        sort(left, cmp);
+        sort(right, cmp);
+        merge(v, mid, cmp);
+    }
+    sort(v, &mut cmp);
+}
+
+fn sift_down<T>(v: &mut [T], mut root: usize, cmp: &mut impl FnMut(&T, &T) -> Ordering) {
+    loop {
+        let mut child = 2 * root + 1;
+        if child >= v.len() {
+            return;
+        }
+        if child + 1 < v.len() && cmp(&v[child], &v[child + 1]) == Ordering::Less {
+            child += 1;
+        }
+        if cmp(&v[root], &v[child]) != Ordering::Less {
+            return;
+        }
+        v.swap(root, child);
+        root = child;
+    }
+}
+
+// Unstable, O(n log n) worst case, no extra memory
+fn heapsort_by<T>(v: &mut [T], mut cmp: impl FnMut(&T, &T) -> Ordering) {
+    heapsort(v, &mut cmp);
+}
+
+fn heapsort<T>(v: &mut [T], cmp: &mut impl FnMut(&T, &T) -> Ordering) {
+    // Build a max heap
+    for i in (0..v.len() / 2).rev() {
+        sift_down(v, i, cmp);
+    }
+    // Move the max to the end, shrink the heap
+    for end in (1..v.len()).rev() {
+        v.swap(0, end);
+        sift_down(&mut v[..end], 0, cmp);
+    }
+}
+
+// Median of first, middle and last, moved to the end
+fn choose_pivot<T>(v: &mut [T], cmp: &mut impl FnMut(&T, &T) -> Ordering) {
+    let (a, b, c) = (0, v.len() / 2, v.len() - 1);
+    if cmp(&v[b], &v[a]) == Ordering::Less {
+        v.swap(a, b);
+    }
+    if cmp(&v[c], &v[b]) == Ordering::Less {
+        v.swap(b, c);
+        if cmp(&v[b], &v[a]) == Ordering::Less {
+            v.swap(a, b);
+        }
+    }
+    // Median is at b
+    v.swap(b, c);
+}
+
+// Lomuto partition around the last element, returns its final index
+fn partition_at_pivot<T>(v: &mut [T], cmp: &mut impl FnMut(&T, &T) -> Ordering) -> usize {
+    let last = v.len() - 1;
+    let mut store = 0;
+    for i in 0..last {
+        if cmp(&v[i], &v[last]) == Ordering::Less {
+            v.swap(i, store);
+            store += 1;
+        }
+    }
+    v.swap(store, last);
+    store
+}
+
+// 3 way partition around the last element, returns (lt, gt)
+// - v[..lt] < pivot, v[lt..gt] == pivot, v[gt..] > pivot
+// - Equal elements end up together, so many duplicates don't make it O(n^2)
+fn partition3_at_pivot<T>(v: &mut [T], cmp: &mut impl FnMut(&T, &T) -> Ordering) -> (usize, usize) {
+    let last = v.len() - 1;
+    let (mut lt, mut i, mut gt) = (0, 0, last);
+    // v[lt..i] == pivot, v[i..gt] not seen yet, v[gt..last] > pivot
+    while i < gt {
+        match cmp(&v[i], &v[last]) {
+            Ordering::Less => {
+                v.swap(lt, i);
+                lt += 1;
+                i += 1;
+            }
+            Ordering::Equal => i += 1,
+            Ordering::Greater => {
+                gt -= 1;
+                v.swap(i, gt);
+            }
+        }
+    }
+    v.swap(gt, last);
+    (lt, gt + 1)
+}
+
+// Quicksort, switches to heapsort when recursion is too deep (bad pivots)
+// and to insertion sort for short slices. Unstable.
+fn introsort_by<T>(v: &mut [T], mut cmp: impl FnMut(&T, &T) -> Ordering) {
+    fn sort<T>(mut v: &mut [T], depth: u32, cmp: &mut impl FnMut(&T, &T) -> Ordering) {
+        let mut depth = depth;
+        while v.len() > 16 {
+            if depth == 0 {
+                heapsort(v, cmp);
+                return;
+            }
+            depth -= 1;
+            choose_pivot(v, cmp);
+            let p = partition_at_pivot(v, cmp);
+            let (left, right) = v.split_at_mut(p);
+            // Recurse into the smaller side, loop on the larger one
+            if left.len() < right.len() {
+                sort(left, depth, cmp);
+                v = &mut right[1..];
+            } else {
+                sort(&mut right[1..], depth, cmp);
+                v = left;
+            }
+        }
+        insertion_sort(v, cmp);
+    }
+    let depth = 2 * (usize::BITS - v.len().leading_zeros());
+    sort(v, depth, &mut cmp);
+}
+
+// Move elements where pred is true to the front, returns how many
+fn partition<T>(v: &mut [T], mut pred: impl FnMut(&T) -> bool) -> usize {
+    let mut store = 0;
+    for i in 0..v.len() {
+        if pred(&v[i]) {
+            v.swap(i, store);
+            store += 1;
+        }
+    }
+    store
+}
+
+// Put the element that belongs at index k (if v were sorted) at k,
+// smaller or equal elements before it, greater or equal after it
+fn nth_element_by<T>(v: &mut [T], k: usize, mut cmp: impl FnMut(&T, &T) -> Ordering) {
+    assert!(k < v.len(), "k out of bounds");
+    let (mut lo, mut hi) = (0, v.len());
+    while hi - lo > 16 {
+        let s = &mut v[lo..hi];
+        choose_pivot(s, &mut cmp);
+        let (lt, gt) = partition3_at_pivot(s, &mut cmp);
+        if k < lo + lt {
+            hi = lo + lt;
+        } else if k >= lo + gt {
+            lo += gt;
+        } else {
+            return;
+        }
+    }
+    insertion_sort(&mut v[lo..hi], &mut cmp);
+}
+
+// xorshift64 for test data
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn vec(&mut self, len: usize, max: u64) -> Vec<u64> {
+        (0..len).map(|_| self.next() % max).collect()
+    }
+}
+
+// Compare every algorithm with slice::sort on random inputs
+fn property_test(rounds: usize) {
+    let mut rng = Rng(42);
+    for round in 0..rounds {
+        let len = (rng.next() % 500) as usize;
+        // Small max so that there are many duplicates
+        let max = 1 + rng.next() % 50;
+        // (key, original index) - equal keys must keep the index order to be stable
+        let input: Vec<(u64, usize)> = rng
+            .vec(len, max)
+            .into_iter()
+            .enumerate()
+            .map(|(i, k)| (k, i))
+            .collect();
+        let mut expected = input.clone();
+        expected.sort_by_key(|e| e.0);
+
+        let mut v = input.clone();
+        merge_sort_by(&mut v, |a, b| a.0.cmp(&b.0));
+        assert_eq!(v, expected, "merge sort round {round}");
+
+        let mut v = input.clone();
+        introsort_by(&mut v, |a, b| a.0.cmp(&b.0));
+        assert!(v.iter().map(|e| e.0).eq(expected.iter().map(|e| e.0)));
+
+        let mut v = input.clone();
+        heapsort_by(&mut v, |a, b| a.0.cmp(&b.0));
+        assert!(v.iter().map(|e| e.0).eq(expected.iter().map(|e| e.0)));
+
+        let mut v = input.clone();
+        insertion_sort(&mut v, &mut |a, b| a.0.cmp(&b.0));
+        assert_eq!(v, expected);
+
+        if len > 0 {
+            let k = (rng.next() % len as u64) as usize;
+            let mut v = input.clone();
+            nth_element_by(&mut v, k, |a, b| a.0.cmp(&b.0));
+            assert_eq!(v[k].0, expected[k].0);
+            assert!(v[..k].iter().all(|e| e.0 <= v[k].0));
+            assert!(v[k + 1..].iter().all(|e| e.0 >= v[k].0));
+        }
+
+        let mut v = input.clone();
+        let n = partition(&mut v, |e| e.0 % 2 == 0);
+        assert_eq!(n, input.iter().filter(|e| e.0 % 2 == 0).count());
+        assert!(v[..n].iter().all(|e| e.0 % 2 == 0));
+        assert!(v[n..].iter().all(|e| e.0 % 2 == 1));
+
+        assert!(is_sorted_by(&expected, |a, b| a.0.cmp(&b.0)));
+        assert_eq!(
+            is_sorted_by(&input, |a, b| a.0.cmp(&b.0)),
+            input.windows(2).all(|w| w[0].0 <= w[1].0)
+        );
+
+        let keys: Vec<u64> = expected.iter().map(|e| e.0).collect();
+        let x = rng.next() % max;
+        assert_eq!(lower_bound(&keys, &x), keys.partition_point(|&k| k < x));
+        assert_eq!(upper_bound(&keys, &x), keys.partition_point(|&k| k <= x));
+    }
+}
+
+// A comparator that panics after n calls must not lose or duplicate elements
+fn panic_safety_test() {
+    type Sort = fn(&mut [String], &mut dyn FnMut(&String, &String) -> Ordering);
+    let sorts: [(&str, Sort); 3] = [
+        ("merge", |v, cmp| merge_sort_by(v, cmp)),
+        ("intro", |v, cmp| introsort_by(v, cmp)),
+        ("heap", |v, cmp| heapsort_by(v, cmp)),
+    ];
+    panic::set_hook(Box::new(|_| {}));
+    for (name, sort) in sorts {
+        for limit in [1, 10, 100, 1000] {
+            let mut rng = Rng(7);
+            let mut v: Vec<String> = rng.vec(200, 1000).iter().map(|n| n.to_string()).collect();
+            let mut expected = v.clone();
+            expected.sort();
+            let mut calls = 0;
+            let res = panic::catch_unwind(AssertUnwindSafe(|| {
+                sort(&mut v, &mut |a, b| {
+                    calls += 1;
+                    if calls == limit {
+                        panic!("comparator panicked");
+                    }
+                    a.cmp(b)
+                })
+            }));
+            assert!(res.is_err(), "{name} should have panicked");
+            // Still a permutation of the input
+            v.sort();
+            assert_eq!(v, expected, "{name} lost elements");
+        }
+    }
+    let _ = panic::take_hook();
+}
+
+fn main() {
+    let mut a = [5, 3, 8, 1, 9, 2, 7];
+    merge_sort_by(&mut a, |x, y| x.cmp(y));
+    info!("merge sort {:?}", a);
+
+    // Sort descending
+    let mut a = [5, 3, 8, 1, 9, 2, 7];
+    introsort_by(&mut a, |x, y| y.cmp(x));
+    info!("introsort desc {:?}", a);
+
+    let mut a = [5, 3, 8, 1, 9, 2, 7];
+    heapsort_by(&mut a, |x, y| x.cmp(y));
+    info!("heapsort {:?}", a);
+
+    // Stable - equal lengths keep their order
+    let mut words = ["rust", "go", "c", "zig", "java", "d"];
+    merge_sort_by(&mut words, |a, b| a.len().cmp(&b.len()));
+    info!("by length {:?}", words);
+
+    let mut a = [5, 3, 8, 1, 9, 2, 7];
+    let n = partition(&mut a, |x| x % 2 == 0);
+    let (even, odd) = a.split_at(n);
+    info!("even {:?}, odd {:?}", even, odd);
+
+    let mut a = [5, 3, 8, 1, 9, 2, 7];
+    nth_element_by(&mut a, 3, |x, y| x.cmp(y));
+    info!("median = {}", a[3]);
+
+    // All equal or few distinct keys, 1 partition pass per distinct key at most
+    for max in [1, 2, 10] {
+        let n = 100_000;
+        let mut v = Rng(7).vec(n, max);
+        let mut comparisons = 0;
+        nth_element_by(&mut v, n / 2, |x, y| {
+            comparisons += 1;
+            x.cmp(y)
+        });
+        let mut expected = v.clone();
+        expected.sort();
+        assert_eq!(v[n / 2], expected[n / 2]);
+        assert!(
+            comparisons < 4 * n,
+            "max = {max}, {comparisons} comparisons"
+        );
+    }
+
+    let a = [1, 2, 2, 2, 3, 5];
+    info!(
+        "lower_bound(2) = {}, upper_bound(2) = {}",
+        lower_bound(&a, &2),
+        upper_bound(&a, &2)
+    );
+    info!("sorted: {}", is_sorted_by(&a, |x, y| x.cmp(y)));
+
+    let rounds = std::env::args()
+        .nth(1)
+        .map(|n| n.parse().expect("number of rounds"))
+        .unwrap_or(300);
+    property_test(rounds);
+    panic_safety_test();
+    info!("property and panic safety tests passed");
+}
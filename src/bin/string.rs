@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 // String = vector of u8 (Vec<u8>) valid UTF-8
 // &str = slice of u8 (&[u8]) valid UTF-8
 
@@ -10,7 +12,7 @@ fn main() {
     // String
     let msg: String = String::from("Hello Rust 🦀");
     let len: usize = msg.len();
-    println!("String length = {len}");
+    info!("String length = {len}");
 
     // str
     // - String slice
@@ -20,9 +22,12 @@ fn main() {
     let msg: String = String::from("Hello Rust 🦀");
     // String slice
     let s: &str = &msg[..5];
-    println!("slice = {s}");
+    info!("slice = {s}");
     let len: usize = s.len();
-    println!("slice length = {len}");
+    info!("slice length = {len}");
+    // Indexes are in bytes, &msg[..12] panics because byte 12 is inside 🦀
+    // get returns None instead, see text.rs for char and grapheme safe slicing
+    info!("get(..12) = {:?}", msg.get(..12));
 
     // String literal
     // - stored inside binary
@@ -37,22 +42,22 @@ fn main() {
           "d": 3
         }
     "#;
-    println!("{s}");
+    info!("{s}");
 
     // Deref coercion
     // Rust automatically dereferences &String into a &str
     let msg: String = String::from("Hello Rust 🦀");
     let s: &str = &msg;
-    println!("slice = {s}");
+    info!("slice = {s}");
 
     // Add str to string
     let mut msg = "Hello".to_string();
     msg += " Rust";
-    println!("{msg}");
+    info!("{msg}");
 
     // String interpolation - format!
     let lang = "Rust";
     let emoji = "🦀";
     let msg: String = format!("Hello {} {}", lang, emoji);
-    println!("{msg}");
+    info!("{msg}");
 }
@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 #[derive(Debug)]
 struct Point {
     x: f32,
@@ -22,13 +24,13 @@ struct Circle {
 fn main() {
     // Create
     let p = Point { x: 1.0, y: 1.0 };
-    println!("p.x = {}", p.x);
-    println!("p.y = {}", p.y);
+    info!("p.x = {}", p.x);
+    info!("p.y = {}", p.y);
     // Debug
-    println!("{:?}", p);
+    info!("{:?}", p);
 
     let p = Point3d(1, 2, 3);
-    println!("{} {} {}", p.0, p.1, p.2);
+    info!("{} {} {}", p.0, p.1, p.2);
 
     let empty = Empty;
 
@@ -36,7 +38,7 @@ fn main() {
         center: Point { x: 0.0, y: 0.0 },
         radius: 3,
     };
-    println!("{:?}", circle);
+    info!("{:?}", circle);
 
     // Shortcut
     let x = 1.0;
@@ -47,11 +49,11 @@ fn main() {
     let p0 = Point { x: 1.0, y: 2.0 };
     let p1 = Point { x: 2.0, ..p0 };
 
-    println!("{:?}", p1);
+    info!("{:?}", p1);
 
     // Update
     let mut p = Point { x: 0.0, y: 0.0 };
     p.x += 1.0;
     p.y += 1.0;
-    println!("{:?}", p);
+    info!("{:?}", p);
 }
@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 #[derive(Debug)]
 struct Point {
     x: f32,
@@ -32,5 +34,5 @@ fn main() {
     p.move_to(2.0, 0.0);
 
     let d = p.dist();
-    println!("{}", d);
+    info!("{}", d);
 }
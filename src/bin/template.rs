@@ -0,0 +1,903 @@
+#![allow(unused)]
+
+// Runtime template engine with the same syntax as format! (print.rs)
+// - format! strings are checked at compile time, templates are parsed at runtime,
+//   for example loaded from a file
+// - {} {0} {name} {{ }} and the format spec {:[[fill]align][sign][#][0][width][.precision][type]}
+// - width and precision can also come from an argument, {:1$} {:.prec$} {:.*}
+// - Errors point to the offset of the problem in the template
+// - Arguments are passed as &dyn TemplateArg
+
+use hello_rust::info;
+use std::env;
+use std::fmt;
+use std::fs;
+
+#[derive(Debug, Clone, PartialEq)]
+enum ArgRef {
+    Index(usize),
+    Name(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Count {
+    Is(usize),
+    Arg(ArgRef),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+// Format traits, {} {:?} {:x} {:X} {:o} {:b} {:e} {:E}
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Display,
+    Debug,
+    LowerHex,
+    UpperHex,
+    Octal,
+    Binary,
+    LowerExp,
+    UpperExp,
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let t = match self {
+            Kind::Display => "",
+            Kind::Debug => "?",
+            Kind::LowerHex => "x",
+            Kind::UpperHex => "X",
+            Kind::Octal => "o",
+            Kind::Binary => "b",
+            Kind::LowerExp => "e",
+            Kind::UpperExp => "E",
+        };
+        write!(f, "{{:{t}}}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Spec {
+    fill: char,
+    align: Option<Align>,
+    plus: bool,
+    alternate: bool,
+    zero: bool,
+    width: Option<Count>,
+    precision: Option<Count>,
+    kind: Kind,
+}
+
+impl Default for Spec {
+    fn default() -> Self {
+        Self {
+            fill: ' ',
+            align: None,
+            plus: false,
+            alternate: false,
+            zero: false,
+            width: None,
+            precision: None,
+            kind: Kind::Display,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Piece {
+    Literal(String),
+    Arg {
+        arg: ArgRef,
+        spec: Spec,
+        // Byte offset of the '{' in the template
+        offset: usize,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct TemplateError {
+    // Byte offset in the template
+    offset: usize,
+    message: String,
+}
+
+impl TemplateError {
+    fn new(offset: usize, message: impl Into<String>) -> Self {
+        Self {
+            offset,
+            message: message.into(),
+        }
+    }
+
+    // Message with the line of the template and a ^ under the offset
+    fn report(&self, src: &str) -> String {
+        let start = src[..self.offset].rfind('\n').map_or(0, |i| i + 1);
+        let end = src[self.offset..]
+            .find('\n')
+            .map_or(src.len(), |i| self.offset + i);
+        let line = src[..self.offset].matches('\n').count() + 1;
+        let col = src[start..self.offset].chars().count();
+        format!(
+            "error: {}\n --> {}:{}\n  | {}\n  | {}^",
+            self.message,
+            line,
+            col + 1,
+            &src[start..end],
+            " ".repeat(col)
+        )
+    }
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at offset {}", self.message, self.offset)
+    }
+}
+
+impl std::error::Error for TemplateError {}
+
+// Numbers can also be formatted with a sign, zero padding, {:x} {:b} {:e} ...
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Number {
+    // Value and size in bits, {:x} of a negative number prints its two's complement
+    Signed(i128, u32),
+    Unsigned(u128),
+    // f32 is not widened to f64, 0.1f32 as f64 prints 0.10000000149011612
+    F32(f32),
+    Float(f64),
+}
+
+trait TemplateArg {
+    // Output of {}, None if the value is not Display
+    fn display(&self) -> Option<String>;
+
+    // Output of {:?} or {:#?}, None if the value is not Debug
+    fn debug(&self, pretty: bool) -> Option<String>;
+
+    fn number(&self) -> Option<Number> {
+        None
+    }
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {$(
+        impl TemplateArg for $t {
+            fn display(&self) -> Option<String> {
+                Some(self.to_string())
+            }
+
+            fn debug(&self, _pretty: bool) -> Option<String> {
+                Some(self.to_string())
+            }
+
+            fn number(&self) -> Option<Number> {
+                Some(Number::Signed(*self as i128, <$t>::BITS))
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {$(
+        impl TemplateArg for $t {
+            fn display(&self) -> Option<String> {
+                Some(self.to_string())
+            }
+
+            fn debug(&self, _pretty: bool) -> Option<String> {
+                Some(self.to_string())
+            }
+
+            fn number(&self) -> Option<Number> {
+                Some(Number::Unsigned(*self as u128))
+            }
+        }
+    )*};
+}
+
+impl_signed!(i8, i16, i32, i64, i128, isize);
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+
+impl TemplateArg for f32 {
+    fn display(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+
+    fn debug(&self, _pretty: bool) -> Option<String> {
+        Some(format!("{:?}", self))
+    }
+
+    fn number(&self) -> Option<Number> {
+        Some(Number::F32(*self))
+    }
+}
+
+impl TemplateArg for f64 {
+    fn display(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+
+    fn debug(&self, _pretty: bool) -> Option<String> {
+        Some(format!("{:?}", self))
+    }
+
+    fn number(&self) -> Option<Number> {
+        Some(Number::Float(*self))
+    }
+}
+
+macro_rules! impl_display_debug {
+    ($($t:ty),*) => {$(
+        impl TemplateArg for $t {
+            fn display(&self) -> Option<String> {
+                Some(self.to_string())
+            }
+
+            fn debug(&self, _pretty: bool) -> Option<String> {
+                Some(format!("{:?}", self))
+            }
+        }
+    )*};
+}
+
+impl_display_debug!(str, String, char, bool);
+
+impl<T: TemplateArg + ?Sized> TemplateArg for &T {
+    fn display(&self) -> Option<String> {
+        (**self).display()
+    }
+
+    fn debug(&self, pretty: bool) -> Option<String> {
+        (**self).debug(pretty)
+    }
+
+    fn number(&self) -> Option<Number> {
+        (**self).number()
+    }
+}
+
+// Any Display value, Show(&value)
+struct Show<T>(T);
+
+impl<T: fmt::Display> TemplateArg for Show<T> {
+    fn display(&self) -> Option<String> {
+        Some(self.0.to_string())
+    }
+
+    fn debug(&self, _pretty: bool) -> Option<String> {
+        None
+    }
+}
+
+// Any Debug value, Dbg(&value)
+struct Dbg<T>(T);
+
+impl<T: fmt::Debug> TemplateArg for Dbg<T> {
+    fn display(&self) -> Option<String> {
+        None
+    }
+
+    fn debug(&self, pretty: bool) -> Option<String> {
+        Some(if pretty {
+            format!("{:#?}", self.0)
+        } else {
+            format!("{:?}", self.0)
+        })
+    }
+}
+
+#[derive(Default)]
+struct Args<'a> {
+    positional: Vec<&'a dyn TemplateArg>,
+    named: Vec<(&'a str, &'a dyn TemplateArg)>,
+}
+
+impl<'a> Args<'a> {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn arg(mut self, value: &'a dyn TemplateArg) -> Self {
+        self.positional.push(value);
+        self
+    }
+
+    fn named(mut self, name: &'a str, value: &'a dyn TemplateArg) -> Self {
+        self.named.push((name, value));
+        self
+    }
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+    // Index of the next implicit positional argument {}
+    next_arg: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.src[self.pos..].chars().next()
+    }
+
+    fn peek2(&self) -> Option<char> {
+        self.src[self.pos..].chars().nth(1)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn error<T>(&self, message: impl Into<String>) -> Result<T, TemplateError> {
+        Err(TemplateError::new(self.pos, message))
+    }
+
+    fn integer(&mut self) -> Result<Option<usize>, TemplateError> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Ok(None);
+        }
+        match self.src[start..self.pos].parse() {
+            Ok(n) => Ok(Some(n)),
+            Err(_) => Err(TemplateError::new(start, "number is too large")),
+        }
+    }
+
+    fn identifier(&mut self) -> Option<&'a str> {
+        let start = self.pos;
+        if !self.peek().is_some_and(|c| c.is_alphabetic() || c == '_') {
+            return None;
+        }
+        while self.peek().is_some_and(|c| c.is_alphanumeric() || c == '_') {
+            self.bump();
+        }
+        Some(&self.src[start..self.pos])
+    }
+
+    // 0, name or nothing
+    fn arg_ref(&mut self) -> Result<Option<ArgRef>, TemplateError> {
+        if let Some(i) = self.integer()? {
+            return Ok(Some(ArgRef::Index(i)));
+        }
+        Ok(self.identifier().map(|s| ArgRef::Name(s.to_string())))
+    }
+
+    // 5, 1$ or name$
+    // Returns None and rewinds if there is no count, "x" in {:x} is a type
+    fn count(&mut self) -> Result<Option<Count>, TemplateError> {
+        let start = self.pos;
+        let Some(arg) = self.arg_ref()? else {
+            return Ok(None);
+        };
+        if self.eat('$') {
+            return Ok(Some(Count::Arg(arg)));
+        }
+        match arg {
+            ArgRef::Index(n) => Ok(Some(Count::Is(n))),
+            ArgRef::Name(_) => {
+                self.pos = start;
+                Ok(None)
+            }
+        }
+    }
+
+    fn spec(&mut self) -> Result<Spec, TemplateError> {
+        let mut spec = Spec::default();
+        let align = |c| match c {
+            Some('<') => Some(Align::Left),
+            Some('^') => Some(Align::Center),
+            Some('>') => Some(Align::Right),
+            _ => None,
+        };
+        if let Some(a) = align(self.peek2()) {
+            spec.fill = self.bump().unwrap();
+            spec.align = Some(a);
+            self.bump();
+        } else if let Some(a) = align(self.peek()) {
+            spec.align = Some(a);
+            self.bump();
+        }
+        if self.eat('+') {
+            spec.plus = true;
+        } else if self.eat('-') {
+            // Accepted but unused, like in std
+        }
+        spec.alternate = self.eat('#');
+        // 0$ is a width argument, not the 0 flag
+        if self.peek() == Some('0') && self.peek2() != Some('$') {
+            self.bump();
+            spec.zero = true;
+        }
+        spec.width = self.count()?;
+        if self.eat('.') {
+            spec.precision = if self.eat('*') {
+                // Precision is the next positional argument, before the value
+                self.next_arg += 1;
+                Some(Count::Arg(ArgRef::Index(self.next_arg - 1)))
+            } else {
+                match self.count()? {
+                    Some(c) => Some(c),
+                    None => return self.error("expected precision after `.`"),
+                }
+            };
+        }
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c != '}') {
+            self.bump();
+        }
+        spec.kind = match &self.src[start..self.pos] {
+            "" => Kind::Display,
+            "?" => Kind::Debug,
+            "x" => Kind::LowerHex,
+            "X" => Kind::UpperHex,
+            "o" => Kind::Octal,
+            "b" => Kind::Binary,
+            "e" => Kind::LowerExp,
+            "E" => Kind::UpperExp,
+            t => {
+                return Err(TemplateError::new(
+                    start,
+                    format!("unknown format type `{t}`"),
+                ))
+            }
+        };
+        Ok(spec)
+    }
+
+    // After the '{' at offset
+    fn placeholder(&mut self, offset: usize) -> Result<Piece, TemplateError> {
+        let arg = self.arg_ref()?;
+        let spec = if self.eat(':') {
+            self.spec()?
+        } else {
+            Spec::default()
+        };
+        match self.peek() {
+            Some('}') => self.pos += 1,
+            None => {
+                return Err(TemplateError::new(
+                    offset,
+                    "unterminated placeholder, expected `}`",
+                ))
+            }
+            Some(c) => return self.error(format!("invalid character `{c}`, expected `}}`")),
+        }
+        let arg = arg.unwrap_or_else(|| {
+            self.next_arg += 1;
+            ArgRef::Index(self.next_arg - 1)
+        });
+        Ok(Piece::Arg { arg, spec, offset })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Template {
+    pieces: Vec<Piece>,
+}
+
+impl Template {
+    fn parse(src: &str) -> Result<Self, TemplateError> {
+        let mut p = Parser {
+            src,
+            pos: 0,
+            next_arg: 0,
+        };
+        let mut pieces = Vec::new();
+        let mut literal = String::new();
+        while let Some(c) = p.peek() {
+            let offset = p.pos;
+            p.bump();
+            match c {
+                '{' if p.eat('{') => literal.push('{'),
+                '}' if p.eat('}') => literal.push('}'),
+                '{' => {
+                    if !literal.is_empty() {
+                        pieces.push(Piece::Literal(std::mem::take(&mut literal)));
+                    }
+                    pieces.push(p.placeholder(offset)?);
+                }
+                '}' => {
+                    return Err(TemplateError::new(
+                        offset,
+                        "unmatched `}`, use `}}` for a literal `}`",
+                    ))
+                }
+                c => literal.push(c),
+            }
+        }
+        if !literal.is_empty() {
+            pieces.push(Piece::Literal(literal));
+        }
+        Ok(Self { pieces })
+    }
+
+    fn render(&self, args: &Args) -> Result<String, TemplateError> {
+        let mut out = String::new();
+        for piece in &self.pieces {
+            match piece {
+                Piece::Literal(s) => out.push_str(s),
+                Piece::Arg { arg, spec, offset } => {
+                    let err = |m: String| TemplateError::new(*offset, m);
+                    let value = lookup(args, arg).map_err(err)?;
+                    let width = resolve(args, &spec.width).map_err(err)?;
+                    let precision = resolve(args, &spec.precision).map_err(err)?;
+                    out.push_str(&format_value(value, spec, width, precision).map_err(err)?);
+                }
+            }
+        }
+        Ok(out)
+    }
+}
+
+fn lookup<'a>(args: &Args<'a>, arg: &ArgRef) -> Result<&'a dyn TemplateArg, String> {
+    match arg {
+        ArgRef::Index(i) => args
+            .positional
+            .get(*i)
+            .copied()
+            .ok_or_else(|| format!("missing positional argument {i}")),
+        ArgRef::Name(name) => args
+            .named
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|&(_, v)| v)
+            .ok_or_else(|| format!("missing argument `{name}`")),
+    }
+}
+
+fn resolve(args: &Args, count: &Option<Count>) -> Result<Option<usize>, String> {
+    match count {
+        None => Ok(None),
+        Some(Count::Is(n)) => Ok(Some(*n)),
+        Some(Count::Arg(arg)) => {
+            let n = match lookup(args, arg)?.number() {
+                Some(Number::Unsigned(n)) => usize::try_from(n).ok(),
+                Some(Number::Signed(n, _)) => usize::try_from(n).ok(),
+                _ => None,
+            };
+            n.map(Some)
+                .ok_or_else(|| "width and precision arguments must be usize".to_string())
+        }
+    }
+}
+
+// 1234 -> 1.234e3, exact for integers
+// A precision rounds half to even like std, {:.2e} of 1234 -> 1.23e3
+fn int_exp(digits: &str, precision: Option<usize>, upper: bool) -> String {
+    let mut exp = digits.len() - 1;
+    let mantissa = match precision {
+        None => digits.trim_end_matches('0').to_string(),
+        Some(p) if p < exp => {
+            let (keep, dropped) = digits.split_at(p + 1);
+            let half = format!("5{}", "0".repeat(dropped.len() - 1));
+            let odd = keep.ends_with(['1', '3', '5', '7', '9']);
+            let up = match dropped.cmp(&half) {
+                std::cmp::Ordering::Greater => true,
+                std::cmp::Ordering::Equal => odd,
+                std::cmp::Ordering::Less => false,
+            };
+            let mut m = keep.to_string();
+            if up {
+                // keep has at most 39 digits, + 1 fits in u128
+                m = (keep.parse::<u128>().unwrap() + 1).to_string();
+                // 9.99 -> 10.00, one more digit than asked for
+                if m.len() > keep.len() {
+                    m.pop();
+                    exp += 1;
+                }
+            }
+            m
+        }
+        Some(p) => format!("{digits:0<width$}", width = p + 1),
+    };
+    let e = if upper { 'E' } else { 'e' };
+    let (first, rest) = mantissa.split_at(1);
+    if rest.is_empty() {
+        format!("{first}{e}{exp}")
+    } else {
+        format!("{first}.{rest}{e}{exp}")
+    }
+}
+
+// Digits of a float, formatted as its own type
+fn float_digits<F>(a: F, kind: Kind, precision: Option<usize>) -> String
+where
+    F: fmt::Display + fmt::Debug + fmt::LowerExp + fmt::UpperExp,
+{
+    match (kind, precision) {
+        (Kind::LowerExp, None) => format!("{a:e}"),
+        (Kind::LowerExp, Some(p)) => format!("{a:.p$e}"),
+        (Kind::UpperExp, None) => format!("{a:E}"),
+        (Kind::UpperExp, Some(p)) => format!("{a:.p$E}"),
+        (_, Some(p)) => format!("{a:.p$}"),
+        (Kind::Debug, None) => format!("{a:?}"),
+        (_, None) => format!("{a}"),
+    }
+}
+
+// (negative, prefix, digits) of a number
+fn format_number(
+    n: Number,
+    kind: Kind,
+    alternate: bool,
+    precision: Option<usize>,
+) -> Result<(bool, &'static str, String), String> {
+    let radix = |u: u128| -> (&'static str, String) {
+        match kind {
+            Kind::LowerHex => ("0x", format!("{u:x}")),
+            Kind::UpperHex => ("0x", format!("{u:X}")),
+            Kind::Octal => ("0o", format!("{u:o}")),
+            _ => ("0b", format!("{u:b}")),
+        }
+    };
+    let upper = kind == Kind::UpperExp;
+    let (negative, prefix, digits) = match (n, kind) {
+        (
+            Number::F32(_) | Number::Float(_),
+            Kind::LowerHex | Kind::UpperHex | Kind::Octal | Kind::Binary,
+        ) => {
+            return Err(format!("`{kind}` requires an integer"));
+        }
+        (Number::F32(f), _) => (
+            f.is_sign_negative() && !f.is_nan(),
+            "",
+            float_digits(f.abs(), kind, precision),
+        ),
+        (Number::Float(f), _) => (
+            f.is_sign_negative() && !f.is_nan(),
+            "",
+            float_digits(f.abs(), kind, precision),
+        ),
+        (Number::Signed(v, bits), Kind::LowerHex | Kind::UpperHex | Kind::Octal | Kind::Binary) => {
+            let mask = if bits == 128 {
+                u128::MAX
+            } else {
+                (1 << bits) - 1
+            };
+            let (p, s) = radix(v as u128 & mask);
+            (false, p, s)
+        }
+        (Number::Unsigned(u), Kind::LowerHex | Kind::UpperHex | Kind::Octal | Kind::Binary) => {
+            let (p, s) = radix(u);
+            (false, p, s)
+        }
+        (Number::Signed(v, _), Kind::LowerExp | Kind::UpperExp) => (
+            v < 0,
+            "",
+            int_exp(&v.unsigned_abs().to_string(), precision, upper),
+        ),
+        (Number::Unsigned(u), Kind::LowerExp | Kind::UpperExp) => {
+            (false, "", int_exp(&u.to_string(), precision, upper))
+        }
+        (Number::Signed(v, _), _) => (v < 0, "", v.unsigned_abs().to_string()),
+        (Number::Unsigned(u), _) => (false, "", u.to_string()),
+    };
+    Ok((negative, if alternate { prefix } else { "" }, digits))
+}
+
+fn format_value(
+    value: &dyn TemplateArg,
+    spec: &Spec,
+    width: Option<usize>,
+    precision: Option<usize>,
+) -> Result<String, String> {
+    let is_number = |k| matches!(k, Kind::Display | Kind::Debug) && value.number().is_some();
+    let (sign, prefix, body) = match (value.number(), spec.kind) {
+        (Some(n), kind) => {
+            let (negative, prefix, digits) = format_number(n, kind, spec.alternate, precision)?;
+            let sign = if negative {
+                "-"
+            } else if spec.plus {
+                "+"
+            } else {
+                ""
+            };
+            (sign, prefix, digits)
+        }
+        (None, Kind::Display) => {
+            let s = value
+                .display()
+                .ok_or("value is not Display, use {:?} or Show(..)")?;
+            // Precision truncates strings
+            let s = match precision {
+                Some(p) => s.chars().take(p).collect(),
+                None => s,
+            };
+            ("", "", s)
+        }
+        (None, Kind::Debug) => {
+            let s = value
+                .debug(spec.alternate)
+                .ok_or("value is not Debug, use {} or Dbg(..)")?;
+            ("", "", s)
+        }
+        (None, kind) => return Err(format!("`{kind}` requires a number")),
+    };
+
+    let len = sign.chars().count() + prefix.len() + body.chars().count();
+    let pad = width.unwrap_or(0).saturating_sub(len);
+    let numeric = value.number().is_some();
+    // 0 flag pads with zeros after the sign and prefix, fill and align are ignored
+    if numeric && spec.zero {
+        return Ok(format!("{sign}{prefix}{}{body}", "0".repeat(pad)));
+    }
+    let align = spec
+        .align
+        .unwrap_or(if numeric { Align::Right } else { Align::Left });
+    let (left, right) = match align {
+        Align::Left => (0, pad),
+        Align::Center => (pad / 2, pad - pad / 2),
+        Align::Right => (pad, 0),
+    };
+    let fill = |n| spec.fill.to_string().repeat(n);
+    Ok(format!("{}{sign}{prefix}{body}{}", fill(left), fill(right)))
+}
+
+#[derive(Debug)]
+struct Lang {
+    language: String,
+    version: String,
+}
+
+// Render the template and compare with the output of format!
+fn check(template: &str, args: &Args, expected: String) {
+    let out = Template::parse(template)
+        .and_then(|t| t.render(args))
+        .unwrap_or_else(|e| panic!("{}", e.report(template)));
+    assert_eq!(out, expected, "template {template:?}");
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let path = env::args()
+        .nth(1)
+        .unwrap_or("data/template.txt".to_string());
+    let src = fs::read_to_string(&path)?;
+    let template = Template::parse(&src).map_err(|e| e.report(&src))?;
+    let args = Args::new()
+        .arg(&"Report")
+        .named("name", &"Rust")
+        .named("count", &42u8)
+        .named("item", &"coffee")
+        .named("price", &4.5)
+        .named("width", &12usize)
+        .named("emoji", &'🦀');
+    // info! adds the last newline
+    let text = template.render(&args).map_err(|e| e.report(&src))?;
+    info!("{}", text.strip_suffix('\n').unwrap_or(&text));
+
+    // Same output as format!
+    let pi = 12.34567;
+    let lang = "rust";
+    let x = 2;
+    let rust = Lang {
+        language: "rust".to_string(),
+        version: "1.80".to_string(),
+    };
+    let args = Args::new().arg(&x).arg(&4).named("lang", &lang);
+    check("hello {lang}!", &args, format!("hello {lang}!"));
+    check(
+        "{0} x {0} = {1}",
+        &args,
+        format!("{0} x {0} = {1}", x, x * x),
+    );
+    check("{} {} {{}} }}", &args, format!("{} {} {{}} }}", x, 4));
+    let rust = Dbg(&rust);
+    let args = Args::new().arg(&rust);
+    check("{:?}", &args, format!("{:?}", rust.0));
+    check("{:#?}", &args, format!("{:#?}", rust.0));
+
+    let args = Args::new().arg(&pi).arg(&-7i32).arg(&"abc").arg(&255u8);
+    check("[{0:>10.3}]", &args, format!("[{:>10.3}]", pi));
+    check("[{0:*^+12.2}]", &args, format!("[{:*^+12.2}]", pi));
+    check("[{0:e}] [{0:.2E}]", &args, format!("[{0:e}] [{0:.2E}]", pi));
+    check(
+        "[{1:05}] [{1:+}] [{1:<5}]",
+        &args,
+        format!("[{0:05}] [{0:+}] [{0:<5}]", -7),
+    );
+    check(
+        "[{1:x}] [{1:#b}]",
+        &args,
+        format!("[{0:x}] [{0:#b}]", -7i32),
+    );
+    check(
+        "[{2:>6}] [{2:-<6}] [{2:.2}] [{2:?}]",
+        &args,
+        format!("[{0:>6}] [{0:-<6}] [{0:.2}] [{0:?}]", "abc"),
+    );
+    check(
+        "[{3:#x}] [{3:#010b}] [{3:o}] [{3:#X}]",
+        &args,
+        format!("[{0:#x}] [{0:#010b}] [{0:o}] [{0:#X}]", 255u8),
+    );
+    check("[{3:e}] [{:?}]", &args, format!("[{:e}] [{:?}]", 255u8, pi));
+
+    // f32 is formatted as f32, not as the f64 it widens to
+    let args = Args::new()
+        .arg(&0.1f32)
+        .arg(&(1.0f32 / 3.0))
+        .arg(&-1234.5f32);
+    check(
+        "[{0}] [{0:?}] [{0:e}] [{1}] [{1:.3}] [{2:.2e}] [{2:+010.1}]",
+        &args,
+        format!(
+            "[{0}] [{0:?}] [{0:e}] [{1}] [{1:.3}] [{2:.2e}] [{2:+010.1}]",
+            0.1f32,
+            1.0f32 / 3.0,
+            -1234.5f32
+        ),
+    );
+    // Integer {:e} with a precision rounds half to even
+    for n in [0u64, 15, 25, 95, 135, 1234, 9950, 9999, 1250001, u64::MAX] {
+        let neg = -(n as i128);
+        let args = Args::new().arg(&n).arg(&neg);
+        check(
+            "[{0:.0e}] [{0:.1e}] [{0:.2E}] [{0:.5e}] [{1:.1e}]",
+            &args,
+            format!("[{0:.0e}] [{0:.1e}] [{0:.2E}] [{0:.5e}] [{1:.1e}]", n, neg),
+        );
+    }
+
+    let w = 8;
+    let p = 2;
+    let args = Args::new().arg(&pi).arg(&w).named("p", &p);
+    check(
+        "[{0:1$.p$}] [{:>1$}]",
+        &args,
+        format!("[{0:1$.p$}] [{:>1$}]", pi, w),
+    );
+    let args = Args::new().arg(&3).arg(&pi).arg(&1.0);
+    check(
+        "[{:.*}] [{:?}]",
+        &args,
+        format!("[{:.*}] [{:?}]", 3, pi, 1.0),
+    );
+    let args = Args::new().arg(&Show(std::net::Ipv4Addr::LOCALHOST));
+    check(
+        "[{:>12}]",
+        &args,
+        format!("[{:>12}]", std::net::Ipv4Addr::LOCALHOST),
+    );
+    info!("all templates match format!");
+
+    // Errors point to the offset in the template
+    let v = Dbg(vec![1, 2]);
+    let args = Args::new().arg(&"a").named("name", &v);
+    for src in [
+        "Hello {name",
+        "a } b",
+        "{:q}",
+        "{0:>8.}",
+        "{0!}",
+        "{missing}",
+        "{} {}",
+        "{:x}",
+        "{name}",
+        "line 1\n  width {:w$}",
+    ] {
+        let err = Template::parse(src)
+            .and_then(|t| t.render(&args))
+            .unwrap_err();
+        info!("{}\n", err.report(src));
+    }
+    Ok(())
+}
@@ -0,0 +1,362 @@
+#![allow(unused)]
+
+use hello_rust::info;
+
+// Char and grapheme cluster safe string utilities
+// - &s[..n] slices by byte and panics inside a multi byte char (string.rs)
+// - A char is a unicode scalar value, 1 to 4 bytes in UTF-8
+// - A grapheme cluster is what a user sees as 1 character
+//   e + ◌́ = é (2 chars), 👨‍👩‍👧 (5 chars), 🇯🇵 (2 chars)
+// - Grapheme segmentation follows the rules of UAX #29
+//
+// The property tables are generated into src/unicode_tables.rs by
+// scripts/gen_unicode_tables.py, the header says where the data came from.
+// data/grapheme_break_test.txt has the test lines of GraphemeBreakTest.txt,
+// rebuilt from unicode-segmentation 1.13.3 (see its header), every line is checked.
+
+mod text {
+    use std::borrow::Cow;
+    use std::cmp::Ordering;
+
+    use hello_rust::unicode_tables::{
+        Gcb, InCb, EAST_ASIAN_WIDE, EXTENDED_PICTOGRAPHIC, GRAPHEME_BREAK, INDIC_CONJUNCT_BREAK,
+    };
+    use Gcb::*;
+
+    // Binary search for the range that contains c
+    fn find<T>(table: &[T], c: char, range: impl Fn(&T) -> (u32, u32)) -> Option<&T> {
+        let c = c as u32;
+        table
+            .binary_search_by(|e| {
+                let (first, last) = range(e);
+                if last < c {
+                    Ordering::Less
+                } else if first > c {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .ok()
+            .map(|i| &table[i])
+    }
+
+    fn in_table(table: &[(u32, u32)], c: char) -> bool {
+        find(table, c, |&r| r).is_some()
+    }
+
+    fn gcb(c: char) -> Gcb {
+        // Hangul syllables, LV every 28 code points
+        if ('\u{AC00}'..='\u{D7A3}').contains(&c) {
+            return if (c as u32 - 0xAC00).is_multiple_of(28) {
+                LV
+            } else {
+                LVT
+            };
+        }
+        find(GRAPHEME_BREAK, c, |&(first, last, _)| (first, last)).map_or(Other, |e| e.2)
+    }
+
+    fn is_pictographic(c: char) -> bool {
+        in_table(EXTENDED_PICTOGRAPHIC, c)
+    }
+
+    fn incb(c: char) -> Option<InCb> {
+        find(INDIC_CONJUNCT_BREAK, c, |&(first, last, _)| (first, last)).map(|e| e.2)
+    }
+
+    // State of the current cluster, needed by the rules that look further back
+    // than the previous char (GB9c, GB11, GB12, GB13)
+    #[derive(Debug, Default)]
+    struct State {
+        // GB11 - ExtPict Extend* seen, then ZWJ
+        pictographic: bool,
+        pictographic_zwj: bool,
+        // GB12 GB13 - number of regional indicators in a row
+        regional: usize,
+        // GB9c - Consonant [Extend Linker]* seen, and if a Linker was among them
+        consonant: bool,
+        linker: bool,
+    }
+
+    impl State {
+        fn push(&mut self, c: char, prop: Gcb) {
+            self.pictographic_zwj = self.pictographic && prop == ZWJ;
+            self.pictographic = is_pictographic(c) || (self.pictographic && prop == Extend);
+            self.regional = if prop == RegionalIndicator {
+                self.regional + 1
+            } else {
+                0
+            };
+            match incb(c) {
+                Some(InCb::Consonant) => {
+                    self.consonant = true;
+                    self.linker = false;
+                }
+                Some(InCb::Linker) if self.consonant => self.linker = true,
+                Some(InCb::Extend) if self.consonant => {}
+                _ => {
+                    self.consonant = false;
+                    self.linker = false;
+                }
+            }
+        }
+
+        // Is there a boundary between prev and c
+        fn is_break(&self, prev: Gcb, c: char, prop: Gcb) -> bool {
+            match (prev, prop) {
+                // GB3
+                (CR, LF) => false,
+                // GB4 GB5
+                (CR | LF | Control, _) | (_, CR | LF | Control) => true,
+                // GB6 GB7 GB8
+                (L, L | V | LV | LVT) | (LV | V, V | T) | (LVT | T, T) => false,
+                // GB9 GB9a GB9b
+                (_, Extend | ZWJ | SpacingMark) | (Prepend, _) => false,
+                // GB9c
+                _ if self.linker && incb(c) == Some(InCb::Consonant) => false,
+                // GB11
+                _ if self.pictographic_zwj && is_pictographic(c) => false,
+                // GB12 GB13
+                (RegionalIndicator, RegionalIndicator) => self.regional.is_multiple_of(2),
+                // GB999
+                _ => true,
+            }
+        }
+    }
+
+    pub struct Graphemes<'a> {
+        s: &'a str,
+    }
+
+    impl<'a> Iterator for Graphemes<'a> {
+        type Item = &'a str;
+
+        fn next(&mut self) -> Option<&'a str> {
+            let mut chars = self.s.char_indices();
+            let (_, first) = chars.next()?;
+            let mut prev = gcb(first);
+            let mut state = State::default();
+            state.push(first, prev);
+            // GB2 - break at the end of text
+            let mut end = self.s.len();
+            for (i, c) in chars {
+                let prop = gcb(c);
+                if state.is_break(prev, c, prop) {
+                    end = i;
+                    break;
+                }
+                state.push(c, prop);
+                prev = prop;
+            }
+            let (g, rest) = self.s.split_at(end);
+            self.s = rest;
+            Some(g)
+        }
+    }
+
+    pub fn graphemes(s: &str) -> Graphemes<'_> {
+        Graphemes { s }
+    }
+
+    // Byte offset of the n-th item, or s.len()
+    fn offset(s: &str, mut starts: impl Iterator<Item = usize>, n: usize) -> usize {
+        starts.nth(n).unwrap_or(s.len())
+    }
+
+    fn grapheme_offset(s: &str, n: usize) -> usize {
+        let starts = graphemes(s).scan(0, |pos, g| {
+            let start = *pos;
+            *pos += g.len();
+            Some(start)
+        });
+        offset(s, starts, n)
+    }
+
+    fn char_offset(s: &str, n: usize) -> usize {
+        offset(s, s.char_indices().map(|(i, _)| i), n)
+    }
+
+    // First n chars
+    pub fn take_chars(s: &str, n: usize) -> &str {
+        &s[..char_offset(s, n)]
+    }
+
+    pub fn skip_chars(s: &str, n: usize) -> &str {
+        &s[char_offset(s, n)..]
+    }
+
+    // Chars start..end, clamped to the length of s
+    pub fn slice_chars(s: &str, start: usize, end: usize) -> &str {
+        take_chars(skip_chars(s, start), end.saturating_sub(start))
+    }
+
+    // First n grapheme clusters
+    pub fn take(s: &str, n: usize) -> &str {
+        &s[..grapheme_offset(s, n)]
+    }
+
+    pub fn skip(s: &str, n: usize) -> &str {
+        &s[grapheme_offset(s, n)..]
+    }
+
+    // Grapheme clusters start..end, clamped to the length of s
+    pub fn slice(s: &str, start: usize, end: usize) -> &str {
+        take(skip(s, start), end.saturating_sub(start))
+    }
+
+    pub fn reverse(s: &str) -> String {
+        let gs: Vec<&str> = graphemes(s).collect();
+        gs.into_iter().rev().collect()
+    }
+
+    // Number of terminal columns used by a grapheme cluster
+    // - Control and lone combining marks = 0
+    // - Emoji and East Asian wide chars = 2
+    // - Everything else = 1
+    fn grapheme_width(g: &str) -> usize {
+        let mut chars = g.chars();
+        let Some(c) = chars.next() else {
+            return 0;
+        };
+        match gcb(c) {
+            CR | LF | Control | Extend | ZWJ => 0,
+            RegionalIndicator => 2,
+            _ if in_table(EAST_ASIAN_WIDE, c) => 2,
+            // Text style pictograph turned into emoji by VS16
+            _ if is_pictographic(c) && (c as u32 >= 0x1F000 || g.contains('\u{FE0F}')) => 2,
+            _ => 1,
+        }
+    }
+
+    pub fn width(s: &str) -> usize {
+        graphemes(s).map(grapheme_width).sum()
+    }
+
+    // Cut s to at most max_width columns, ending with the ellipsis if cut
+    // Never splits a grapheme cluster
+    pub fn truncate<'a>(s: &'a str, max_width: usize, ellipsis: &str) -> Cow<'a, str> {
+        if width(s) <= max_width {
+            return Cow::Borrowed(s);
+        }
+        let budget = max_width.saturating_sub(width(ellipsis));
+        let mut used = 0;
+        let mut end = 0;
+        for g in graphemes(s) {
+            used += grapheme_width(g);
+            if used > budget {
+                break;
+            }
+            end += g.len();
+        }
+        Cow::Owned(format!("{}{}", &s[..end], ellipsis))
+    }
+
+    // Case folding by upper then lower case
+    // - "ß" -> "SS" -> "ss", "ς" -> "Σ" -> "σ"
+    fn fold(s: &str) -> impl Iterator<Item = char> + '_ {
+        s.chars()
+            .flat_map(char::to_uppercase)
+            .flat_map(char::to_lowercase)
+    }
+
+    pub fn cmp_ignore_case(a: &str, b: &str) -> Ordering {
+        fold(a).cmp(fold(b))
+    }
+
+    pub fn eq_ignore_case(a: &str, b: &str) -> bool {
+        fold(a).eq(fold(b))
+    }
+}
+
+// Run the test file, format of GraphemeBreakTest.txt
+// ÷ 0061 × 0308 ÷ 0062 ÷
+fn run_break_tests(data: &str) -> (usize, Vec<String>) {
+    let mut passed = 0;
+    let mut failed = Vec::new();
+    for line in data.lines() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let mut s = String::new();
+        let mut expected = Vec::new();
+        for token in line.split_whitespace() {
+            match token {
+                "÷" => expected.push(s.clone()),
+                "×" => {}
+                hex => {
+                    let c = u32::from_str_radix(hex, 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .expect("invalid code point");
+                    s.push(c);
+                }
+            }
+        }
+        // Prefixes at each break -> clusters
+        let expected: Vec<&str> = expected.windows(2).map(|w| &w[1][w[0].len()..]).collect();
+        let actual: Vec<&str> = text::graphemes(&s).collect();
+        if actual == expected {
+            passed += 1;
+        } else {
+            failed.push(format!("{line}\n  got {:?}", actual));
+        }
+    }
+    (passed, failed)
+}
+
+fn main() {
+    let msg = "Hello Rust 🦀";
+    // &msg[..12] panics, byte 12 is inside the 4 byte crab
+    info!("get(..12) = {:?}", msg.get(..12));
+    info!("take_chars = {:?}", text::take_chars(msg, 12));
+    info!("skip_chars = {:?}", text::skip_chars(msg, 6));
+    info!("slice_chars = {:?}", text::slice_chars(msg, 6, 100));
+
+    // 10 chars, 4 grapheme clusters
+    let s = "e\u{301}🇯🇵👨‍👩‍👧!";
+    let gs: Vec<&str> = text::graphemes(s).collect();
+    info!("{s}: {} chars, graphemes = {:?}", s.chars().count(), gs);
+    info!("take(2) = {}", text::take(s, 2));
+    info!("skip(1) = {}", text::skip(s, 1));
+    info!("slice(1, 3) = {}", text::slice(s, 1, 3));
+    info!("reverse = {}", text::reverse(s));
+    // Reversing chars moves the accent onto another letter
+    info!(
+        "reverse chars = {}",
+        "ae\u{301}".chars().rev().collect::<String>()
+    );
+    info!("reverse = {}", text::reverse("ae\u{301}"));
+
+    for s in [
+        "abc",
+        "🦀",
+        "한국어",
+        "e\u{301}",
+        "👨‍👩‍👧",
+        "🇯🇵",
+        "❤",
+        "❤\u{FE0F}",
+    ] {
+        info!("width({s}) = {}", text::width(s));
+    }
+
+    for s in ["Hello Rust 🦀", "Hello 🇯🇵🇯🇵", "안녕하세요 세계"] {
+        info!("{:?}", text::truncate(s, 10, "…"));
+    }
+
+    info!("{}", text::eq_ignore_case("Straße", "STRASSE"));
+    info!("{}", text::eq_ignore_case("ΣΊΣΥΦΟΣ", "σίσυφος"));
+    info!("{:?}", text::cmp_ignore_case("apple", "Banana"));
+
+    let (passed, failed) = run_break_tests(include_str!("../../data/grapheme_break_test.txt"));
+    info!(
+        "grapheme break tests: {passed} passed, {} failed",
+        failed.len()
+    );
+    for f in &failed {
+        info!("{f}");
+    }
+    assert!(failed.is_empty());
+}
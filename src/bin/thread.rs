@@ -1,5 +1,6 @@
 #![allow(unused)]
 
+use hello_rust::info;
 use std::thread;
 use std::thread::JoinHandle;
 use std::time::Duration;
@@ -7,14 +8,14 @@ use std::time::Duration;
 fn main() {
     let t1: JoinHandle<()> = thread::spawn(|| {
         for i in 0..5 {
-            println!("t1: {i}");
+            info!("t1: {i}");
             thread::sleep(Duration::from_millis(10));
         }
     });
 
     let t2: JoinHandle<()> = thread::spawn(|| {
         for i in 0..5 {
-            println!("t2: {i}");
+            info!("t2: {i}");
             thread::sleep(Duration::from_millis(10));
         }
     });
@@ -28,17 +29,17 @@ fn main() {
         return 1;
     });
     let v = t.join().unwrap();
-    println!("value: {v}");
+    info!("value: {v}");
 
     // move
     let v = vec![1, 2, 3];
     // Closure may outlive the main function so transfer ownership of v
     let t = thread::spawn(move || {
-        println!("{v:?}");
+        info!("{v:?}");
     });
 
     // Cannot compile - ownership transferred into closure above
-    // println!("{:?}", v);
+    // info!("{:?}", v);
 
     t.join().unwrap();
 
@@ -52,10 +53,10 @@ fn main() {
 
     match t.join() {
         Ok(v) => {
-            println!("Thread ok: {:?}", v);
+            info!("Thread ok: {:?}", v);
         }
         Err(err) => {
-            println!("Thread error: {:?}", err);
+            info!("Thread error: {:?}", err);
         }
     }
 }
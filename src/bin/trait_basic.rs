@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 struct Solidity {
     version: String,
 }
@@ -61,8 +63,8 @@ fn main() {
         version: "0.4".to_string(),
     };
 
-    println!("{}", compile(&sol, "Hello.sol"));
-    println!("{}", compile(&vy, "Hello.vy"));
-    println!("{}", test(&sol, "Hello.sol"));
-    println!("{}", test(&vy, "Hello.vy"));
+    info!("{}", compile(&sol, "Hello.sol"));
+    info!("{}", compile(&vy, "Hello.vy"));
+    info!("{}", test(&sol, "Hello.sol"));
+    info!("{}", test(&vy, "Hello.vy"));
 }
@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 // Clone
 // - Creates a deep copy
 // - Duplication might involve running arbitrary code
@@ -26,22 +28,22 @@ fn main() {
     // Clone
     let s0 = String::from("rust");
     let s1 = s0.clone();
-    println!("s0 {:?}", s0);
-    println!("s1 {:?}", s1);
+    info!("s0 {:?}", s0);
+    info!("s1 {:?}", s1);
 
     // Copy
     let x0: i32 = 1;
     // Doesn't move overship. Value is copied
     let x1 = x0;
-    println!("x0 {x0}");
-    println!("x1 {x1}");
+    info!("x0 {x0}");
+    info!("x1 {x1}");
 
     // Copy struct
     let p0 = Point { x: 1, y: 1 };
     // Values are copied, no transfer of ownership
     let p1 = p0;
-    println!("p0 {:?}", p0);
-    println!("p1 {:?}", p1);
+    info!("p0 {:?}", p0);
+    info!("p1 {:?}", p1);
 
     // Clone struct
     let g0 = Graph {
@@ -50,6 +52,6 @@ fn main() {
     };
     // Must be cloned. Otherwise moves ownership
     let g1 = g0.clone();
-    println!("g0 {:?}", g0);
-    println!("g1 {:?}", g1);
+    info!("g0 {:?}", g0);
+    info!("g1 {:?}", g1);
 }
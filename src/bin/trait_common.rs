@@ -1,5 +1,6 @@
 #![allow(unused)]
 
+use hello_rust::info;
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -27,18 +28,18 @@ fn main() {
     let p3 = p2.clone();
 
     // Debug
-    println!("{:?}", p0);
+    info!("{:?}", p0);
 
     // PartialEq
-    println!("p0 == p1? {:?}", p0 == p1);
-    println!("p0 == p2? {:?}", p0 == p2);
-    println!("p2 == p3? {:?}", p2 == p3);
+    info!("p0 == p1? {:?}", p0 == p1);
+    info!("p0 == p2? {:?}", p0 == p2);
+    info!("p2 == p3? {:?}", p2 == p3);
 
     let c0 = Color::Red;
     let c1 = Color::Green;
     let c2 = Color::Green;
-    println!("c0 == c1? {:?}", c0 == c1);
-    println!("c1 == c2? {:?}", c1 == c2);
+    info!("c0 == c1? {:?}", c0 == c1);
+    info!("c1 == c2? {:?}", c1 == c2);
 
     // Eq + Hash
     let mut map: HashMap<Color, u32> = HashMap::new();
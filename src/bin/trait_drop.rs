@@ -1,12 +1,14 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 struct A {
     name: String,
 }
 
 impl Drop for A {
     fn drop(&mut self) {
-        println!("Dropping {}", self.name);
+        info!("Dropping {}", self.name);
     }
 }
 
@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 trait Animal {
     fn speak(&self) -> String;
 }
@@ -21,12 +23,12 @@ impl Animal for Dog {
 
 // Trait as input - animal must be a type known at compile time
 fn greet(animal: &impl Animal) {
-    println!("{} world!", animal.speak());
+    info!("{} world!", animal.speak());
 }
 
 // Trait as input - animal is a type not known at compile time
 fn greet_dyn(animal: &dyn Animal) {
-    println!("{} world!", animal.speak());
+    info!("{} world!", animal.speak());
 }
 
 // Trait as output when return type is a single type known at compile time
@@ -60,8 +62,8 @@ fn main() {
     greet_dyn(animal);
 
     let dog = return_concrete_type();
-    println!("{}", dog.speak());
+    info!("{}", dog.speak());
 
     let animal = rand_animal(0.4);
-    println!("{}", animal.speak());
+    info!("{}", animal.speak());
 }
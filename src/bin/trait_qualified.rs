@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 trait Color {
     fn get(&self) -> String;
 }
@@ -38,6 +40,6 @@ fn main() {
     let color = Color::get(&square);
     let (x, y, width, height) = Rectangle::get(&square);
 
-    println!("Color: {color}");
-    println!("x: {x}, y: {y}, width: {width}, height: {height}");
+    info!("Color: {color}");
+    info!("x: {x}, y: {y}, width: {width}, height: {height}");
 }
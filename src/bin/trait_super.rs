@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 trait Language {
     fn name(&self) -> String;
     fn run(&self, file_path: &str) -> String;
@@ -12,10 +14,10 @@ trait Compiler {
 trait CompiledLanguage: Language + Compiler {
     fn exec(&self, file_path: &str) {
         let cmd = self.compile(file_path);
-        println!("{cmd}");
+        info!("{cmd}");
 
         let cmd = self.run(file_path);
-        println!("{cmd}");
+        info!("{cmd}");
     }
 }
 
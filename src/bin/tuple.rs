@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 // Function that returns multiple values using a tuple
 fn return_many() -> (u32, bool) {
     (1, true)
@@ -14,11 +16,11 @@ fn return_empty_tuple() -> () {}
 fn main() {
     // Tuples - fixed size, mixed types, known at compile time
     let t: (bool, char, u32) = (true, 'c', 3);
-    println!("({}, {}, {})", t.0, t.1, t.2);
+    info!("({}, {}, {})", t.0, t.1, t.2);
 
     // Destructuring a tuple
     let (a, b, c) = t;
-    println!("a = {}, b = {}, c = {}", a, b, c);
+    info!("a = {}, b = {}, c = {}", a, b, c);
 
     // Partial destructuring (ignore first and last values)
     let (_, b, _) = t;
@@ -32,5 +34,5 @@ fn main() {
 
     // Nested tuple
     let nested = (('a', 1.23), ('b', true, 1), ());
-    println!("nested: {}", (nested.0).1);
+    info!("nested: {}", (nested.0).1);
 }
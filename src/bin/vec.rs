@@ -1,5 +1,7 @@
 #![allow(unused)]
 
+use hello_rust::info;
+
 // Vector
 fn main() {
     // Vec<T>
@@ -9,16 +11,16 @@ fn main() {
     let v = vec![1u8, 2, 3, 4];
     // Initialize with all 5 elements equal to 1
     let v = vec![1u8; 5];
-    println!("vec = {:?}, length = {}", v, v.len());
+    info!("vec = {:?}, length = {}", v, v.len());
 
     // Get
     let x = v[1];
-    println!("{}", x);
+    info!("{}", x);
 
     let x = v.get(1);
     match x {
-        Some(val) => println!("get: {:?}", val),
-        None => println!("get: value doesn't exist"),
+        Some(val) => info!("get: {:?}", val),
+        None => info!("get: value doesn't exist"),
     }
 
     // Update
@@ -30,17 +32,17 @@ fn main() {
     v.push(5);
     v.push(6);
     v.push(7);
-    println!("vec = {:?}", v);
+    info!("vec = {:?}", v);
 
     // pop - remove last element
     let mut v = vec![1, 2];
     match v.pop() {
-        Some(val) => println!("pop: {val}"),
-        None => println!("pop: none"),
+        Some(val) => info!("pop: {val}"),
+        None => info!("pop: none"),
     }
 
     // Slice
     let v = vec![1, 2, 3, 4, 5];
     let s = &v[1..3];
-    println!("slice = {:?}", s);
+    info!("slice = {:?}", s);
 }
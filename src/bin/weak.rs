@@ -1,5 +1,6 @@
 #![allow(unused)]
 
+use hello_rust::info;
 use std::cell::{RefCell, RefMut};
 use std::rc::{Rc, Weak};
 
@@ -20,45 +21,45 @@ fn main() {
     let x = "hello".to_string();
 
     let r0: Rc<String> = Rc::new(x);
-    println!("r0 - strong count: {}", Rc::strong_count(&r0));
+    info!("r0 - strong count: {}", Rc::strong_count(&r0));
 
     let r1 = Rc::clone(&r0);
-    println!("r1 - strong count: {}", Rc::strong_count(&r0));
+    info!("r1 - strong count: {}", Rc::strong_count(&r0));
 
     // Weak refenence
     // Rc::downgrade -> weak_count increases
     // Rc::upgrade -> returns Option<Rc<T>>`
     let w1: Weak<String> = Rc::downgrade(&r0);
-    println!("w1 - strong count: {}", Rc::strong_count(&r0));
-    println!("w1 - weak count: {}", Rc::weak_count(&r0));
+    info!("w1 - strong count: {}", Rc::strong_count(&r0));
+    info!("w1 - weak count: {}", Rc::weak_count(&r0));
 
     let w2: Weak<String> = Rc::downgrade(&r0);
-    println!("w2 - strong count: {}", Rc::strong_count(&r0));
-    println!("w2 - weak count: {}", Rc::weak_count(&r0));
+    info!("w2 - strong count: {}", Rc::strong_count(&r0));
+    info!("w2 - weak count: {}", Rc::weak_count(&r0));
 
     // Upgrade weak to strong reference
     let u0 = w1.upgrade();
-    println!("u0 - upgrade w1: {:?}", u0);
-    println!("u0 - strong count: {}", Rc::strong_count(&r0));
-    println!("u0 - weak count: {}", Rc::weak_count(&r0));
+    info!("u0 - upgrade w1: {:?}", u0);
+    info!("u0 - strong count: {}", Rc::strong_count(&r0));
+    info!("u0 - weak count: {}", Rc::weak_count(&r0));
 
     // Drop some strong references
-    println!("drop u0 and r1");
+    info!("drop u0 and r1");
     std::mem::drop(u0);
     std::mem::drop(r1);
 
     let u1 = w1.upgrade();
-    println!("u1 - upgrade w1: {:?}", u1);
-    println!("u1 - strong count: {}", Rc::strong_count(&r0));
-    println!("u1 - weak count: {}", Rc::weak_count(&r0));
+    info!("u1 - upgrade w1: {:?}", u1);
+    info!("u1 - strong count: {}", Rc::strong_count(&r0));
+    info!("u1 - weak count: {}", Rc::weak_count(&r0));
 
     // Drop all strong references
-    println!("drop u1 and r0");
+    info!("drop u1 and r0");
     std::mem::drop(u1);
     std::mem::drop(r0);
 
     let u2 = w1.upgrade();
-    println!("u2 - upgrade w1: {:?}", u2);
+    info!("u2 - upgrade w1: {:?}", u2);
 
     // Example - Node
     let node0 = Rc::new(Node {
@@ -92,10 +93,10 @@ fn main() {
     }
 
     // No infinite loop
-    println!("{:#?}", node0);
+    info!("{:#?}", node0);
 
     // Print node1 - prints Some(Node)
-    println!(
+    info!(
         "{:#?}",
         node0
             .neighbors
@@ -108,7 +109,7 @@ fn main() {
     std::mem::drop(node1);
 
     // Print node1 - prints None
-    println!(
+    info!(
         "{:#?}",
         node0
             .neighbors
@@ -0,0 +1,314 @@
+// Numeric casts that say what `as` did
+// -1i32 as u32 silently becomes 4294967295
+//
+// use hello_rust::convert::Cast;
+// let x: Option<u8> = 300i32.cast_checked();      None
+// let x: u8 = 300i32.cast_saturating();           255
+// let x: u8 = 300i32.cast_wrapping();             44, same as `as`
+// let r = Cast::<u8>::cast_explain(300i32);       300i32 as u8 = 44 (truncated)
+//
+// Implemented for every pair of the 12 integer and 2 float types
+
+use std::fmt;
+
+// Exact value of any primitive number
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Repr {
+    Int { neg: bool, mag: u128 },
+    Float(f64),
+}
+
+impl Repr {
+    pub fn is_negative(self) -> bool {
+        match self {
+            Repr::Int { neg, .. } => neg,
+            Repr::Float(f) => f < 0.0,
+        }
+    }
+}
+
+pub trait Prim: Copy + PartialEq + fmt::Debug + fmt::Display + 'static {
+    const NAME: &'static str;
+    const BITS: u32;
+    const IS_FLOAT: bool;
+    const MIN: Self;
+    const MAX: Self;
+
+    fn repr(self) -> Repr;
+
+    // v as Self
+    fn from_u128(v: u128) -> Self;
+}
+
+macro_rules! prim_signed {
+    ($($t:ident),*) => {$(
+        impl Prim for $t {
+            const NAME: &'static str = stringify!($t);
+            const BITS: u32 = $t::BITS;
+            const IS_FLOAT: bool = false;
+            const MIN: Self = $t::MIN;
+            const MAX: Self = $t::MAX;
+
+            fn repr(self) -> Repr {
+                Repr::Int {
+                    neg: self < 0,
+                    mag: (self as i128).unsigned_abs(),
+                }
+            }
+
+            fn from_u128(v: u128) -> Self {
+                v as $t
+            }
+        }
+    )*};
+}
+
+macro_rules! prim_unsigned {
+    ($($t:ident),*) => {$(
+        impl Prim for $t {
+            const NAME: &'static str = stringify!($t);
+            const BITS: u32 = $t::BITS;
+            const IS_FLOAT: bool = false;
+            const MIN: Self = $t::MIN;
+            const MAX: Self = $t::MAX;
+
+            fn repr(self) -> Repr {
+                Repr::Int {
+                    neg: false,
+                    mag: self as u128,
+                }
+            }
+
+            fn from_u128(v: u128) -> Self {
+                v as $t
+            }
+        }
+    )*};
+}
+
+macro_rules! prim_float {
+    ($($t:ident),*) => {$(
+        impl Prim for $t {
+            const NAME: &'static str = stringify!($t);
+            const BITS: u32 = std::mem::size_of::<$t>() as u32 * 8;
+            const IS_FLOAT: bool = true;
+            const MIN: Self = $t::MIN;
+            const MAX: Self = $t::MAX;
+
+            fn repr(self) -> Repr {
+                Repr::Float(self as f64)
+            }
+
+            fn from_u128(v: u128) -> Self {
+                v as $t
+            }
+        }
+    )*};
+}
+
+prim_signed!(i8, i16, i32, i64, i128, isize);
+prim_unsigned!(u8, u16, u32, u64, u128, usize);
+prim_float!(f32, f64);
+
+// What a cast did to the value, all false = exact
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Effects {
+    // int -> int, high bits were dropped, 300i32 as u8 = 44
+    pub truncated: bool,
+    // int -> int, negative became positive or the reverse, -1i32 as u32
+    pub sign_flipped: bool,
+    // Fraction or precision lost, 2.5 as i32, 16777217 as f32, 0.1f64 as f32
+    pub rounded: bool,
+    // float -> int out of range, clamped to MIN or MAX, 1e10 as i32
+    pub saturated: bool,
+    // Finite value became infinite, u128::MAX as f32, f64::MAX as f32
+    pub infinity: bool,
+    // Source was NaN, 0 for an int, NaN for a float
+    pub nan: bool,
+}
+
+impl Effects {
+    pub fn is_exact(&self) -> bool {
+        *self == Effects::default()
+    }
+}
+
+impl fmt::Display for Effects {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = [
+            (self.truncated, "truncated"),
+            (self.sign_flipped, "sign flipped"),
+            (self.rounded, "rounded"),
+            (self.saturated, "saturated"),
+            (self.infinity, "infinity"),
+            (self.nan, "NaN"),
+        ];
+        let names: Vec<_> = names
+            .iter()
+            .filter(|(on, _)| *on)
+            .map(|(_, n)| *n)
+            .collect();
+        if names.is_empty() {
+            f.write_str("exact")
+        } else {
+            f.write_str(&names.join(", "))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CastReport<S, T> {
+    pub from: S,
+    pub value: T,
+    pub effects: Effects,
+}
+
+// 300i32 as u8 = 44 (truncated)
+// 1e40f64 as f32 = inf (infinity)
+impl<S: Prim, T: Prim> fmt::Display for CastReport<S, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?}{} as {} = {:?} ({})",
+            self.from,
+            S::NAME,
+            T::NAME,
+            self.value,
+            self.effects
+        )
+    }
+}
+
+// f is an integer equal to the integer
+fn int_eq_float(neg: bool, mag: u128, f: f64) -> bool {
+    // 2^128
+    const LIMIT: f64 = 340282366920938463463374607431768211456.0;
+    f.is_finite()
+        && f.fract() == 0.0
+        && f.abs() < LIMIT
+        && f.abs() as u128 == mag
+        && (mag == 0 || neg == (f < 0.0))
+}
+
+// round_trip = (value as S) == from, only used for int -> int
+fn explain<S: Prim, T: Prim>(from: S, value: T, round_trip: bool) -> CastReport<S, T> {
+    let mut e = Effects::default();
+    match (from.repr(), value.repr()) {
+        (Repr::Int { neg: a, .. }, Repr::Int { neg: b, .. }) => {
+            e.truncated = !round_trip;
+            e.sign_flipped = a != b;
+        }
+        (Repr::Int { neg, mag }, Repr::Float(f)) => {
+            e.infinity = f.is_infinite();
+            e.rounded = !e.infinity && !int_eq_float(neg, mag, f);
+        }
+        (Repr::Float(x), Repr::Int { neg, mag }) => {
+            e.nan = x.is_nan();
+            if !e.nan {
+                e.rounded = x.is_finite() && x.fract() != 0.0;
+                e.saturated = !int_eq_float(neg, mag, x.trunc());
+            }
+        }
+        (Repr::Float(x), Repr::Float(y)) => {
+            e.nan = x.is_nan();
+            e.infinity = y.is_infinite() && !x.is_infinite();
+            e.rounded = !e.nan && !e.infinity && x != y;
+        }
+    }
+    CastReport {
+        from,
+        value,
+        effects: e,
+    }
+}
+
+// Low 128 bits of the integer part of f, two's complement, 0 for NaN and infinity
+fn float_low_bits(f: f64) -> u128 {
+    // 2^127
+    const LIMIT: f64 = 170141183460469231731687303715884105728.0;
+    if !f.is_finite() {
+        return 0;
+    }
+    let f = f.trunc();
+    if f.abs() < LIMIT {
+        return f as i128 as u128;
+    }
+    // f = mantissa * 2^exp with exp >= 75, bits above 128 are shifted out
+    let bits = f.to_bits();
+    let exp = ((bits >> 52) & 0x7ff) as u32 - 1075;
+    let mantissa = ((bits & ((1 << 52) - 1)) | (1 << 52)) as u128;
+    let low = if exp >= 128 { 0 } else { mantissa << exp };
+    if f < 0.0 {
+        low.wrapping_neg()
+    } else {
+        low
+    }
+}
+
+pub trait Cast<T: Prim>: Prim {
+    // Same as `self as T`
+    fn cast(self) -> T;
+
+    fn cast_explain(self) -> CastReport<Self, T>;
+
+    // Some only if the value is unchanged, NaN is never unchanged
+    fn cast_checked(self) -> Option<T> {
+        let r = self.cast_explain();
+        r.effects.is_exact().then_some(r.value)
+    }
+
+    // Out of range values become T::MIN or T::MAX
+    // Rounding is kept, 2.5 -> 2, 16777217 -> 16777216f32
+    fn cast_saturating(self) -> T {
+        let r = self.cast_explain();
+        let e = r.effects;
+        if e.truncated || e.sign_flipped || e.infinity {
+            if self.repr().is_negative() {
+                T::MIN
+            } else {
+                T::MAX
+            }
+        } else {
+            r.value
+        }
+    }
+
+    // Modulo 2^bits for integer targets, float -> int wraps instead of saturating
+    // 300.0 -> 44u8, NaN -> 0, float targets are the same as `as`
+    fn cast_wrapping(self) -> T {
+        match self.repr() {
+            Repr::Float(f) if !T::IS_FLOAT => T::from_u128(float_low_bits(f)),
+            _ => self.cast(),
+        }
+    }
+}
+
+macro_rules! impl_cast {
+    ($s:ident => [$($t:ident),*]) => {$(
+        #[allow(clippy::unnecessary_cast)]
+        impl Cast<$t> for $s {
+            fn cast(self) -> $t {
+                self as $t
+            }
+
+            fn cast_explain(self) -> CastReport<$s, $t> {
+                let value = self as $t;
+                #[allow(clippy::float_cmp)]
+                let round_trip = value as $s == self;
+                explain(self, value, round_trip)
+            }
+        }
+    )*};
+}
+
+// Every source with every target
+macro_rules! cast_matrix {
+    ($($s:ident),*) => {
+        cast_matrix!(@each [$($s),*] $($s),*);
+    };
+    (@each $all:tt $($s:ident),*) => {
+        $(impl_cast!($s => $all);)*
+    };
+}
+
+cast_matrix!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
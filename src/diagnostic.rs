@@ -0,0 +1,514 @@
+// rustc style error messages with the source code
+//
+// error[E0001]: invalid digit found in string
+//  --> data/numbers.txt:3:2
+//   |
+// 3 | 1z
+//   |  ^ not a digit
+//   |
+//   = help: remove the `z`
+//
+// - Spans are byte ranges into the source, the end is exclusive
+// - 1 primary label (^^^) and any number of secondary labels (---)
+// - Spans over several lines are drawn with a line in the gutter
+// - Optional ANSI color, NO_COLOR is respected by Renderer::auto
+// - JSON output for editors, 1 object per diagnostic
+
+use crate::log::json_string;
+use std::fmt;
+use std::io::{self, IsTerminal};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+    Note,
+    Help,
+}
+
+impl Severity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Note => "note",
+            Severity::Help => "help",
+        }
+    }
+
+    // ANSI style
+    fn style(self) -> &'static str {
+        match self {
+            Severity::Error => "1;31",
+            Severity::Warning => "1;33",
+            Severity::Note => "1;32",
+            Severity::Help => "1;36",
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Label {
+    pub span: Span,
+    pub message: String,
+    pub primary: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: Option<String>,
+    pub message: String,
+    pub labels: Vec<Label>,
+    pub notes: Vec<String>,
+    pub help: Vec<String>,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            code: None,
+            message: message.into(),
+            labels: Vec::new(),
+            notes: Vec::new(),
+            help: Vec::new(),
+        }
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(Severity::Error, message)
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(Severity::Warning, message)
+    }
+
+    pub fn with_code(mut self, code: impl Into<String>) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    pub fn with_primary(mut self, span: Span, message: impl Into<String>) -> Self {
+        self.labels.push(Label {
+            span,
+            message: message.into(),
+            primary: true,
+        });
+        self
+    }
+
+    pub fn with_label(mut self, span: Span, message: impl Into<String>) -> Self {
+        self.labels.push(Label {
+            span,
+            message: message.into(),
+            primary: false,
+        });
+        self
+    }
+
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help.push(help.into());
+        self
+    }
+
+    // The primary label, or the first label
+    pub fn primary(&self) -> Option<&Label> {
+        self.labels
+            .iter()
+            .find(|l| l.primary)
+            .or(self.labels.first())
+    }
+}
+
+// Source code with a line index
+pub struct Source<'a> {
+    pub name: &'a str,
+    pub text: &'a str,
+    line_starts: Vec<usize>,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(name: &'a str, text: &'a str) -> Self {
+        let mut line_starts = vec![0];
+        line_starts.extend(text.match_indices('\n').map(|(i, _)| i + 1));
+        Self {
+            name,
+            text,
+            line_starts,
+        }
+    }
+
+    // 0 based line of a byte offset
+    pub fn line_of(&self, offset: usize) -> usize {
+        self.line_starts.partition_point(|&s| s <= offset) - 1
+    }
+
+    // Line without the newline
+    pub fn line(&self, line: usize) -> &'a str {
+        let start = self.line_starts[line];
+        let end = self
+            .line_starts
+            .get(line + 1)
+            .map_or(self.text.len(), |&e| e - 1);
+        self.text[start..end].trim_end_matches('\r')
+    }
+
+    // 0 based line and column in chars
+    pub fn line_col(&self, offset: usize) -> (usize, usize) {
+        let line = self.line_of(offset);
+        let start = self.line_starts[line];
+        let col = self.text[start..offset.min(self.text.len())]
+            .chars()
+            .count();
+        (line, col)
+    }
+
+    // Column on screen, a tab is 4 columns
+    fn display_col(&self, offset: usize) -> usize {
+        let line = self.line_of(offset);
+        let start = self.line_starts[line];
+        let end = offset.min(start + self.line(line).len());
+        let text = &self.text[start..end];
+        let past_end = offset.saturating_sub(end);
+        text.chars()
+            .map(|c| if c == '\t' { 4 } else { 1 })
+            .sum::<usize>()
+            + past_end
+    }
+}
+
+// Label resolved to lines and screen columns
+struct Mark<'a> {
+    label: &'a Label,
+    line: usize,
+    end_line: usize,
+    // Screen columns, end is exclusive and > col
+    col: usize,
+    end_col: usize,
+}
+
+impl Mark<'_> {
+    fn multi_line(&self) -> bool {
+        self.line != self.end_line
+    }
+
+    fn char(&self) -> char {
+        if self.label.primary {
+            '^'
+        } else {
+            '-'
+        }
+    }
+}
+
+pub struct Renderer {
+    pub color: bool,
+}
+
+impl Renderer {
+    pub fn new(color: bool) -> Self {
+        Self { color }
+    }
+
+    // Color if stderr is a terminal and NO_COLOR is not set
+    pub fn auto() -> Self {
+        let color = io::stderr().is_terminal() && std::env::var_os("NO_COLOR").is_none();
+        Self { color }
+    }
+
+    fn paint(&self, style: &str, text: &str) -> String {
+        if self.color && !text.is_empty() {
+            format!("\x1b[{style}m{text}\x1b[0m")
+        } else {
+            text.to_string()
+        }
+    }
+
+    pub fn render(&self, d: &Diagnostic, src: &Source) -> String {
+        let blue = "1;34";
+        let sev = d.severity.style();
+        let mut out = String::new();
+
+        let code = d.code.as_ref().map_or(String::new(), |c| format!("[{c}]"));
+        out += &self.paint(sev, &format!("{}{code}", d.severity));
+        out += &self.paint("1", &format!(": {}", d.message));
+        out.push('\n');
+
+        let marks: Vec<Mark> = d.labels.iter().map(|l| mark(l, src)).collect();
+        let last_line = marks.iter().map(|m| m.end_line).max().unwrap_or(0);
+        let width = (last_line + 1).to_string().len();
+        let pad = " ".repeat(width);
+        let bar = self.paint(blue, "|");
+        // Blank gutter line
+        let empty = format!("{pad} {bar}");
+
+        if let Some(primary) = d.primary() {
+            let (line, col) = src.line_col(primary.span.start);
+            out += &format!(
+                "{pad}{} {}:{}:{}\n",
+                self.paint(blue, "-->"),
+                src.name,
+                line + 1,
+                col + 1
+            );
+            out += &empty;
+            out.push('\n');
+        }
+
+        // Lines to show, gaps of 1 line are filled in
+        let mut lines: Vec<usize> = Vec::new();
+        for m in &marks {
+            lines.extend(m.line..=m.end_line);
+        }
+        lines.sort_unstable();
+        lines.dedup();
+        let mut filled: Vec<usize> = Vec::new();
+        for l in lines {
+            if filled.last().is_some_and(|&p| l == p + 2) {
+                filled.push(l - 1);
+            }
+            filled.push(l);
+        }
+
+        // Space for the multi-line span line
+        let has_multi = marks.iter().any(|m| m.multi_line());
+        for (i, &line) in filled.iter().enumerate() {
+            if i > 0 && line > filled[i - 1] + 1 {
+                out += &self.paint(blue, "...");
+                out.push('\n');
+            }
+            let text = src.line(line).replace('\t', "    ");
+            let multi = marks
+                .iter()
+                .find(|m| m.multi_line() && (m.line..=m.end_line).contains(&line));
+            // Gutter for this line: / at a span that starts at the indent, | inside a span
+            let gutter = match multi {
+                _ if !has_multi => String::new(),
+                Some(m) if m.line == line && starts_at_indent(&text, m.col) => {
+                    self.paint(style(m, sev), "/") + " "
+                }
+                Some(m) if m.line < line => self.paint(style(m, sev), "|") + " ",
+                _ => "  ".to_string(),
+            };
+            let num = self.paint(blue, &format!("{:>width$}", line + 1));
+            out += format!("{num} {bar} {gutter}{text}").trim_end();
+            out.push('\n');
+
+            // Gutter of the rows below the line
+            let inside = match multi {
+                _ if !has_multi => String::new(),
+                // The span is still open, its end is drawn after the labels
+                Some(m) if m.line < line || starts_at_indent(&text, m.col) => {
+                    self.paint(style(m, sev), "|") + " "
+                }
+                _ => "  ".to_string(),
+            };
+
+            // Single line labels, the rightmost message goes after the underline
+            let mut singles: Vec<&Mark> = marks
+                .iter()
+                .filter(|m| !m.multi_line() && m.line == line)
+                .collect();
+            singles.sort_by_key(|m| m.col);
+            if !singles.is_empty() {
+                // 1 cell per column, primary labels are drawn over secondary ones
+                let width = singles.iter().map(|m| m.end_col).max().unwrap();
+                let mut cells: Vec<Option<&Mark>> = vec![None; width];
+                for m in singles.iter().filter(|m| !m.label.primary) {
+                    cells[m.col..m.end_col].fill(Some(m));
+                }
+                for m in singles.iter().filter(|m| m.label.primary) {
+                    cells[m.col..m.end_col].fill(Some(m));
+                }
+                let mut row = String::new();
+                for run in cells.chunk_by(|a, b| match (a, b) {
+                    (Some(a), Some(b)) => std::ptr::eq(*a, *b),
+                    (None, None) => true,
+                    _ => false,
+                }) {
+                    match run[0] {
+                        Some(m) => {
+                            let underline: String =
+                                std::iter::repeat_n(m.char(), run.len()).collect();
+                            row += &self.paint(style(m, sev), &underline);
+                        }
+                        None => row += &" ".repeat(run.len()),
+                    }
+                }
+                let last = singles.last().unwrap();
+                if !last.label.message.is_empty() {
+                    row += " ";
+                    row += &self.paint(style(last, sev), &last.label.message);
+                }
+                out += &format!("{pad} {bar} {inside}{row}\n");
+                // The other messages below, connected with |
+                let rest: Vec<&&Mark> = singles[..singles.len() - 1]
+                    .iter()
+                    .filter(|m| !m.label.message.is_empty())
+                    .collect();
+                for (j, m) in rest.iter().enumerate().rev() {
+                    for with_message in [false, true] {
+                        let mut row = String::new();
+                        let mut x = 0;
+                        let shown = &rest[..=j];
+                        for (k, n) in shown.iter().enumerate() {
+                            // Labels in the same column share 1 |
+                            if shown.get(k + 1).is_some_and(|next| next.col == n.col) {
+                                continue;
+                            }
+                            row += &" ".repeat(n.col - x);
+                            if with_message && std::ptr::eq(*n, *m) {
+                                row += &self.paint(style(n, sev), &n.label.message);
+                            } else {
+                                row += &self.paint(style(n, sev), "|");
+                            }
+                            x = n.col + 1;
+                        }
+                        out += &format!("{pad} {bar} {inside}{row}\n");
+                    }
+                }
+            }
+
+            // Start of a multi-line span that is not at the indent
+            //   |  ______^
+            if let Some(m) = multi.filter(|m| m.line == line && !starts_at_indent(&text, m.col)) {
+                let row = format!(" {}{}", "_".repeat(m.col + 1), m.char());
+                out += &format!("{pad} {bar} {}\n", self.paint(style(m, sev), &row));
+            }
+
+            // End of a multi-line span
+            //   | |____^ message
+            if let Some(m) = multi.filter(|m| m.end_line == line) {
+                let row = format!("|{}{}", "_".repeat(m.end_col), m.char());
+                let row = format!(
+                    "{pad} {bar} {} {}",
+                    self.paint(style(m, sev), &row),
+                    self.paint(style(m, sev), &m.label.message)
+                );
+                out += row.trim_end();
+                out.push('\n');
+            }
+        }
+
+        if !d.notes.is_empty() || !d.help.is_empty() {
+            if !marks.is_empty() {
+                out += &empty;
+                out.push('\n');
+            }
+            for note in &d.notes {
+                out += &format!("{pad} {} {note}\n", self.paint("1", "= note:"));
+            }
+            for help in &d.help {
+                out += &format!("{pad} {} {help}\n", self.paint("1", "= help:"));
+            }
+        }
+        out
+    }
+
+    // {"severity":"error","code":null,"message":"..","spans":[..],"notes":[..],"help":[..],"rendered":".."}
+    // Lines and columns are 1 based, columns in chars, end columns exclusive
+    pub fn json(&self, d: &Diagnostic, src: &Source) -> String {
+        let spans: Vec<String> = d
+            .labels
+            .iter()
+            .map(|l| {
+                let (line, col) = src.line_col(l.span.start);
+                let (end_line, end_col) = src.line_col(l.span.end);
+                format!(
+                    "{{\"file\":{},\"byte_start\":{},\"byte_end\":{},\"line_start\":{},\"column_start\":{},\"line_end\":{},\"column_end\":{},\"is_primary\":{},\"label\":{}}}",
+                    json_string(src.name),
+                    l.span.start,
+                    l.span.end,
+                    line + 1,
+                    col + 1,
+                    end_line + 1,
+                    end_col + 1,
+                    l.primary,
+                    json_string(&l.message)
+                )
+            })
+            .collect();
+        let list = |items: &[String]| {
+            let items: Vec<String> = items.iter().map(|s| json_string(s)).collect();
+            format!("[{}]", items.join(","))
+        };
+        format!(
+            "{{\"severity\":\"{}\",\"code\":{},\"message\":{},\"spans\":[{}],\"notes\":{},\"help\":{},\"rendered\":{}}}",
+            d.severity,
+            d.code.as_deref().map_or("null".to_string(), json_string),
+            json_string(&d.message),
+            spans.join(","),
+            list(&d.notes),
+            list(&d.help),
+            json_string(&Renderer::new(false).render(d, src))
+        )
+    }
+}
+
+fn mark<'a>(label: &'a Label, src: &Source) -> Mark<'a> {
+    let Span { start, end } = label.span;
+    // The last char of the span, the span is at least 1 char
+    let last = if end > start {
+        src.text[..end]
+            .char_indices()
+            .next_back()
+            .map_or(start, |(i, _)| i)
+    } else {
+        start
+    };
+    let line = src.line_of(start);
+    let end_line = src.line_of(last);
+    let col = src.display_col(start);
+    let last_col = if src.text[last..].starts_with('\t') {
+        src.display_col(last) + 3
+    } else {
+        src.display_col(last)
+    };
+    Mark {
+        label,
+        line,
+        end_line,
+        col,
+        end_col: if end_line == line {
+            (last_col + 1).max(col + 1)
+        } else {
+            last_col + 1
+        },
+    }
+}
+
+fn style(m: &Mark, severity: &'static str) -> &'static str {
+    if m.label.primary {
+        severity
+    } else {
+        "1;34"
+    }
+}
+
+fn starts_at_indent(text: &str, col: usize) -> bool {
+    text.len() - text.trim_start().len() == col
+}
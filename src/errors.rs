@@ -0,0 +1,30 @@
+// MathError and ParseError used by the error handling examples
+// - box_dyn_error.rs returns them as Box<dyn Error>
+// - any_error.rs and question.rs return them as AnyError, with context
+
+// Implementing std::error::Error for MathError and ParseError
+#[derive(Debug, PartialEq)]
+pub enum MathError {
+    DivByZero,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseError {
+    InvalidInt,
+}
+
+impl std::fmt::Display for MathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "math error {:?}", self)
+    }
+}
+
+impl std::error::Error for MathError {}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "parse error {:?}", self)
+    }
+}
+
+impl std::error::Error for ParseError {}
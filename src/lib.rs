@@ -0,0 +1,10 @@
+// Code shared by the examples in src/bin
+// use hello_rust::info;
+
+pub mod alloc_count;
+pub mod any_error;
+pub mod convert;
+pub mod diagnostic;
+pub mod errors;
+pub mod log;
+pub mod unicode_tables;
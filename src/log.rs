@@ -0,0 +1,329 @@
+// Structured leveled logging shared by the examples
+// - src/bin/*.rs print with info! instead of println!,
+//   except hello.rs and print.rs which are about println! itself
+// - 5 levels, error to trace
+// - Target = module path of the call site, the bin name for src/bin/*.rs
+// - Key value fields after a `;`
+//   info!("sent {}", msg; id = i, worker = name)
+// - Filter from the env var HELLO_LOG, comma separated directives
+//   HELLO_LOG=warn                only warn and error
+//   HELLO_LOG=channel=debug,warn  debug for the channel example, warn for the rest
+//   HELLO_LOG=off                 nothing
+// - Output format from the env var HELLO_LOG_FORMAT
+//   plain (default)  message only, same output as println!
+//   text             level, target, message and fields
+//   json             1 JSON object per line
+// - Safe to call from threads and tokio tasks, each line is written under a lock
+//   and nothing is held across an .await
+
+use std::env;
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::sync::{Mutex, OnceLock};
+use std::thread;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s.to_ascii_lowercase().as_str() {
+            "error" => Ok(Level::Error),
+            "warn" => Ok(Level::Warn),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!("unknown log level {s:?}")),
+        }
+    }
+}
+
+// Max level per target, None = off
+#[derive(Debug, Clone, PartialEq)]
+pub struct Filter {
+    default: Option<Level>,
+    targets: Vec<(String, Option<Level>)>,
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Self {
+            default: Some(Level::Info),
+            targets: Vec::new(),
+        }
+    }
+}
+
+impl Filter {
+    pub fn new(default: Option<Level>) -> Self {
+        Self {
+            default,
+            targets: Vec::new(),
+        }
+    }
+
+    pub fn target(mut self, target: &str, level: Option<Level>) -> Self {
+        self.targets.push((target.to_string(), level));
+        self
+    }
+
+    // warn
+    // channel=debug,warn
+    // channel        (all levels for channel)
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        fn level(s: &str) -> Result<Option<Level>, String> {
+            if s.eq_ignore_ascii_case("off") {
+                Ok(None)
+            } else {
+                s.parse().map(Some)
+            }
+        }
+
+        let mut filter = Filter::default();
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((target, l)) => filter.targets.push((target.to_string(), level(l)?)),
+                None => match level(directive) {
+                    Ok(l) => filter.default = l,
+                    // A bare target enables everything for it
+                    Err(_) => filter
+                        .targets
+                        .push((directive.to_string(), Some(Level::Trace))),
+                },
+            }
+        }
+        Ok(filter)
+    }
+
+    // The longest matching target wins, channel matches channel and channel::worker
+    pub fn enabled(&self, level: Level, target: &str) -> bool {
+        let max = self
+            .targets
+            .iter()
+            .filter(|(t, _)| {
+                target == t
+                    || (target.starts_with(t.as_str()) && target[t.len()..].starts_with("::"))
+            })
+            .max_by_key(|(t, _)| t.len())
+            .map_or(self.default, |(_, l)| *l);
+        max.is_some_and(|max| level <= max)
+    }
+}
+
+pub struct Record<'a> {
+    pub level: Level,
+    pub target: &'a str,
+    pub args: fmt::Arguments<'a>,
+    pub fields: &'a [(&'static str, &'a dyn fmt::Display)],
+}
+
+pub trait Sink: Send + Sync {
+    fn write(&self, record: &Record);
+}
+
+// Message only, like println!
+pub struct PlainSink;
+
+impl Sink for PlainSink {
+    fn write(&self, record: &Record) {
+        let mut out = io::stdout().lock();
+        let _ = writeln!(out, "{}", record.args);
+    }
+}
+
+// INFO  channel: received msg 1 id=1
+pub struct TextSink<W> {
+    out: Mutex<W>,
+}
+
+impl<W: Write + Send> TextSink<W> {
+    pub fn new(out: W) -> Self {
+        Self {
+            out: Mutex::new(out),
+        }
+    }
+}
+
+impl<W: Write + Send> Sink for TextSink<W> {
+    fn write(&self, record: &Record) {
+        let mut line = format!("{:<5} {}: {}", record.level, record.target, record.args);
+        for (key, value) in record.fields {
+            line += &format!(" {key}={value}");
+        }
+        // A panic in another thread while writing must not stop logging
+        let mut out = self.out.lock().unwrap_or_else(|e| e.into_inner());
+        let _ = writeln!(out, "{line}");
+    }
+}
+
+// {"level":"INFO","target":"channel","thread":"main","msg":"received","id":"1"}
+pub struct JsonSink<W> {
+    out: Mutex<W>,
+}
+
+impl<W: Write + Send> JsonSink<W> {
+    pub fn new(out: W) -> Self {
+        Self {
+            out: Mutex::new(out),
+        }
+    }
+}
+
+pub(crate) fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+impl<W: Write + Send> Sink for JsonSink<W> {
+    fn write(&self, record: &Record) {
+        let mut line = format!(
+            "{{\"level\":\"{}\",\"target\":{},\"thread\":{},\"msg\":{}",
+            record.level,
+            json_string(record.target),
+            json_string(thread::current().name().unwrap_or("?")),
+            json_string(&record.args.to_string())
+        );
+        for (key, value) in record.fields {
+            line += &format!(",{}:{}", json_string(key), json_string(&value.to_string()));
+        }
+        line.push('}');
+        let mut out = self.out.lock().unwrap_or_else(|e| e.into_inner());
+        let _ = writeln!(out, "{line}");
+    }
+}
+
+pub struct Logger {
+    filter: Filter,
+    sink: Box<dyn Sink>,
+}
+
+impl Logger {
+    pub fn new(filter: Filter, sink: impl Sink + 'static) -> Self {
+        Self {
+            filter,
+            sink: Box::new(sink),
+        }
+    }
+
+    // From HELLO_LOG and HELLO_LOG_FORMAT
+    // An invalid value is reported on stderr and the default is used
+    pub fn from_env() -> Self {
+        let filter = match env::var("HELLO_LOG") {
+            Ok(spec) => Filter::parse(&spec).unwrap_or_else(|e| {
+                eprintln!("HELLO_LOG: {e}");
+                Filter::default()
+            }),
+            Err(_) => Filter::default(),
+        };
+        let sink: Box<dyn Sink> = match env::var("HELLO_LOG_FORMAT").as_deref() {
+            Ok("text") => Box::new(TextSink::new(io::stdout())),
+            Ok("json") => Box::new(JsonSink::new(io::stdout())),
+            Ok("plain") | Err(_) => Box::new(PlainSink),
+            Ok(other) => {
+                eprintln!("HELLO_LOG_FORMAT: unknown format {other:?}");
+                Box::new(PlainSink)
+            }
+        };
+        Self { filter, sink }
+    }
+}
+
+static LOGGER: OnceLock<Logger> = OnceLock::new();
+
+// Replace the logger from the env, must be called before the first log
+pub fn init(logger: Logger) -> Result<(), Logger> {
+    LOGGER.set(logger)
+}
+
+fn logger() -> &'static Logger {
+    LOGGER.get_or_init(Logger::from_env)
+}
+
+pub fn enabled(level: Level, target: &str) -> bool {
+    logger().filter.enabled(level, target)
+}
+
+pub fn write(record: &Record) {
+    logger().sink.write(record);
+}
+
+// log!(Level::Info, "format {}", args; key = value, ...)
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $fmt:literal $(, $arg:expr)* $(; $($key:ident = $value:expr),+)?) => {{
+        let level: $crate::log::Level = $level;
+        if $crate::log::enabled(level, module_path!()) {
+            $crate::log::write(&$crate::log::Record {
+                level,
+                target: module_path!(),
+                args: format_args!($fmt $(, $arg)*),
+                fields: &[$($((stringify!($key), &$value as &dyn ::std::fmt::Display)),+)?],
+            });
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($t:tt)+) => { $crate::log!($crate::log::Level::Error, $($t)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($t:tt)+) => { $crate::log!($crate::log::Level::Warn, $($t)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($t:tt)+) => { $crate::log!($crate::log::Level::Info, $($t)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($t:tt)+) => { $crate::log!($crate::log::Level::Debug, $($t)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($t:tt)+) => { $crate::log!($crate::log::Level::Trace, $($t)+) };
+}
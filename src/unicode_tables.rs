@@ -0,0 +1,1393 @@
+// Generated by scripts/gen_unicode_tables.py from Unicode 17.0.0, do not edit
+// Source: wcwidth 0.7.0 tables checked against unicode-segmentation 1.13.3, not unicode.org
+// Tables are sorted, non overlapping (first, last) code point ranges
+
+pub const UNICODE_VERSION: (u8, u8, u8) = (17, 0, 0);
+
+// Grapheme_Cluster_Break property, names as in UAX #29
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gcb {
+    Other,
+    CR,
+    LF,
+    Control,
+    Extend,
+    ZWJ,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    LV,
+    LVT,
+}
+
+// From GraphemeBreakProperty.txt, code points not in the table are Other
+// - Hangul syllables AC00..D7A3 are left out,
+//   LV every 28 code points and LVT in between
+pub const GRAPHEME_BREAK: &[(u32, u32, Gcb)] = &[
+    (0x0000, 0x0009, Gcb::Control),
+    (0x000A, 0x000A, Gcb::LF),
+    (0x000B, 0x000C, Gcb::Control),
+    (0x000D, 0x000D, Gcb::CR),
+    (0x000E, 0x001F, Gcb::Control),
+    (0x007F, 0x009F, Gcb::Control),
+    (0x00AD, 0x00AD, Gcb::Control),
+    (0x0300, 0x036F, Gcb::Extend),
+    (0x0483, 0x0489, Gcb::Extend),
+    (0x0591, 0x05BD, Gcb::Extend),
+    (0x05BF, 0x05BF, Gcb::Extend),
+    (0x05C1, 0x05C2, Gcb::Extend),
+    (0x05C4, 0x05C5, Gcb::Extend),
+    (0x05C7, 0x05C7, Gcb::Extend),
+    (0x0600, 0x0605, Gcb::Prepend),
+    (0x0610, 0x061A, Gcb::Extend),
+    (0x061C, 0x061C, Gcb::Control),
+    (0x064B, 0x065F, Gcb::Extend),
+    (0x0670, 0x0670, Gcb::Extend),
+    (0x06D6, 0x06DC, Gcb::Extend),
+    (0x06DD, 0x06DD, Gcb::Prepend),
+    (0x06DF, 0x06E4, Gcb::Extend),
+    (0x06E7, 0x06E8, Gcb::Extend),
+    (0x06EA, 0x06ED, Gcb::Extend),
+    (0x070F, 0x070F, Gcb::Prepend),
+    (0x0711, 0x0711, Gcb::Extend),
+    (0x0730, 0x074A, Gcb::Extend),
+    (0x07A6, 0x07B0, Gcb::Extend),
+    (0x07EB, 0x07F3, Gcb::Extend),
+    (0x07FD, 0x07FD, Gcb::Extend),
+    (0x0816, 0x0819, Gcb::Extend),
+    (0x081B, 0x0823, Gcb::Extend),
+    (0x0825, 0x0827, Gcb::Extend),
+    (0x0829, 0x082D, Gcb::Extend),
+    (0x0859, 0x085B, Gcb::Extend),
+    (0x0890, 0x0891, Gcb::Prepend),
+    (0x0897, 0x089F, Gcb::Extend),
+    (0x08CA, 0x08E1, Gcb::Extend),
+    (0x08E2, 0x08E2, Gcb::Prepend),
+    (0x08E3, 0x0902, Gcb::Extend),
+    (0x0903, 0x0903, Gcb::SpacingMark),
+    (0x093A, 0x093A, Gcb::Extend),
+    (0x093B, 0x093B, Gcb::SpacingMark),
+    (0x093C, 0x093C, Gcb::Extend),
+    (0x093E, 0x0940, Gcb::SpacingMark),
+    (0x0941, 0x0948, Gcb::Extend),
+    (0x0949, 0x094C, Gcb::SpacingMark),
+    (0x094D, 0x094D, Gcb::Extend),
+    (0x094E, 0x094F, Gcb::SpacingMark),
+    (0x0951, 0x0957, Gcb::Extend),
+    (0x0962, 0x0963, Gcb::Extend),
+    (0x0981, 0x0981, Gcb::Extend),
+    (0x0982, 0x0983, Gcb::SpacingMark),
+    (0x09BC, 0x09BC, Gcb::Extend),
+    (0x09BE, 0x09BE, Gcb::Extend),
+    (0x09BF, 0x09C0, Gcb::SpacingMark),
+    (0x09C1, 0x09C4, Gcb::Extend),
+    (0x09C7, 0x09C8, Gcb::SpacingMark),
+    (0x09CB, 0x09CC, Gcb::SpacingMark),
+    (0x09CD, 0x09CD, Gcb::Extend),
+    (0x09D7, 0x09D7, Gcb::Extend),
+    (0x09E2, 0x09E3, Gcb::Extend),
+    (0x09FE, 0x09FE, Gcb::Extend),
+    (0x0A01, 0x0A02, Gcb::Extend),
+    (0x0A03, 0x0A03, Gcb::SpacingMark),
+    (0x0A3C, 0x0A3C, Gcb::Extend),
+    (0x0A3E, 0x0A40, Gcb::SpacingMark),
+    (0x0A41, 0x0A42, Gcb::Extend),
+    (0x0A47, 0x0A48, Gcb::Extend),
+    (0x0A4B, 0x0A4D, Gcb::Extend),
+    (0x0A51, 0x0A51, Gcb::Extend),
+    (0x0A70, 0x0A71, Gcb::Extend),
+    (0x0A75, 0x0A75, Gcb::Extend),
+    (0x0A81, 0x0A82, Gcb::Extend),
+    (0x0A83, 0x0A83, Gcb::SpacingMark),
+    (0x0ABC, 0x0ABC, Gcb::Extend),
+    (0x0ABE, 0x0AC0, Gcb::SpacingMark),
+    (0x0AC1, 0x0AC5, Gcb::Extend),
+    (0x0AC7, 0x0AC8, Gcb::Extend),
+    (0x0AC9, 0x0AC9, Gcb::SpacingMark),
+    (0x0ACB, 0x0ACC, Gcb::SpacingMark),
+    (0x0ACD, 0x0ACD, Gcb::Extend),
+    (0x0AE2, 0x0AE3, Gcb::Extend),
+    (0x0AFA, 0x0AFF, Gcb::Extend),
+    (0x0B01, 0x0B01, Gcb::Extend),
+    (0x0B02, 0x0B03, Gcb::SpacingMark),
+    (0x0B3C, 0x0B3C, Gcb::Extend),
+    (0x0B3E, 0x0B3F, Gcb::Extend),
+    (0x0B40, 0x0B40, Gcb::SpacingMark),
+    (0x0B41, 0x0B44, Gcb::Extend),
+    (0x0B47, 0x0B48, Gcb::SpacingMark),
+    (0x0B4B, 0x0B4C, Gcb::SpacingMark),
+    (0x0B4D, 0x0B4D, Gcb::Extend),
+    (0x0B55, 0x0B57, Gcb::Extend),
+    (0x0B62, 0x0B63, Gcb::Extend),
+    (0x0B82, 0x0B82, Gcb::Extend),
+    (0x0BBE, 0x0BBE, Gcb::Extend),
+    (0x0BBF, 0x0BBF, Gcb::SpacingMark),
+    (0x0BC0, 0x0BC0, Gcb::Extend),
+    (0x0BC1, 0x0BC2, Gcb::SpacingMark),
+    (0x0BC6, 0x0BC8, Gcb::SpacingMark),
+    (0x0BCA, 0x0BCC, Gcb::SpacingMark),
+    (0x0BCD, 0x0BCD, Gcb::Extend),
+    (0x0BD7, 0x0BD7, Gcb::Extend),
+    (0x0C00, 0x0C00, Gcb::Extend),
+    (0x0C01, 0x0C03, Gcb::SpacingMark),
+    (0x0C04, 0x0C04, Gcb::Extend),
+    (0x0C3C, 0x0C3C, Gcb::Extend),
+    (0x0C3E, 0x0C40, Gcb::Extend),
+    (0x0C41, 0x0C44, Gcb::SpacingMark),
+    (0x0C46, 0x0C48, Gcb::Extend),
+    (0x0C4A, 0x0C4D, Gcb::Extend),
+    (0x0C55, 0x0C56, Gcb::Extend),
+    (0x0C62, 0x0C63, Gcb::Extend),
+    (0x0C81, 0x0C81, Gcb::Extend),
+    (0x0C82, 0x0C83, Gcb::SpacingMark),
+    (0x0CBC, 0x0CBC, Gcb::Extend),
+    (0x0CBE, 0x0CBE, Gcb::SpacingMark),
+    (0x0CBF, 0x0CC0, Gcb::Extend),
+    (0x0CC1, 0x0CC1, Gcb::SpacingMark),
+    (0x0CC2, 0x0CC2, Gcb::Extend),
+    (0x0CC3, 0x0CC4, Gcb::SpacingMark),
+    (0x0CC6, 0x0CC8, Gcb::Extend),
+    (0x0CCA, 0x0CCD, Gcb::Extend),
+    (0x0CD5, 0x0CD6, Gcb::Extend),
+    (0x0CE2, 0x0CE3, Gcb::Extend),
+    (0x0CF3, 0x0CF3, Gcb::SpacingMark),
+    (0x0D00, 0x0D01, Gcb::Extend),
+    (0x0D02, 0x0D03, Gcb::SpacingMark),
+    (0x0D3B, 0x0D3C, Gcb::Extend),
+    (0x0D3E, 0x0D3E, Gcb::Extend),
+    (0x0D3F, 0x0D40, Gcb::SpacingMark),
+    (0x0D41, 0x0D44, Gcb::Extend),
+    (0x0D46, 0x0D48, Gcb::SpacingMark),
+    (0x0D4A, 0x0D4C, Gcb::SpacingMark),
+    (0x0D4D, 0x0D4D, Gcb::Extend),
+    (0x0D4E, 0x0D4E, Gcb::Prepend),
+    (0x0D57, 0x0D57, Gcb::Extend),
+    (0x0D62, 0x0D63, Gcb::Extend),
+    (0x0D81, 0x0D81, Gcb::Extend),
+    (0x0D82, 0x0D83, Gcb::SpacingMark),
+    (0x0DCA, 0x0DCA, Gcb::Extend),
+    (0x0DCF, 0x0DCF, Gcb::Extend),
+    (0x0DD0, 0x0DD1, Gcb::SpacingMark),
+    (0x0DD2, 0x0DD4, Gcb::Extend),
+    (0x0DD6, 0x0DD6, Gcb::Extend),
+    (0x0DD8, 0x0DDE, Gcb::SpacingMark),
+    (0x0DDF, 0x0DDF, Gcb::Extend),
+    (0x0DF2, 0x0DF3, Gcb::SpacingMark),
+    (0x0E31, 0x0E31, Gcb::Extend),
+    (0x0E33, 0x0E33, Gcb::SpacingMark),
+    (0x0E34, 0x0E3A, Gcb::Extend),
+    (0x0E47, 0x0E4E, Gcb::Extend),
+    (0x0EB1, 0x0EB1, Gcb::Extend),
+    (0x0EB3, 0x0EB3, Gcb::SpacingMark),
+    (0x0EB4, 0x0EBC, Gcb::Extend),
+    (0x0EC8, 0x0ECE, Gcb::Extend),
+    (0x0F18, 0x0F19, Gcb::Extend),
+    (0x0F35, 0x0F35, Gcb::Extend),
+    (0x0F37, 0x0F37, Gcb::Extend),
+    (0x0F39, 0x0F39, Gcb::Extend),
+    (0x0F3E, 0x0F3F, Gcb::SpacingMark),
+    (0x0F71, 0x0F7E, Gcb::Extend),
+    (0x0F7F, 0x0F7F, Gcb::SpacingMark),
+    (0x0F80, 0x0F84, Gcb::Extend),
+    (0x0F86, 0x0F87, Gcb::Extend),
+    (0x0F8D, 0x0F97, Gcb::Extend),
+    (0x0F99, 0x0FBC, Gcb::Extend),
+    (0x0FC6, 0x0FC6, Gcb::Extend),
+    (0x102D, 0x1030, Gcb::Extend),
+    (0x1031, 0x1031, Gcb::SpacingMark),
+    (0x1032, 0x1037, Gcb::Extend),
+    (0x1039, 0x103A, Gcb::Extend),
+    (0x103B, 0x103C, Gcb::SpacingMark),
+    (0x103D, 0x103E, Gcb::Extend),
+    (0x1056, 0x1057, Gcb::SpacingMark),
+    (0x1058, 0x1059, Gcb::Extend),
+    (0x105E, 0x1060, Gcb::Extend),
+    (0x1071, 0x1074, Gcb::Extend),
+    (0x1082, 0x1082, Gcb::Extend),
+    (0x1084, 0x1084, Gcb::SpacingMark),
+    (0x1085, 0x1086, Gcb::Extend),
+    (0x108D, 0x108D, Gcb::Extend),
+    (0x109D, 0x109D, Gcb::Extend),
+    (0x1100, 0x115F, Gcb::L),
+    (0x1160, 0x11A7, Gcb::V),
+    (0x11A8, 0x11FF, Gcb::T),
+    (0x135D, 0x135F, Gcb::Extend),
+    (0x1712, 0x1715, Gcb::Extend),
+    (0x1732, 0x1734, Gcb::Extend),
+    (0x1752, 0x1753, Gcb::Extend),
+    (0x1772, 0x1773, Gcb::Extend),
+    (0x17B4, 0x17B5, Gcb::Extend),
+    (0x17B6, 0x17B6, Gcb::SpacingMark),
+    (0x17B7, 0x17BD, Gcb::Extend),
+    (0x17BE, 0x17C5, Gcb::SpacingMark),
+    (0x17C6, 0x17C6, Gcb::Extend),
+    (0x17C7, 0x17C8, Gcb::SpacingMark),
+    (0x17C9, 0x17D3, Gcb::Extend),
+    (0x17DD, 0x17DD, Gcb::Extend),
+    (0x180B, 0x180D, Gcb::Extend),
+    (0x180E, 0x180E, Gcb::Control),
+    (0x180F, 0x180F, Gcb::Extend),
+    (0x1885, 0x1886, Gcb::Extend),
+    (0x18A9, 0x18A9, Gcb::Extend),
+    (0x1920, 0x1922, Gcb::Extend),
+    (0x1923, 0x1926, Gcb::SpacingMark),
+    (0x1927, 0x1928, Gcb::Extend),
+    (0x1929, 0x192B, Gcb::SpacingMark),
+    (0x1930, 0x1931, Gcb::SpacingMark),
+    (0x1932, 0x1932, Gcb::Extend),
+    (0x1933, 0x1938, Gcb::SpacingMark),
+    (0x1939, 0x193B, Gcb::Extend),
+    (0x1A17, 0x1A18, Gcb::Extend),
+    (0x1A19, 0x1A1A, Gcb::SpacingMark),
+    (0x1A1B, 0x1A1B, Gcb::Extend),
+    (0x1A55, 0x1A55, Gcb::SpacingMark),
+    (0x1A56, 0x1A56, Gcb::Extend),
+    (0x1A57, 0x1A57, Gcb::SpacingMark),
+    (0x1A58, 0x1A5E, Gcb::Extend),
+    (0x1A60, 0x1A60, Gcb::Extend),
+    (0x1A62, 0x1A62, Gcb::Extend),
+    (0x1A65, 0x1A6C, Gcb::Extend),
+    (0x1A6D, 0x1A72, Gcb::SpacingMark),
+    (0x1A73, 0x1A7C, Gcb::Extend),
+    (0x1A7F, 0x1A7F, Gcb::Extend),
+    (0x1AB0, 0x1ADD, Gcb::Extend),
+    (0x1AE0, 0x1AEB, Gcb::Extend),
+    (0x1B00, 0x1B03, Gcb::Extend),
+    (0x1B04, 0x1B04, Gcb::SpacingMark),
+    (0x1B34, 0x1B3D, Gcb::Extend),
+    (0x1B3E, 0x1B41, Gcb::SpacingMark),
+    (0x1B42, 0x1B44, Gcb::Extend),
+    (0x1B6B, 0x1B73, Gcb::Extend),
+    (0x1B80, 0x1B81, Gcb::Extend),
+    (0x1B82, 0x1B82, Gcb::SpacingMark),
+    (0x1BA1, 0x1BA1, Gcb::SpacingMark),
+    (0x1BA2, 0x1BA5, Gcb::Extend),
+    (0x1BA6, 0x1BA7, Gcb::SpacingMark),
+    (0x1BA8, 0x1BAD, Gcb::Extend),
+    (0x1BE6, 0x1BE6, Gcb::Extend),
+    (0x1BE7, 0x1BE7, Gcb::SpacingMark),
+    (0x1BE8, 0x1BE9, Gcb::Extend),
+    (0x1BEA, 0x1BEC, Gcb::SpacingMark),
+    (0x1BED, 0x1BED, Gcb::Extend),
+    (0x1BEE, 0x1BEE, Gcb::SpacingMark),
+    (0x1BEF, 0x1BF3, Gcb::Extend),
+    (0x1C24, 0x1C2B, Gcb::SpacingMark),
+    (0x1C2C, 0x1C33, Gcb::Extend),
+    (0x1C34, 0x1C35, Gcb::SpacingMark),
+    (0x1C36, 0x1C37, Gcb::Extend),
+    (0x1CD0, 0x1CD2, Gcb::Extend),
+    (0x1CD4, 0x1CE0, Gcb::Extend),
+    (0x1CE1, 0x1CE1, Gcb::SpacingMark),
+    (0x1CE2, 0x1CE8, Gcb::Extend),
+    (0x1CED, 0x1CED, Gcb::Extend),
+    (0x1CF4, 0x1CF4, Gcb::Extend),
+    (0x1CF7, 0x1CF7, Gcb::SpacingMark),
+    (0x1CF8, 0x1CF9, Gcb::Extend),
+    (0x1DC0, 0x1DFF, Gcb::Extend),
+    (0x200B, 0x200B, Gcb::Control),
+    (0x200C, 0x200C, Gcb::Extend),
+    (0x200D, 0x200D, Gcb::ZWJ),
+    (0x200E, 0x200F, Gcb::Control),
+    (0x2028, 0x202E, Gcb::Control),
+    (0x2060, 0x206F, Gcb::Control),
+    (0x20D0, 0x20F0, Gcb::Extend),
+    (0x2CEF, 0x2CF1, Gcb::Extend),
+    (0x2D7F, 0x2D7F, Gcb::Extend),
+    (0x2DE0, 0x2DFF, Gcb::Extend),
+    (0x302A, 0x302F, Gcb::Extend),
+    (0x3099, 0x309A, Gcb::Extend),
+    (0xA66F, 0xA672, Gcb::Extend),
+    (0xA674, 0xA67D, Gcb::Extend),
+    (0xA69E, 0xA69F, Gcb::Extend),
+    (0xA6F0, 0xA6F1, Gcb::Extend),
+    (0xA802, 0xA802, Gcb::Extend),
+    (0xA806, 0xA806, Gcb::Extend),
+    (0xA80B, 0xA80B, Gcb::Extend),
+    (0xA823, 0xA824, Gcb::SpacingMark),
+    (0xA825, 0xA826, Gcb::Extend),
+    (0xA827, 0xA827, Gcb::SpacingMark),
+    (0xA82C, 0xA82C, Gcb::Extend),
+    (0xA880, 0xA881, Gcb::SpacingMark),
+    (0xA8B4, 0xA8C3, Gcb::SpacingMark),
+    (0xA8C4, 0xA8C5, Gcb::Extend),
+    (0xA8E0, 0xA8F1, Gcb::Extend),
+    (0xA8FF, 0xA8FF, Gcb::Extend),
+    (0xA926, 0xA92D, Gcb::Extend),
+    (0xA947, 0xA951, Gcb::Extend),
+    (0xA952, 0xA952, Gcb::SpacingMark),
+    (0xA953, 0xA953, Gcb::Extend),
+    (0xA960, 0xA97C, Gcb::L),
+    (0xA980, 0xA982, Gcb::Extend),
+    (0xA983, 0xA983, Gcb::SpacingMark),
+    (0xA9B3, 0xA9B3, Gcb::Extend),
+    (0xA9B4, 0xA9B5, Gcb::SpacingMark),
+    (0xA9B6, 0xA9B9, Gcb::Extend),
+    (0xA9BA, 0xA9BB, Gcb::SpacingMark),
+    (0xA9BC, 0xA9BD, Gcb::Extend),
+    (0xA9BE, 0xA9BF, Gcb::SpacingMark),
+    (0xA9C0, 0xA9C0, Gcb::Extend),
+    (0xA9E5, 0xA9E5, Gcb::Extend),
+    (0xAA29, 0xAA2E, Gcb::Extend),
+    (0xAA2F, 0xAA30, Gcb::SpacingMark),
+    (0xAA31, 0xAA32, Gcb::Extend),
+    (0xAA33, 0xAA34, Gcb::SpacingMark),
+    (0xAA35, 0xAA36, Gcb::Extend),
+    (0xAA43, 0xAA43, Gcb::Extend),
+    (0xAA4C, 0xAA4C, Gcb::Extend),
+    (0xAA4D, 0xAA4D, Gcb::SpacingMark),
+    (0xAA7C, 0xAA7C, Gcb::Extend),
+    (0xAAB0, 0xAAB0, Gcb::Extend),
+    (0xAAB2, 0xAAB4, Gcb::Extend),
+    (0xAAB7, 0xAAB8, Gcb::Extend),
+    (0xAABE, 0xAABF, Gcb::Extend),
+    (0xAAC1, 0xAAC1, Gcb::Extend),
+    (0xAAEB, 0xAAEB, Gcb::SpacingMark),
+    (0xAAEC, 0xAAED, Gcb::Extend),
+    (0xAAEE, 0xAAEF, Gcb::SpacingMark),
+    (0xAAF5, 0xAAF5, Gcb::SpacingMark),
+    (0xAAF6, 0xAAF6, Gcb::Extend),
+    (0xABE3, 0xABE4, Gcb::SpacingMark),
+    (0xABE5, 0xABE5, Gcb::Extend),
+    (0xABE6, 0xABE7, Gcb::SpacingMark),
+    (0xABE8, 0xABE8, Gcb::Extend),
+    (0xABE9, 0xABEA, Gcb::SpacingMark),
+    (0xABEC, 0xABEC, Gcb::SpacingMark),
+    (0xABED, 0xABED, Gcb::Extend),
+    (0xD7B0, 0xD7C6, Gcb::V),
+    (0xD7CB, 0xD7FB, Gcb::T),
+    (0xFB1E, 0xFB1E, Gcb::Extend),
+    (0xFE00, 0xFE0F, Gcb::Extend),
+    (0xFE20, 0xFE2F, Gcb::Extend),
+    (0xFEFF, 0xFEFF, Gcb::Control),
+    (0xFF9E, 0xFF9F, Gcb::Extend),
+    (0xFFF0, 0xFFFB, Gcb::Control),
+    (0x101FD, 0x101FD, Gcb::Extend),
+    (0x102E0, 0x102E0, Gcb::Extend),
+    (0x10376, 0x1037A, Gcb::Extend),
+    (0x10A01, 0x10A03, Gcb::Extend),
+    (0x10A05, 0x10A06, Gcb::Extend),
+    (0x10A0C, 0x10A0F, Gcb::Extend),
+    (0x10A38, 0x10A3A, Gcb::Extend),
+    (0x10A3F, 0x10A3F, Gcb::Extend),
+    (0x10AE5, 0x10AE6, Gcb::Extend),
+    (0x10D24, 0x10D27, Gcb::Extend),
+    (0x10D69, 0x10D6D, Gcb::Extend),
+    (0x10EAB, 0x10EAC, Gcb::Extend),
+    (0x10EFA, 0x10EFF, Gcb::Extend),
+    (0x10F46, 0x10F50, Gcb::Extend),
+    (0x10F82, 0x10F85, Gcb::Extend),
+    (0x11000, 0x11000, Gcb::SpacingMark),
+    (0x11001, 0x11001, Gcb::Extend),
+    (0x11002, 0x11002, Gcb::SpacingMark),
+    (0x11038, 0x11046, Gcb::Extend),
+    (0x11070, 0x11070, Gcb::Extend),
+    (0x11073, 0x11074, Gcb::Extend),
+    (0x1107F, 0x11081, Gcb::Extend),
+    (0x11082, 0x11082, Gcb::SpacingMark),
+    (0x110B0, 0x110B2, Gcb::SpacingMark),
+    (0x110B3, 0x110B6, Gcb::Extend),
+    (0x110B7, 0x110B8, Gcb::SpacingMark),
+    (0x110B9, 0x110BA, Gcb::Extend),
+    (0x110BD, 0x110BD, Gcb::Prepend),
+    (0x110C2, 0x110C2, Gcb::Extend),
+    (0x110CD, 0x110CD, Gcb::Prepend),
+    (0x11100, 0x11102, Gcb::Extend),
+    (0x11127, 0x1112B, Gcb::Extend),
+    (0x1112C, 0x1112C, Gcb::SpacingMark),
+    (0x1112D, 0x11134, Gcb::Extend),
+    (0x11145, 0x11146, Gcb::SpacingMark),
+    (0x11173, 0x11173, Gcb::Extend),
+    (0x11180, 0x11181, Gcb::Extend),
+    (0x11182, 0x11182, Gcb::SpacingMark),
+    (0x111B3, 0x111B5, Gcb::SpacingMark),
+    (0x111B6, 0x111BE, Gcb::Extend),
+    (0x111BF, 0x111BF, Gcb::SpacingMark),
+    (0x111C0, 0x111C0, Gcb::Extend),
+    (0x111C2, 0x111C3, Gcb::Prepend),
+    (0x111C9, 0x111CC, Gcb::Extend),
+    (0x111CE, 0x111CE, Gcb::SpacingMark),
+    (0x111CF, 0x111CF, Gcb::Extend),
+    (0x1122C, 0x1122E, Gcb::SpacingMark),
+    (0x1122F, 0x11231, Gcb::Extend),
+    (0x11232, 0x11233, Gcb::SpacingMark),
+    (0x11234, 0x11237, Gcb::Extend),
+    (0x1123E, 0x1123E, Gcb::Extend),
+    (0x11241, 0x11241, Gcb::Extend),
+    (0x112DF, 0x112DF, Gcb::Extend),
+    (0x112E0, 0x112E2, Gcb::SpacingMark),
+    (0x112E3, 0x112EA, Gcb::Extend),
+    (0x11300, 0x11301, Gcb::Extend),
+    (0x11302, 0x11303, Gcb::SpacingMark),
+    (0x1133B, 0x1133C, Gcb::Extend),
+    (0x1133E, 0x1133E, Gcb::Extend),
+    (0x1133F, 0x1133F, Gcb::SpacingMark),
+    (0x11340, 0x11340, Gcb::Extend),
+    (0x11341, 0x11344, Gcb::SpacingMark),
+    (0x11347, 0x11348, Gcb::SpacingMark),
+    (0x1134B, 0x1134C, Gcb::SpacingMark),
+    (0x1134D, 0x1134D, Gcb::Extend),
+    (0x11357, 0x11357, Gcb::Extend),
+    (0x11362, 0x11363, Gcb::SpacingMark),
+    (0x11366, 0x1136C, Gcb::Extend),
+    (0x11370, 0x11374, Gcb::Extend),
+    (0x113B8, 0x113B8, Gcb::Extend),
+    (0x113B9, 0x113BA, Gcb::SpacingMark),
+    (0x113BB, 0x113C0, Gcb::Extend),
+    (0x113C2, 0x113C2, Gcb::Extend),
+    (0x113C5, 0x113C5, Gcb::Extend),
+    (0x113C7, 0x113C9, Gcb::Extend),
+    (0x113CA, 0x113CA, Gcb::SpacingMark),
+    (0x113CC, 0x113CD, Gcb::SpacingMark),
+    (0x113CE, 0x113D0, Gcb::Extend),
+    (0x113D1, 0x113D1, Gcb::Prepend),
+    (0x113D2, 0x113D2, Gcb::Extend),
+    (0x113E1, 0x113E2, Gcb::Extend),
+    (0x11435, 0x11437, Gcb::SpacingMark),
+    (0x11438, 0x1143F, Gcb::Extend),
+    (0x11440, 0x11441, Gcb::SpacingMark),
+    (0x11442, 0x11444, Gcb::Extend),
+    (0x11445, 0x11445, Gcb::SpacingMark),
+    (0x11446, 0x11446, Gcb::Extend),
+    (0x1145E, 0x1145E, Gcb::Extend),
+    (0x114B0, 0x114B0, Gcb::Extend),
+    (0x114B1, 0x114B2, Gcb::SpacingMark),
+    (0x114B3, 0x114B8, Gcb::Extend),
+    (0x114B9, 0x114B9, Gcb::SpacingMark),
+    (0x114BA, 0x114BA, Gcb::Extend),
+    (0x114BB, 0x114BC, Gcb::SpacingMark),
+    (0x114BD, 0x114BD, Gcb::Extend),
+    (0x114BE, 0x114BE, Gcb::SpacingMark),
+    (0x114BF, 0x114C0, Gcb::Extend),
+    (0x114C1, 0x114C1, Gcb::SpacingMark),
+    (0x114C2, 0x114C3, Gcb::Extend),
+    (0x115AF, 0x115AF, Gcb::Extend),
+    (0x115B0, 0x115B1, Gcb::SpacingMark),
+    (0x115B2, 0x115B5, Gcb::Extend),
+    (0x115B8, 0x115BB, Gcb::SpacingMark),
+    (0x115BC, 0x115BD, Gcb::Extend),
+    (0x115BE, 0x115BE, Gcb::SpacingMark),
+    (0x115BF, 0x115C0, Gcb::Extend),
+    (0x115DC, 0x115DD, Gcb::Extend),
+    (0x11630, 0x11632, Gcb::SpacingMark),
+    (0x11633, 0x1163A, Gcb::Extend),
+    (0x1163B, 0x1163C, Gcb::SpacingMark),
+    (0x1163D, 0x1163D, Gcb::Extend),
+    (0x1163E, 0x1163E, Gcb::SpacingMark),
+    (0x1163F, 0x11640, Gcb::Extend),
+    (0x116AB, 0x116AB, Gcb::Extend),
+    (0x116AC, 0x116AC, Gcb::SpacingMark),
+    (0x116AD, 0x116AD, Gcb::Extend),
+    (0x116AE, 0x116AF, Gcb::SpacingMark),
+    (0x116B0, 0x116B7, Gcb::Extend),
+    (0x1171D, 0x1171D, Gcb::Extend),
+    (0x1171E, 0x1171E, Gcb::SpacingMark),
+    (0x1171F, 0x1171F, Gcb::Extend),
+    (0x11722, 0x11725, Gcb::Extend),
+    (0x11726, 0x11726, Gcb::SpacingMark),
+    (0x11727, 0x1172B, Gcb::Extend),
+    (0x1182C, 0x1182E, Gcb::SpacingMark),
+    (0x1182F, 0x11837, Gcb::Extend),
+    (0x11838, 0x11838, Gcb::SpacingMark),
+    (0x11839, 0x1183A, Gcb::Extend),
+    (0x11930, 0x11930, Gcb::Extend),
+    (0x11931, 0x11935, Gcb::SpacingMark),
+    (0x11937, 0x11938, Gcb::SpacingMark),
+    (0x1193B, 0x1193E, Gcb::Extend),
+    (0x1193F, 0x1193F, Gcb::Prepend),
+    (0x11940, 0x11940, Gcb::SpacingMark),
+    (0x11941, 0x11941, Gcb::Prepend),
+    (0x11942, 0x11942, Gcb::SpacingMark),
+    (0x11943, 0x11943, Gcb::Extend),
+    (0x119D1, 0x119D3, Gcb::SpacingMark),
+    (0x119D4, 0x119D7, Gcb::Extend),
+    (0x119DA, 0x119DB, Gcb::Extend),
+    (0x119DC, 0x119DF, Gcb::SpacingMark),
+    (0x119E0, 0x119E0, Gcb::Extend),
+    (0x119E4, 0x119E4, Gcb::SpacingMark),
+    (0x11A01, 0x11A0A, Gcb::Extend),
+    (0x11A33, 0x11A38, Gcb::Extend),
+    (0x11A39, 0x11A39, Gcb::SpacingMark),
+    (0x11A3B, 0x11A3E, Gcb::Extend),
+    (0x11A47, 0x11A47, Gcb::Extend),
+    (0x11A51, 0x11A56, Gcb::Extend),
+    (0x11A57, 0x11A58, Gcb::SpacingMark),
+    (0x11A59, 0x11A5B, Gcb::Extend),
+    (0x11A84, 0x11A89, Gcb::Prepend),
+    (0x11A8A, 0x11A96, Gcb::Extend),
+    (0x11A97, 0x11A97, Gcb::SpacingMark),
+    (0x11A98, 0x11A99, Gcb::Extend),
+    (0x11B60, 0x11B60, Gcb::Extend),
+    (0x11B61, 0x11B61, Gcb::SpacingMark),
+    (0x11B62, 0x11B64, Gcb::Extend),
+    (0x11B65, 0x11B65, Gcb::SpacingMark),
+    (0x11B66, 0x11B66, Gcb::Extend),
+    (0x11B67, 0x11B67, Gcb::SpacingMark),
+    (0x11C2F, 0x11C2F, Gcb::SpacingMark),
+    (0x11C30, 0x11C36, Gcb::Extend),
+    (0x11C38, 0x11C3D, Gcb::Extend),
+    (0x11C3E, 0x11C3E, Gcb::SpacingMark),
+    (0x11C3F, 0x11C3F, Gcb::Extend),
+    (0x11C92, 0x11CA7, Gcb::Extend),
+    (0x11CA9, 0x11CA9, Gcb::SpacingMark),
+    (0x11CAA, 0x11CB0, Gcb::Extend),
+    (0x11CB1, 0x11CB1, Gcb::SpacingMark),
+    (0x11CB2, 0x11CB3, Gcb::Extend),
+    (0x11CB4, 0x11CB4, Gcb::SpacingMark),
+    (0x11CB5, 0x11CB6, Gcb::Extend),
+    (0x11D31, 0x11D36, Gcb::Extend),
+    (0x11D3A, 0x11D3A, Gcb::Extend),
+    (0x11D3C, 0x11D3D, Gcb::Extend),
+    (0x11D3F, 0x11D45, Gcb::Extend),
+    (0x11D46, 0x11D46, Gcb::Prepend),
+    (0x11D47, 0x11D47, Gcb::Extend),
+    (0x11D8A, 0x11D8E, Gcb::SpacingMark),
+    (0x11D90, 0x11D91, Gcb::Extend),
+    (0x11D93, 0x11D94, Gcb::SpacingMark),
+    (0x11D95, 0x11D95, Gcb::Extend),
+    (0x11D96, 0x11D96, Gcb::SpacingMark),
+    (0x11D97, 0x11D97, Gcb::Extend),
+    (0x11EF3, 0x11EF4, Gcb::Extend),
+    (0x11EF5, 0x11EF6, Gcb::SpacingMark),
+    (0x11F00, 0x11F01, Gcb::Extend),
+    (0x11F02, 0x11F02, Gcb::Prepend),
+    (0x11F03, 0x11F03, Gcb::SpacingMark),
+    (0x11F34, 0x11F35, Gcb::SpacingMark),
+    (0x11F36, 0x11F3A, Gcb::Extend),
+    (0x11F3E, 0x11F3F, Gcb::SpacingMark),
+    (0x11F40, 0x11F42, Gcb::Extend),
+    (0x11F5A, 0x11F5A, Gcb::Extend),
+    (0x13430, 0x1343F, Gcb::Control),
+    (0x13440, 0x13440, Gcb::Extend),
+    (0x13447, 0x13455, Gcb::Extend),
+    (0x1611E, 0x16129, Gcb::Extend),
+    (0x1612A, 0x1612C, Gcb::SpacingMark),
+    (0x1612D, 0x1612F, Gcb::Extend),
+    (0x16AF0, 0x16AF4, Gcb::Extend),
+    (0x16B30, 0x16B36, Gcb::Extend),
+    (0x16D63, 0x16D63, Gcb::V),
+    (0x16D67, 0x16D6A, Gcb::V),
+    (0x16F4F, 0x16F4F, Gcb::Extend),
+    (0x16F51, 0x16F87, Gcb::SpacingMark),
+    (0x16F8F, 0x16F92, Gcb::Extend),
+    (0x16FE4, 0x16FE4, Gcb::Extend),
+    (0x16FF0, 0x16FF1, Gcb::Extend),
+    (0x1BC9D, 0x1BC9E, Gcb::Extend),
+    (0x1BCA0, 0x1BCA3, Gcb::Control),
+    (0x1CF00, 0x1CF2D, Gcb::Extend),
+    (0x1CF30, 0x1CF46, Gcb::Extend),
+    (0x1D165, 0x1D169, Gcb::Extend),
+    (0x1D16D, 0x1D172, Gcb::Extend),
+    (0x1D173, 0x1D17A, Gcb::Control),
+    (0x1D17B, 0x1D182, Gcb::Extend),
+    (0x1D185, 0x1D18B, Gcb::Extend),
+    (0x1D1AA, 0x1D1AD, Gcb::Extend),
+    (0x1D242, 0x1D244, Gcb::Extend),
+    (0x1DA00, 0x1DA36, Gcb::Extend),
+    (0x1DA3B, 0x1DA6C, Gcb::Extend),
+    (0x1DA75, 0x1DA75, Gcb::Extend),
+    (0x1DA84, 0x1DA84, Gcb::Extend),
+    (0x1DA9B, 0x1DA9F, Gcb::Extend),
+    (0x1DAA1, 0x1DAAF, Gcb::Extend),
+    (0x1E000, 0x1E006, Gcb::Extend),
+    (0x1E008, 0x1E018, Gcb::Extend),
+    (0x1E01B, 0x1E021, Gcb::Extend),
+    (0x1E023, 0x1E024, Gcb::Extend),
+    (0x1E026, 0x1E02A, Gcb::Extend),
+    (0x1E08F, 0x1E08F, Gcb::Extend),
+    (0x1E130, 0x1E136, Gcb::Extend),
+    (0x1E2AE, 0x1E2AE, Gcb::Extend),
+    (0x1E2EC, 0x1E2EF, Gcb::Extend),
+    (0x1E4EC, 0x1E4EF, Gcb::Extend),
+    (0x1E5EE, 0x1E5EF, Gcb::Extend),
+    (0x1E6E3, 0x1E6E3, Gcb::Extend),
+    (0x1E6E6, 0x1E6E6, Gcb::Extend),
+    (0x1E6EE, 0x1E6EF, Gcb::Extend),
+    (0x1E6F5, 0x1E6F5, Gcb::Extend),
+    (0x1E8D0, 0x1E8D6, Gcb::Extend),
+    (0x1E944, 0x1E94A, Gcb::Extend),
+    (0x1F1E6, 0x1F1FF, Gcb::RegionalIndicator),
+    (0x1F3FB, 0x1F3FF, Gcb::Extend),
+    (0xE0000, 0xE001F, Gcb::Control),
+    (0xE0020, 0xE007F, Gcb::Extend),
+    (0xE0080, 0xE00FF, Gcb::Control),
+    (0xE0100, 0xE01EF, Gcb::Extend),
+    (0xE01F0, 0xE0FFF, Gcb::Control),
+];
+
+// Indic_Conjunct_Break property
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InCb {
+    Consonant,
+    Linker,
+    Extend,
+}
+
+// From DerivedCoreProperties.txt, code points not in the table are None
+pub const INDIC_CONJUNCT_BREAK: &[(u32, u32, InCb)] = &[
+    (0x0300, 0x036F, InCb::Extend),
+    (0x0483, 0x0489, InCb::Extend),
+    (0x0591, 0x05BD, InCb::Extend),
+    (0x05BF, 0x05BF, InCb::Extend),
+    (0x05C1, 0x05C2, InCb::Extend),
+    (0x05C4, 0x05C5, InCb::Extend),
+    (0x05C7, 0x05C7, InCb::Extend),
+    (0x0610, 0x061A, InCb::Extend),
+    (0x064B, 0x065F, InCb::Extend),
+    (0x0670, 0x0670, InCb::Extend),
+    (0x06D6, 0x06DC, InCb::Extend),
+    (0x06DF, 0x06E4, InCb::Extend),
+    (0x06E7, 0x06E8, InCb::Extend),
+    (0x06EA, 0x06ED, InCb::Extend),
+    (0x0711, 0x0711, InCb::Extend),
+    (0x0730, 0x074A, InCb::Extend),
+    (0x07A6, 0x07B0, InCb::Extend),
+    (0x07EB, 0x07F3, InCb::Extend),
+    (0x07FD, 0x07FD, InCb::Extend),
+    (0x0816, 0x0819, InCb::Extend),
+    (0x081B, 0x0823, InCb::Extend),
+    (0x0825, 0x0827, InCb::Extend),
+    (0x0829, 0x082D, InCb::Extend),
+    (0x0859, 0x085B, InCb::Extend),
+    (0x0897, 0x089F, InCb::Extend),
+    (0x08CA, 0x08E1, InCb::Extend),
+    (0x08E3, 0x0902, InCb::Extend),
+    (0x0915, 0x0939, InCb::Consonant),
+    (0x093A, 0x093A, InCb::Extend),
+    (0x093C, 0x093C, InCb::Extend),
+    (0x0941, 0x0948, InCb::Extend),
+    (0x094D, 0x094D, InCb::Linker),
+    (0x0951, 0x0957, InCb::Extend),
+    (0x0958, 0x095F, InCb::Consonant),
+    (0x0962, 0x0963, InCb::Extend),
+    (0x0978, 0x097F, InCb::Consonant),
+    (0x0981, 0x0981, InCb::Extend),
+    (0x0995, 0x09A8, InCb::Consonant),
+    (0x09AA, 0x09B0, InCb::Consonant),
+    (0x09B2, 0x09B2, InCb::Consonant),
+    (0x09B6, 0x09B9, InCb::Consonant),
+    (0x09BC, 0x09BC, InCb::Extend),
+    (0x09BE, 0x09BE, InCb::Extend),
+    (0x09C1, 0x09C4, InCb::Extend),
+    (0x09CD, 0x09CD, InCb::Linker),
+    (0x09D7, 0x09D7, InCb::Extend),
+    (0x09DC, 0x09DD, InCb::Consonant),
+    (0x09DF, 0x09DF, InCb::Consonant),
+    (0x09E2, 0x09E3, InCb::Extend),
+    (0x09F0, 0x09F1, InCb::Consonant),
+    (0x09FE, 0x09FE, InCb::Extend),
+    (0x0A01, 0x0A02, InCb::Extend),
+    (0x0A3C, 0x0A3C, InCb::Extend),
+    (0x0A41, 0x0A42, InCb::Extend),
+    (0x0A47, 0x0A48, InCb::Extend),
+    (0x0A4B, 0x0A4D, InCb::Extend),
+    (0x0A51, 0x0A51, InCb::Extend),
+    (0x0A70, 0x0A71, InCb::Extend),
+    (0x0A75, 0x0A75, InCb::Extend),
+    (0x0A81, 0x0A82, InCb::Extend),
+    (0x0A95, 0x0AA8, InCb::Consonant),
+    (0x0AAA, 0x0AB0, InCb::Consonant),
+    (0x0AB2, 0x0AB3, InCb::Consonant),
+    (0x0AB5, 0x0AB9, InCb::Consonant),
+    (0x0ABC, 0x0ABC, InCb::Extend),
+    (0x0AC1, 0x0AC5, InCb::Extend),
+    (0x0AC7, 0x0AC8, InCb::Extend),
+    (0x0ACD, 0x0ACD, InCb::Linker),
+    (0x0AE2, 0x0AE3, InCb::Extend),
+    (0x0AF9, 0x0AF9, InCb::Consonant),
+    (0x0AFA, 0x0AFF, InCb::Extend),
+    (0x0B01, 0x0B01, InCb::Extend),
+    (0x0B15, 0x0B28, InCb::Consonant),
+    (0x0B2A, 0x0B30, InCb::Consonant),
+    (0x0B32, 0x0B33, InCb::Consonant),
+    (0x0B35, 0x0B39, InCb::Consonant),
+    (0x0B3C, 0x0B3C, InCb::Extend),
+    (0x0B3E, 0x0B3F, InCb::Extend),
+    (0x0B41, 0x0B44, InCb::Extend),
+    (0x0B4D, 0x0B4D, InCb::Linker),
+    (0x0B55, 0x0B57, InCb::Extend),
+    (0x0B5C, 0x0B5D, InCb::Consonant),
+    (0x0B5F, 0x0B5F, InCb::Consonant),
+    (0x0B62, 0x0B63, InCb::Extend),
+    (0x0B71, 0x0B71, InCb::Consonant),
+    (0x0B82, 0x0B82, InCb::Extend),
+    (0x0BBE, 0x0BBE, InCb::Extend),
+    (0x0BC0, 0x0BC0, InCb::Extend),
+    (0x0BCD, 0x0BCD, InCb::Extend),
+    (0x0BD7, 0x0BD7, InCb::Extend),
+    (0x0C00, 0x0C00, InCb::Extend),
+    (0x0C04, 0x0C04, InCb::Extend),
+    (0x0C15, 0x0C28, InCb::Consonant),
+    (0x0C2A, 0x0C39, InCb::Consonant),
+    (0x0C3C, 0x0C3C, InCb::Extend),
+    (0x0C3E, 0x0C40, InCb::Extend),
+    (0x0C46, 0x0C48, InCb::Extend),
+    (0x0C4A, 0x0C4C, InCb::Extend),
+    (0x0C4D, 0x0C4D, InCb::Linker),
+    (0x0C55, 0x0C56, InCb::Extend),
+    (0x0C58, 0x0C5A, InCb::Consonant),
+    (0x0C62, 0x0C63, InCb::Extend),
+    (0x0C81, 0x0C81, InCb::Extend),
+    (0x0CBC, 0x0CBC, InCb::Extend),
+    (0x0CBF, 0x0CC0, InCb::Extend),
+    (0x0CC2, 0x0CC2, InCb::Extend),
+    (0x0CC6, 0x0CC8, InCb::Extend),
+    (0x0CCA, 0x0CCD, InCb::Extend),
+    (0x0CD5, 0x0CD6, InCb::Extend),
+    (0x0CE2, 0x0CE3, InCb::Extend),
+    (0x0D00, 0x0D01, InCb::Extend),
+    (0x0D15, 0x0D3A, InCb::Consonant),
+    (0x0D3B, 0x0D3C, InCb::Extend),
+    (0x0D3E, 0x0D3E, InCb::Extend),
+    (0x0D41, 0x0D44, InCb::Extend),
+    (0x0D4D, 0x0D4D, InCb::Linker),
+    (0x0D57, 0x0D57, InCb::Extend),
+    (0x0D62, 0x0D63, InCb::Extend),
+    (0x0D81, 0x0D81, InCb::Extend),
+    (0x0DCA, 0x0DCA, InCb::Extend),
+    (0x0DCF, 0x0DCF, InCb::Extend),
+    (0x0DD2, 0x0DD4, InCb::Extend),
+    (0x0DD6, 0x0DD6, InCb::Extend),
+    (0x0DDF, 0x0DDF, InCb::Extend),
+    (0x0E31, 0x0E31, InCb::Extend),
+    (0x0E34, 0x0E3A, InCb::Extend),
+    (0x0E47, 0x0E4E, InCb::Extend),
+    (0x0EB1, 0x0EB1, InCb::Extend),
+    (0x0EB4, 0x0EBC, InCb::Extend),
+    (0x0EC8, 0x0ECE, InCb::Extend),
+    (0x0F18, 0x0F19, InCb::Extend),
+    (0x0F35, 0x0F35, InCb::Extend),
+    (0x0F37, 0x0F37, InCb::Extend),
+    (0x0F39, 0x0F39, InCb::Extend),
+    (0x0F71, 0x0F7E, InCb::Extend),
+    (0x0F80, 0x0F84, InCb::Extend),
+    (0x0F86, 0x0F87, InCb::Extend),
+    (0x0F8D, 0x0F97, InCb::Extend),
+    (0x0F99, 0x0FBC, InCb::Extend),
+    (0x0FC6, 0x0FC6, InCb::Extend),
+    (0x1000, 0x102A, InCb::Consonant),
+    (0x102D, 0x1030, InCb::Extend),
+    (0x1032, 0x1037, InCb::Extend),
+    (0x1039, 0x1039, InCb::Linker),
+    (0x103A, 0x103A, InCb::Extend),
+    (0x103D, 0x103E, InCb::Extend),
+    (0x103F, 0x103F, InCb::Consonant),
+    (0x1050, 0x1055, InCb::Consonant),
+    (0x1058, 0x1059, InCb::Extend),
+    (0x105A, 0x105D, InCb::Consonant),
+    (0x105E, 0x1060, InCb::Extend),
+    (0x1061, 0x1061, InCb::Consonant),
+    (0x1065, 0x1066, InCb::Consonant),
+    (0x106E, 0x1070, InCb::Consonant),
+    (0x1071, 0x1074, InCb::Extend),
+    (0x1075, 0x1081, InCb::Consonant),
+    (0x1082, 0x1082, InCb::Extend),
+    (0x1085, 0x1086, InCb::Extend),
+    (0x108D, 0x108D, InCb::Extend),
+    (0x108E, 0x108E, InCb::Consonant),
+    (0x109D, 0x109D, InCb::Extend),
+    (0x135D, 0x135F, InCb::Extend),
+    (0x1712, 0x1715, InCb::Extend),
+    (0x1732, 0x1734, InCb::Extend),
+    (0x1752, 0x1753, InCb::Extend),
+    (0x1772, 0x1773, InCb::Extend),
+    (0x1780, 0x17B3, InCb::Consonant),
+    (0x17B4, 0x17B5, InCb::Extend),
+    (0x17B7, 0x17BD, InCb::Extend),
+    (0x17C6, 0x17C6, InCb::Extend),
+    (0x17C9, 0x17D1, InCb::Extend),
+    (0x17D2, 0x17D2, InCb::Linker),
+    (0x17D3, 0x17D3, InCb::Extend),
+    (0x17DD, 0x17DD, InCb::Extend),
+    (0x180B, 0x180D, InCb::Extend),
+    (0x180F, 0x180F, InCb::Extend),
+    (0x1885, 0x1886, InCb::Extend),
+    (0x18A9, 0x18A9, InCb::Extend),
+    (0x1920, 0x1922, InCb::Extend),
+    (0x1927, 0x1928, InCb::Extend),
+    (0x1932, 0x1932, InCb::Extend),
+    (0x1939, 0x193B, InCb::Extend),
+    (0x1A17, 0x1A18, InCb::Extend),
+    (0x1A1B, 0x1A1B, InCb::Extend),
+    (0x1A20, 0x1A54, InCb::Consonant),
+    (0x1A56, 0x1A56, InCb::Extend),
+    (0x1A58, 0x1A5E, InCb::Extend),
+    (0x1A60, 0x1A60, InCb::Linker),
+    (0x1A62, 0x1A62, InCb::Extend),
+    (0x1A65, 0x1A6C, InCb::Extend),
+    (0x1A73, 0x1A7C, InCb::Extend),
+    (0x1A7F, 0x1A7F, InCb::Extend),
+    (0x1AB0, 0x1ADD, InCb::Extend),
+    (0x1AE0, 0x1AEB, InCb::Extend),
+    (0x1B00, 0x1B03, InCb::Extend),
+    (0x1B0B, 0x1B0C, InCb::Consonant),
+    (0x1B13, 0x1B33, InCb::Consonant),
+    (0x1B34, 0x1B3D, InCb::Extend),
+    (0x1B42, 0x1B43, InCb::Extend),
+    (0x1B44, 0x1B44, InCb::Linker),
+    (0x1B45, 0x1B4C, InCb::Consonant),
+    (0x1B6B, 0x1B73, InCb::Extend),
+    (0x1B80, 0x1B81, InCb::Extend),
+    (0x1B83, 0x1BA0, InCb::Consonant),
+    (0x1BA2, 0x1BA5, InCb::Extend),
+    (0x1BA8, 0x1BAA, InCb::Extend),
+    (0x1BAB, 0x1BAB, InCb::Linker),
+    (0x1BAC, 0x1BAD, InCb::Extend),
+    (0x1BAE, 0x1BAF, InCb::Consonant),
+    (0x1BBB, 0x1BBD, InCb::Consonant),
+    (0x1BE6, 0x1BE6, InCb::Extend),
+    (0x1BE8, 0x1BE9, InCb::Extend),
+    (0x1BED, 0x1BED, InCb::Extend),
+    (0x1BEF, 0x1BF3, InCb::Extend),
+    (0x1C2C, 0x1C33, InCb::Extend),
+    (0x1C36, 0x1C37, InCb::Extend),
+    (0x1CD0, 0x1CD2, InCb::Extend),
+    (0x1CD4, 0x1CE0, InCb::Extend),
+    (0x1CE2, 0x1CE8, InCb::Extend),
+    (0x1CED, 0x1CED, InCb::Extend),
+    (0x1CF4, 0x1CF4, InCb::Extend),
+    (0x1CF8, 0x1CF9, InCb::Extend),
+    (0x1DC0, 0x1DFF, InCb::Extend),
+    (0x200D, 0x200D, InCb::Extend),
+    (0x20D0, 0x20F0, InCb::Extend),
+    (0x2CEF, 0x2CF1, InCb::Extend),
+    (0x2D7F, 0x2D7F, InCb::Extend),
+    (0x2DE0, 0x2DFF, InCb::Extend),
+    (0x302A, 0x302F, InCb::Extend),
+    (0x3099, 0x309A, InCb::Extend),
+    (0xA66F, 0xA672, InCb::Extend),
+    (0xA674, 0xA67D, InCb::Extend),
+    (0xA69E, 0xA69F, InCb::Extend),
+    (0xA6F0, 0xA6F1, InCb::Extend),
+    (0xA802, 0xA802, InCb::Extend),
+    (0xA806, 0xA806, InCb::Extend),
+    (0xA80B, 0xA80B, InCb::Extend),
+    (0xA825, 0xA826, InCb::Extend),
+    (0xA82C, 0xA82C, InCb::Extend),
+    (0xA8C4, 0xA8C5, InCb::Extend),
+    (0xA8E0, 0xA8F1, InCb::Extend),
+    (0xA8FF, 0xA8FF, InCb::Extend),
+    (0xA926, 0xA92D, InCb::Extend),
+    (0xA947, 0xA951, InCb::Extend),
+    (0xA953, 0xA953, InCb::Extend),
+    (0xA980, 0xA982, InCb::Extend),
+    (0xA989, 0xA98B, InCb::Consonant),
+    (0xA98F, 0xA9B2, InCb::Consonant),
+    (0xA9B3, 0xA9B3, InCb::Extend),
+    (0xA9B6, 0xA9B9, InCb::Extend),
+    (0xA9BC, 0xA9BD, InCb::Extend),
+    (0xA9C0, 0xA9C0, InCb::Linker),
+    (0xA9E0, 0xA9E4, InCb::Consonant),
+    (0xA9E5, 0xA9E5, InCb::Extend),
+    (0xA9E7, 0xA9EF, InCb::Consonant),
+    (0xA9FA, 0xA9FE, InCb::Consonant),
+    (0xAA29, 0xAA2E, InCb::Extend),
+    (0xAA31, 0xAA32, InCb::Extend),
+    (0xAA35, 0xAA36, InCb::Extend),
+    (0xAA43, 0xAA43, InCb::Extend),
+    (0xAA4C, 0xAA4C, InCb::Extend),
+    (0xAA60, 0xAA6F, InCb::Consonant),
+    (0xAA71, 0xAA73, InCb::Consonant),
+    (0xAA7A, 0xAA7A, InCb::Consonant),
+    (0xAA7C, 0xAA7C, InCb::Extend),
+    (0xAA7E, 0xAA7F, InCb::Consonant),
+    (0xAAB0, 0xAAB0, InCb::Extend),
+    (0xAAB2, 0xAAB4, InCb::Extend),
+    (0xAAB7, 0xAAB8, InCb::Extend),
+    (0xAABE, 0xAABF, InCb::Extend),
+    (0xAAC1, 0xAAC1, InCb::Extend),
+    (0xAAE0, 0xAAEA, InCb::Consonant),
+    (0xAAEC, 0xAAED, InCb::Extend),
+    (0xAAF6, 0xAAF6, InCb::Linker),
+    (0xABC0, 0xABDA, InCb::Consonant),
+    (0xABE5, 0xABE5, InCb::Extend),
+    (0xABE8, 0xABE8, InCb::Extend),
+    (0xABED, 0xABED, InCb::Extend),
+    (0xFB1E, 0xFB1E, InCb::Extend),
+    (0xFE00, 0xFE0F, InCb::Extend),
+    (0xFE20, 0xFE2F, InCb::Extend),
+    (0xFF9E, 0xFF9F, InCb::Extend),
+    (0x101FD, 0x101FD, InCb::Extend),
+    (0x102E0, 0x102E0, InCb::Extend),
+    (0x10376, 0x1037A, InCb::Extend),
+    (0x10A00, 0x10A00, InCb::Consonant),
+    (0x10A01, 0x10A03, InCb::Extend),
+    (0x10A05, 0x10A06, InCb::Extend),
+    (0x10A0C, 0x10A0F, InCb::Extend),
+    (0x10A10, 0x10A13, InCb::Consonant),
+    (0x10A15, 0x10A17, InCb::Consonant),
+    (0x10A19, 0x10A35, InCb::Consonant),
+    (0x10A38, 0x10A3A, InCb::Extend),
+    (0x10A3F, 0x10A3F, InCb::Linker),
+    (0x10AE5, 0x10AE6, InCb::Extend),
+    (0x10D24, 0x10D27, InCb::Extend),
+    (0x10D69, 0x10D6D, InCb::Extend),
+    (0x10EAB, 0x10EAC, InCb::Extend),
+    (0x10EFA, 0x10EFF, InCb::Extend),
+    (0x10F46, 0x10F50, InCb::Extend),
+    (0x10F82, 0x10F85, InCb::Extend),
+    (0x11001, 0x11001, InCb::Extend),
+    (0x11038, 0x11046, InCb::Extend),
+    (0x11070, 0x11070, InCb::Extend),
+    (0x11073, 0x11074, InCb::Extend),
+    (0x1107F, 0x11081, InCb::Extend),
+    (0x110B3, 0x110B6, InCb::Extend),
+    (0x110B9, 0x110BA, InCb::Extend),
+    (0x110C2, 0x110C2, InCb::Extend),
+    (0x11100, 0x11102, InCb::Extend),
+    (0x11103, 0x11126, InCb::Consonant),
+    (0x11127, 0x1112B, InCb::Extend),
+    (0x1112D, 0x11132, InCb::Extend),
+    (0x11133, 0x11133, InCb::Linker),
+    (0x11134, 0x11134, InCb::Extend),
+    (0x11144, 0x11144, InCb::Consonant),
+    (0x11147, 0x11147, InCb::Consonant),
+    (0x11173, 0x11173, InCb::Extend),
+    (0x11180, 0x11181, InCb::Extend),
+    (0x111B6, 0x111BE, InCb::Extend),
+    (0x111C0, 0x111C0, InCb::Extend),
+    (0x111C9, 0x111CC, InCb::Extend),
+    (0x111CF, 0x111CF, InCb::Extend),
+    (0x1122F, 0x11231, InCb::Extend),
+    (0x11234, 0x11237, InCb::Extend),
+    (0x1123E, 0x1123E, InCb::Extend),
+    (0x11241, 0x11241, InCb::Extend),
+    (0x112DF, 0x112DF, InCb::Extend),
+    (0x112E3, 0x112EA, InCb::Extend),
+    (0x11300, 0x11301, InCb::Extend),
+    (0x1133B, 0x1133C, InCb::Extend),
+    (0x1133E, 0x1133E, InCb::Extend),
+    (0x11340, 0x11340, InCb::Extend),
+    (0x1134D, 0x1134D, InCb::Extend),
+    (0x11357, 0x11357, InCb::Extend),
+    (0x11366, 0x1136C, InCb::Extend),
+    (0x11370, 0x11374, InCb::Extend),
+    (0x11380, 0x11389, InCb::Consonant),
+    (0x1138B, 0x1138B, InCb::Consonant),
+    (0x1138E, 0x1138E, InCb::Consonant),
+    (0x11390, 0x113B5, InCb::Consonant),
+    (0x113B8, 0x113B8, InCb::Extend),
+    (0x113BB, 0x113C0, InCb::Extend),
+    (0x113C2, 0x113C2, InCb::Extend),
+    (0x113C5, 0x113C5, InCb::Extend),
+    (0x113C7, 0x113C9, InCb::Extend),
+    (0x113CE, 0x113CF, InCb::Extend),
+    (0x113D0, 0x113D0, InCb::Linker),
+    (0x113D2, 0x113D2, InCb::Extend),
+    (0x113E1, 0x113E2, InCb::Extend),
+    (0x11438, 0x1143F, InCb::Extend),
+    (0x11442, 0x11444, InCb::Extend),
+    (0x11446, 0x11446, InCb::Extend),
+    (0x1145E, 0x1145E, InCb::Extend),
+    (0x114B0, 0x114B0, InCb::Extend),
+    (0x114B3, 0x114B8, InCb::Extend),
+    (0x114BA, 0x114BA, InCb::Extend),
+    (0x114BD, 0x114BD, InCb::Extend),
+    (0x114BF, 0x114C0, InCb::Extend),
+    (0x114C2, 0x114C3, InCb::Extend),
+    (0x115AF, 0x115AF, InCb::Extend),
+    (0x115B2, 0x115B5, InCb::Extend),
+    (0x115BC, 0x115BD, InCb::Extend),
+    (0x115BF, 0x115C0, InCb::Extend),
+    (0x115DC, 0x115DD, InCb::Extend),
+    (0x11633, 0x1163A, InCb::Extend),
+    (0x1163D, 0x1163D, InCb::Extend),
+    (0x1163F, 0x11640, InCb::Extend),
+    (0x116AB, 0x116AB, InCb::Extend),
+    (0x116AD, 0x116AD, InCb::Extend),
+    (0x116B0, 0x116B7, InCb::Extend),
+    (0x1171D, 0x1171D, InCb::Extend),
+    (0x1171F, 0x1171F, InCb::Extend),
+    (0x11722, 0x11725, InCb::Extend),
+    (0x11727, 0x1172B, InCb::Extend),
+    (0x1182F, 0x11837, InCb::Extend),
+    (0x11839, 0x1183A, InCb::Extend),
+    (0x11900, 0x11906, InCb::Consonant),
+    (0x11909, 0x11909, InCb::Consonant),
+    (0x1190C, 0x11913, InCb::Consonant),
+    (0x11915, 0x11916, InCb::Consonant),
+    (0x11918, 0x1192F, InCb::Consonant),
+    (0x11930, 0x11930, InCb::Extend),
+    (0x1193B, 0x1193D, InCb::Extend),
+    (0x1193E, 0x1193E, InCb::Linker),
+    (0x11943, 0x11943, InCb::Extend),
+    (0x119D4, 0x119D7, InCb::Extend),
+    (0x119DA, 0x119DB, InCb::Extend),
+    (0x119E0, 0x119E0, InCb::Extend),
+    (0x11A00, 0x11A00, InCb::Consonant),
+    (0x11A01, 0x11A0A, InCb::Extend),
+    (0x11A0B, 0x11A32, InCb::Consonant),
+    (0x11A33, 0x11A38, InCb::Extend),
+    (0x11A3B, 0x11A3E, InCb::Extend),
+    (0x11A47, 0x11A47, InCb::Linker),
+    (0x11A50, 0x11A50, InCb::Consonant),
+    (0x11A51, 0x11A56, InCb::Extend),
+    (0x11A59, 0x11A5B, InCb::Extend),
+    (0x11A5C, 0x11A83, InCb::Consonant),
+    (0x11A8A, 0x11A96, InCb::Extend),
+    (0x11A98, 0x11A98, InCb::Extend),
+    (0x11A99, 0x11A99, InCb::Linker),
+    (0x11B60, 0x11B60, InCb::Extend),
+    (0x11B62, 0x11B64, InCb::Extend),
+    (0x11B66, 0x11B66, InCb::Extend),
+    (0x11C30, 0x11C36, InCb::Extend),
+    (0x11C38, 0x11C3D, InCb::Extend),
+    (0x11C3F, 0x11C3F, InCb::Extend),
+    (0x11C92, 0x11CA7, InCb::Extend),
+    (0x11CAA, 0x11CB0, InCb::Extend),
+    (0x11CB2, 0x11CB3, InCb::Extend),
+    (0x11CB5, 0x11CB6, InCb::Extend),
+    (0x11D31, 0x11D36, InCb::Extend),
+    (0x11D3A, 0x11D3A, InCb::Extend),
+    (0x11D3C, 0x11D3D, InCb::Extend),
+    (0x11D3F, 0x11D45, InCb::Extend),
+    (0x11D47, 0x11D47, InCb::Extend),
+    (0x11D90, 0x11D91, InCb::Extend),
+    (0x11D95, 0x11D95, InCb::Extend),
+    (0x11D97, 0x11D97, InCb::Extend),
+    (0x11EF3, 0x11EF4, InCb::Extend),
+    (0x11F00, 0x11F01, InCb::Extend),
+    (0x11F04, 0x11F10, InCb::Consonant),
+    (0x11F12, 0x11F33, InCb::Consonant),
+    (0x11F36, 0x11F3A, InCb::Extend),
+    (0x11F40, 0x11F41, InCb::Extend),
+    (0x11F42, 0x11F42, InCb::Linker),
+    (0x11F5A, 0x11F5A, InCb::Extend),
+    (0x13440, 0x13440, InCb::Extend),
+    (0x13447, 0x13455, InCb::Extend),
+    (0x1611E, 0x16129, InCb::Extend),
+    (0x1612D, 0x1612F, InCb::Extend),
+    (0x16AF0, 0x16AF4, InCb::Extend),
+    (0x16B30, 0x16B36, InCb::Extend),
+    (0x16F4F, 0x16F4F, InCb::Extend),
+    (0x16F8F, 0x16F92, InCb::Extend),
+    (0x16FE4, 0x16FE4, InCb::Extend),
+    (0x16FF0, 0x16FF1, InCb::Extend),
+    (0x1BC9D, 0x1BC9E, InCb::Extend),
+    (0x1CF00, 0x1CF2D, InCb::Extend),
+    (0x1CF30, 0x1CF46, InCb::Extend),
+    (0x1D165, 0x1D169, InCb::Extend),
+    (0x1D16D, 0x1D172, InCb::Extend),
+    (0x1D17B, 0x1D182, InCb::Extend),
+    (0x1D185, 0x1D18B, InCb::Extend),
+    (0x1D1AA, 0x1D1AD, InCb::Extend),
+    (0x1D242, 0x1D244, InCb::Extend),
+    (0x1DA00, 0x1DA36, InCb::Extend),
+    (0x1DA3B, 0x1DA6C, InCb::Extend),
+    (0x1DA75, 0x1DA75, InCb::Extend),
+    (0x1DA84, 0x1DA84, InCb::Extend),
+    (0x1DA9B, 0x1DA9F, InCb::Extend),
+    (0x1DAA1, 0x1DAAF, InCb::Extend),
+    (0x1E000, 0x1E006, InCb::Extend),
+    (0x1E008, 0x1E018, InCb::Extend),
+    (0x1E01B, 0x1E021, InCb::Extend),
+    (0x1E023, 0x1E024, InCb::Extend),
+    (0x1E026, 0x1E02A, InCb::Extend),
+    (0x1E08F, 0x1E08F, InCb::Extend),
+    (0x1E130, 0x1E136, InCb::Extend),
+    (0x1E2AE, 0x1E2AE, InCb::Extend),
+    (0x1E2EC, 0x1E2EF, InCb::Extend),
+    (0x1E4EC, 0x1E4EF, InCb::Extend),
+    (0x1E5EE, 0x1E5EF, InCb::Extend),
+    (0x1E6E3, 0x1E6E3, InCb::Extend),
+    (0x1E6E6, 0x1E6E6, InCb::Extend),
+    (0x1E6EE, 0x1E6EF, InCb::Extend),
+    (0x1E6F5, 0x1E6F5, InCb::Extend),
+    (0x1E8D0, 0x1E8D6, InCb::Extend),
+    (0x1E944, 0x1E94A, InCb::Extend),
+    (0x1F3FB, 0x1F3FF, InCb::Extend),
+    (0xE0020, 0xE007F, InCb::Extend),
+    (0xE0100, 0xE01EF, InCb::Extend),
+];
+
+// Extended_Pictographic from emoji-data.txt
+pub const EXTENDED_PICTOGRAPHIC: &[(u32, u32)] = &[
+    (0x00A9, 0x00A9),
+    (0x00AE, 0x00AE),
+    (0x203C, 0x203C),
+    (0x2049, 0x2049),
+    (0x2122, 0x2122),
+    (0x2139, 0x2139),
+    (0x2194, 0x2199),
+    (0x21A9, 0x21AA),
+    (0x231A, 0x231B),
+    (0x2328, 0x2328),
+    (0x23CF, 0x23CF),
+    (0x23E9, 0x23F3),
+    (0x23F8, 0x23FA),
+    (0x24C2, 0x24C2),
+    (0x25AA, 0x25AB),
+    (0x25B6, 0x25B6),
+    (0x25C0, 0x25C0),
+    (0x25FB, 0x25FE),
+    (0x2600, 0x2604),
+    (0x260E, 0x260E),
+    (0x2611, 0x2611),
+    (0x2614, 0x2615),
+    (0x2618, 0x2618),
+    (0x261D, 0x261D),
+    (0x2620, 0x2620),
+    (0x2622, 0x2623),
+    (0x2626, 0x2626),
+    (0x262A, 0x262A),
+    (0x262E, 0x262F),
+    (0x2638, 0x263A),
+    (0x2640, 0x2640),
+    (0x2642, 0x2642),
+    (0x2648, 0x2653),
+    (0x265F, 0x2660),
+    (0x2663, 0x2663),
+    (0x2665, 0x2666),
+    (0x2668, 0x2668),
+    (0x267B, 0x267B),
+    (0x267E, 0x267F),
+    (0x2692, 0x2697),
+    (0x2699, 0x2699),
+    (0x269B, 0x269C),
+    (0x26A0, 0x26A1),
+    (0x26A7, 0x26A7),
+    (0x26AA, 0x26AB),
+    (0x26B0, 0x26B1),
+    (0x26BD, 0x26BE),
+    (0x26C4, 0x26C5),
+    (0x26C8, 0x26C8),
+    (0x26CE, 0x26CF),
+    (0x26D1, 0x26D1),
+    (0x26D3, 0x26D4),
+    (0x26E9, 0x26EA),
+    (0x26F0, 0x26F5),
+    (0x26F7, 0x26FA),
+    (0x26FD, 0x26FD),
+    (0x2702, 0x2702),
+    (0x2705, 0x2705),
+    (0x2708, 0x270D),
+    (0x270F, 0x270F),
+    (0x2712, 0x2712),
+    (0x2714, 0x2714),
+    (0x2716, 0x2716),
+    (0x271D, 0x271D),
+    (0x2721, 0x2721),
+    (0x2728, 0x2728),
+    (0x2733, 0x2734),
+    (0x2744, 0x2744),
+    (0x2747, 0x2747),
+    (0x274C, 0x274C),
+    (0x274E, 0x274E),
+    (0x2753, 0x2755),
+    (0x2757, 0x2757),
+    (0x2763, 0x2764),
+    (0x2795, 0x2797),
+    (0x27A1, 0x27A1),
+    (0x27B0, 0x27B0),
+    (0x27BF, 0x27BF),
+    (0x2934, 0x2935),
+    (0x2B05, 0x2B07),
+    (0x2B1B, 0x2B1C),
+    (0x2B50, 0x2B50),
+    (0x2B55, 0x2B55),
+    (0x3030, 0x3030),
+    (0x303D, 0x303D),
+    (0x3297, 0x3297),
+    (0x3299, 0x3299),
+    (0x1F004, 0x1F004),
+    (0x1F02C, 0x1F02F),
+    (0x1F094, 0x1F09F),
+    (0x1F0AF, 0x1F0B0),
+    (0x1F0C0, 0x1F0C0),
+    (0x1F0CF, 0x1F0D0),
+    (0x1F0F6, 0x1F0FF),
+    (0x1F170, 0x1F171),
+    (0x1F17E, 0x1F17F),
+    (0x1F18E, 0x1F18E),
+    (0x1F191, 0x1F19A),
+    (0x1F1AE, 0x1F1E5),
+    (0x1F201, 0x1F20F),
+    (0x1F21A, 0x1F21A),
+    (0x1F22F, 0x1F22F),
+    (0x1F232, 0x1F23A),
+    (0x1F23C, 0x1F23F),
+    (0x1F249, 0x1F25F),
+    (0x1F266, 0x1F321),
+    (0x1F324, 0x1F393),
+    (0x1F396, 0x1F397),
+    (0x1F399, 0x1F39B),
+    (0x1F39E, 0x1F3F0),
+    (0x1F3F3, 0x1F3F5),
+    (0x1F3F7, 0x1F3FA),
+    (0x1F400, 0x1F4FD),
+    (0x1F4FF, 0x1F53D),
+    (0x1F549, 0x1F54E),
+    (0x1F550, 0x1F567),
+    (0x1F56F, 0x1F570),
+    (0x1F573, 0x1F57A),
+    (0x1F587, 0x1F587),
+    (0x1F58A, 0x1F58D),
+    (0x1F590, 0x1F590),
+    (0x1F595, 0x1F596),
+    (0x1F5A4, 0x1F5A5),
+    (0x1F5A8, 0x1F5A8),
+    (0x1F5B1, 0x1F5B2),
+    (0x1F5BC, 0x1F5BC),
+    (0x1F5C2, 0x1F5C4),
+    (0x1F5D1, 0x1F5D3),
+    (0x1F5DC, 0x1F5DE),
+    (0x1F5E1, 0x1F5E1),
+    (0x1F5E3, 0x1F5E3),
+    (0x1F5E8, 0x1F5E8),
+    (0x1F5EF, 0x1F5EF),
+    (0x1F5F3, 0x1F5F3),
+    (0x1F5FA, 0x1F64F),
+    (0x1F680, 0x1F6C5),
+    (0x1F6CB, 0x1F6D2),
+    (0x1F6D5, 0x1F6E5),
+    (0x1F6E9, 0x1F6E9),
+    (0x1F6EB, 0x1F6F0),
+    (0x1F6F3, 0x1F6FF),
+    (0x1F7DA, 0x1F7FF),
+    (0x1F80C, 0x1F80F),
+    (0x1F848, 0x1F84F),
+    (0x1F85A, 0x1F85F),
+    (0x1F888, 0x1F88F),
+    (0x1F8AE, 0x1F8AF),
+    (0x1F8BC, 0x1F8BF),
+    (0x1F8C2, 0x1F8CF),
+    (0x1F8D9, 0x1F8FF),
+    (0x1F90C, 0x1F93A),
+    (0x1F93C, 0x1F945),
+    (0x1F947, 0x1F9FF),
+    (0x1FA58, 0x1FA5F),
+    (0x1FA6E, 0x1FAFF),
+    (0x1FC00, 0x1FFFD),
+];
+
+// East_Asian_Width = Wide or Fullwidth from EastAsianWidth.txt
+pub const EAST_ASIAN_WIDE: &[(u32, u32)] = &[
+    (0x1100, 0x115F),
+    (0x231A, 0x231B),
+    (0x2329, 0x232A),
+    (0x23E9, 0x23EC),
+    (0x23F0, 0x23F0),
+    (0x23F3, 0x23F3),
+    (0x25FD, 0x25FE),
+    (0x2614, 0x2615),
+    (0x2630, 0x2637),
+    (0x2648, 0x2653),
+    (0x267F, 0x267F),
+    (0x268A, 0x268F),
+    (0x2693, 0x2693),
+    (0x26A1, 0x26A1),
+    (0x26AA, 0x26AB),
+    (0x26BD, 0x26BE),
+    (0x26C4, 0x26C5),
+    (0x26CE, 0x26CE),
+    (0x26D4, 0x26D4),
+    (0x26EA, 0x26EA),
+    (0x26F2, 0x26F3),
+    (0x26F5, 0x26F5),
+    (0x26FA, 0x26FA),
+    (0x26FD, 0x26FD),
+    (0x2705, 0x2705),
+    (0x270A, 0x270B),
+    (0x2728, 0x2728),
+    (0x274C, 0x274C),
+    (0x274E, 0x274E),
+    (0x2753, 0x2755),
+    (0x2757, 0x2757),
+    (0x2795, 0x2797),
+    (0x27B0, 0x27B0),
+    (0x27BF, 0x27BF),
+    (0x2B1B, 0x2B1C),
+    (0x2B50, 0x2B50),
+    (0x2B55, 0x2B55),
+    (0x2E80, 0x2E99),
+    (0x2E9B, 0x2EF3),
+    (0x2F00, 0x2FD5),
+    (0x2FF0, 0x3029),
+    (0x3030, 0x303E),
+    (0x3041, 0x3096),
+    (0x309B, 0x30FF),
+    (0x3105, 0x312F),
+    (0x3131, 0x3163),
+    (0x3165, 0x318E),
+    (0x3190, 0x31E5),
+    (0x31EF, 0x321E),
+    (0x3220, 0x3247),
+    (0x3250, 0xA48C),
+    (0xA490, 0xA4C6),
+    (0xA960, 0xA97C),
+    (0xAC00, 0xD7A3),
+    (0xF900, 0xFAFF),
+    (0xFE10, 0xFE19),
+    (0xFE30, 0xFE52),
+    (0xFE54, 0xFE66),
+    (0xFE68, 0xFE6B),
+    (0xFF01, 0xFF60),
+    (0xFFE0, 0xFFE6),
+    (0x16FE0, 0x16FE3),
+    (0x16FF2, 0x16FF6),
+    (0x17000, 0x18CD5),
+    (0x18CFF, 0x18D1E),
+    (0x18D80, 0x18DF2),
+    (0x1AFF0, 0x1AFF3),
+    (0x1AFF5, 0x1AFFB),
+    (0x1AFFD, 0x1AFFE),
+    (0x1B000, 0x1B122),
+    (0x1B132, 0x1B132),
+    (0x1B150, 0x1B152),
+    (0x1B155, 0x1B155),
+    (0x1B164, 0x1B167),
+    (0x1B170, 0x1B2FB),
+    (0x1D300, 0x1D356),
+    (0x1D360, 0x1D376),
+    (0x1F004, 0x1F004),
+    (0x1F0CF, 0x1F0CF),
+    (0x1F18E, 0x1F18E),
+    (0x1F191, 0x1F19A),
+    (0x1F1E6, 0x1F202),
+    (0x1F210, 0x1F23B),
+    (0x1F240, 0x1F248),
+    (0x1F250, 0x1F251),
+    (0x1F260, 0x1F265),
+    (0x1F300, 0x1F320),
+    (0x1F32D, 0x1F335),
+    (0x1F337, 0x1F37C),
+    (0x1F37E, 0x1F393),
+    (0x1F3A0, 0x1F3CA),
+    (0x1F3CF, 0x1F3D3),
+    (0x1F3E0, 0x1F3F0),
+    (0x1F3F4, 0x1F3F4),
+    (0x1F3F8, 0x1F43E),
+    (0x1F440, 0x1F440),
+    (0x1F442, 0x1F4FC),
+    (0x1F4FF, 0x1F53D),
+    (0x1F54B, 0x1F54E),
+    (0x1F550, 0x1F567),
+    (0x1F57A, 0x1F57A),
+    (0x1F595, 0x1F596),
+    (0x1F5A4, 0x1F5A4),
+    (0x1F5FB, 0x1F64F),
+    (0x1F680, 0x1F6C5),
+    (0x1F6CC, 0x1F6CC),
+    (0x1F6D0, 0x1F6D2),
+    (0x1F6D5, 0x1F6D8),
+    (0x1F6DC, 0x1F6DF),
+    (0x1F6EB, 0x1F6EC),
+    (0x1F6F4, 0x1F6FC),
+    (0x1F7E0, 0x1F7EB),
+    (0x1F7F0, 0x1F7F0),
+    (0x1F90C, 0x1F93A),
+    (0x1F93C, 0x1F945),
+    (0x1F947, 0x1F9FF),
+    (0x1FA70, 0x1FA7C),
+    (0x1FA80, 0x1FA8A),
+    (0x1FA8E, 0x1FAC6),
+    (0x1FAC8, 0x1FAC8),
+    (0x1FACD, 0x1FADC),
+    (0x1FADF, 0x1FAEA),
+    (0x1FAEF, 0x1FAF8),
+    (0x20000, 0x2FFFD),
+    (0x30000, 0x3FFFD),
+];